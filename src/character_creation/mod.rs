@@ -1,32 +1,90 @@
 use std::io;
 
+use rand::{Rng, seq::IndexedRandom};
+
 use crate::{
     worldobject::{
         components::{
             gender::Gender,
             body::{
                 Body,
-                head::{Head, Mouth},
-                torso::{Torso, arm::arm, arm::hand::hand},
-                legs::Legs
+                head::{Head, Mouth, Tooth, HEAD_HIT_POINTS},
+                torso::{Torso, TORSO_HIT_POINTS, arm::arm, arm::hand::hand},
+                legs::{Legs, LEGS_HIT_POINTS}
             },
+            health::Health,
             inventory::{
                 Inventory,
                 item::InventoryItem
             },
-            controllers::terminal::TerminalHumanController,
+            controllers::{
+                terminal::TerminalHumanController,
+                ai::{AiController, personality::Personality}
+            },
         },
         human::Human
     },
     quantities::{
         speed::meters_per_second,
-        mass::kilograms,
-        distance::meters,
+        mass::{kilograms, grams},
+        distance::{meters, centimeters},
         force::newtons,
         direction::DirectionHorizontal,
     }
 };
 
+// human_teeth returns a full adult set: sharp incisors and canines
+// at the front, and blunt molars at the back
+fn human_teeth() -> Vec<Tooth> {
+    let tooth = |length, sharp| Tooth { mass: grams(2.0), length: centimeters(length), sharp };
+    std::iter::repeat_with(|| tooth(1.0, true)).take(8)
+        .chain(std::iter::repeat_with(|| tooth(1.1, true)).take(4))
+        .chain(std::iter::repeat_with(|| tooth(0.7, false)).take(20))
+        .collect()
+}
+
+// human_body returns the body every new character is born with
+pub fn human_body() -> Body {
+    Body{
+        base_mass: kilograms(100.0),
+        head: Head{
+            base_mass: kilograms(7.0),
+            mouth: Mouth{
+                base_mass: kilograms(1.0),
+                teeth: human_teeth(),
+            },
+            health: Health::new(HEAD_HIT_POINTS),
+        },
+        torso: Torso{
+            base_mass: kilograms(20.0),
+            left_arm: Some(arm(
+                kilograms(10.0),
+                meters(1.0),
+                newtons(1000.0),
+                Some(hand(
+                    kilograms(1.0),
+                    None::<Box<dyn InventoryItem>>
+                ))
+            )),
+            right_arm: Some(arm(
+                kilograms(10.0),
+                meters(1.0),
+                newtons(1000.0),
+                Some(hand(
+                    kilograms(1.0),
+                    None::<Box<dyn InventoryItem>>
+                ))
+            )),
+            health: Health::new(TORSO_HIT_POINTS),
+        },
+        legs: Legs{
+            base_mass: kilograms(10.0),
+            speed: meters_per_second(5.0),
+            health: Health::new(LEGS_HIT_POINTS),
+        },
+    }
+}
+
 pub fn create_character() -> Human {
     println!("You awaken - your vision is filled with darkness.  Your memory is hazy.  While your eyes adjust, you talk yourself through some basic facts...");
 
@@ -70,43 +128,24 @@ pub fn create_character() -> Human {
     Human::new(
         name,
         gender,
-        Body{
-            base_mass: kilograms(100.0),
-            head: Head{
-                base_mass: kilograms(7.0),
-                mouth: Mouth{
-                    base_mass: kilograms(1.0),
-                    teeth: vec![],
-                },
-            },
-            torso: Torso{
-                base_mass: kilograms(20.0),
-                left_arm: arm(
-                    kilograms(10.0),
-                    meters(1.0),
-                    newtons(1000.0),
-                    Some(hand(
-                        kilograms(1.0),
-                        None::<Box<dyn InventoryItem>>
-                    ))
-                ),
-                right_arm: arm(
-                    kilograms(10.0),
-                    meters(1.0),
-                    newtons(1000.0),
-                    Some(hand(
-                        kilograms(1.0),
-                        None::<Box<dyn InventoryItem>>
-                    ))
-                ),
-            },
-            legs: Legs{
-                base_mass: kilograms(10.0),
-                speed: meters_per_second(5.0),
-            },
-        },
+        human_body(),
         DirectionHorizontal::Right,
         Inventory::new(),
         Some(TerminalHumanController{})
     )
 }
+
+// create_bot returns a bot with the given name and personality, played by
+// the AI; its gender is drawn from the given generator
+pub fn create_bot(name: String, personality: Personality, rng: &mut impl Rng) -> Human {
+    let gender = *[Gender::Male, Gender::Female, Gender::Other].choose(rng).unwrap_or(&Gender::Other);
+
+    Human::new(
+        name,
+        gender,
+        human_body(),
+        DirectionHorizontal::Right,
+        Inventory::new(),
+        Some(AiController::bot(personality))
+    )
+}
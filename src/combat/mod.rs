@@ -0,0 +1,328 @@
+// This module houses the mechanics of blows.
+//
+// An unarmed blow lands with the force of the arm's punch.  A blow struck
+// with a weapon is modelled as a swing: the arm pushes the weapon with its
+// punch force over the length of the arm and the weapon, so the work done,
+// F·d, becomes the weapon's kinetic energy, ½mv², and the weapon's momentum
+// is √(2Fdm).  The weapon gives up that momentum over a short contact time
+// when it lands, which is shorter for edges and points than for blunt
+// surfaces, and shorter still for edges and points much harder than what
+// they strike.  Edges and points wear down as they strike, the faster the
+// harder the target, and a worn edge lands more softly than a keen one.
+// Things of no particular material are struck as if they were flesh.
+//
+// A bite is modelled tooth by tooth: each tooth bears down with a force in
+// proportion to its length, and sharp teeth, which put all of that force
+// on a point, bite harder than blunt ones.
+//
+// Whatever a blow lands on loses a hit point for every hundred newtons of
+// force behind it, and is knocked away from whoever struck it by that same
+// force acting over the blow's contact time.
+
+use std::fmt;
+
+use serde::{Serialize, Deserialize};
+
+use crate::{
+    lang::VerbPhrase,
+    quantities::{
+        Quantity,
+        force::{Force, newtons},
+        distance::{Distance, centimeters, meters},
+        mass::kilograms,
+        duration::seconds
+    },
+    materials::Material,
+    world::{World, handle::WorldObjectHandle},
+    worldobject::{
+        WorldObject,
+        components::{
+            body::{BodyPart, head::Tooth},
+            inventory::item::InventoryItem
+        }
+    }
+};
+
+// how many times harder than its target an edge or point must be to land
+// with the whole force of the swing; harder edges strike harder, and softer
+// ones softer
+const REFERENCE_HARDNESS_RATIO: f64 = 4.0;
+
+// the hardness, on the Mohs scale, of things of no particular material
+const SOFT_HARDNESS: f64 = 1.0;
+
+// how much force it takes to knock off a hit point
+const NEWTONS_PER_HIT_POINT: f64 = 100.0;
+
+// how hard each centimeter of a tooth bites
+const SHARP_TOOTH_NEWTONS_PER_CENTIMETER: f64 = 50.0;
+const BLUNT_TOOTH_NEWTONS_PER_CENTIMETER: f64 = 20.0;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum DamageType {
+    Slash,
+    Stab,
+    Bash,
+    Bite,
+}
+
+impl DamageType {
+    // how long, in seconds, a weapon striking this way
+    // takes to give up its momentum to what it strikes
+    fn contact_seconds(self) -> f64 {
+        match self {
+            Self::Slash => 0.03,
+            Self::Stab => 0.02,
+            Self::Bash => 0.05,
+            Self::Bite => 0.1,
+        }
+    }
+
+    // how many times over a single blow of this type must be able to take a
+    // limb's hit points to sever it; points pierce limbs, but never sever them
+    pub fn severing_factor(self) -> Option<f64> {
+        match self {
+            Self::Slash => Some(1.0),
+            Self::Stab => None,
+            Self::Bash => Some(3.0),
+            Self::Bite => None,
+        }
+    }
+
+    // whether striking this way wears down the weapon's edge or point
+    pub fn dulls(self) -> bool {
+        matches!(self, Self::Slash | Self::Stab)
+    }
+
+    // the wound a blow of this type leaves
+    pub fn wound(self) -> &'static str {
+        match self {
+            Self::Slash => "cut",
+            Self::Stab => "stab wound",
+            Self::Bash => "bruise",
+            Self::Bite => "bite",
+        }
+    }
+}
+
+impl fmt::Display for DamageType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Slash => write!(f, "slash"),
+            Self::Stab => write!(f, "stab"),
+            Self::Bash => write!(f, "bash"),
+            Self::Bite => write!(f, "bite"),
+        }
+    }
+}
+
+// Blow is what an object is struck with; a blow aimed at a body part lands
+// there, and one that isn't lands wherever it happens to
+pub struct Blow {
+    pub force: Quantity<Force>,
+    pub damage_type: DamageType,
+    pub aim: Option<BodyPart>,
+}
+
+impl Blow {
+    // hit_points returns how many hit points the blow knocks off whatever it lands on
+    pub fn hit_points(&self) -> f64 {
+        hit_points(&self.force)
+    }
+}
+
+// hit_points returns how many hit points a force knocks off whatever it acts on
+pub fn hit_points(force: &Quantity<Force>) -> f64 {
+    (force / &newtons(NEWTONS_PER_HIT_POINT)).cancel().0.0
+}
+
+// BlowOutcome is what came of a blow
+pub struct BlowOutcome {
+    // how the blow landed, for whoever struck it
+    pub description: Option<String>,
+    // what the object struck suffered, narrated with the object as the subject
+    pub effects: Vec<VerbPhrase>,
+    // anything the blow knocked loose, which falls to the ground
+    pub shed: Vec<Box<dyn InventoryItem>>,
+}
+
+impl From<String> for BlowOutcome {
+    fn from(description: String) -> Self {
+        BlowOutcome { description: Some(description), effects: vec![], shed: vec![] }
+    }
+}
+
+// punch returns the blow an arm strikes without a weapon
+pub fn punch(punch_force: &Quantity<Force>, aim: Option<BodyPart>) -> Blow {
+    Blow {
+        force: punch_force.clone(),
+        damage_type: DamageType::Bash,
+        aim,
+    }
+}
+
+// hardness returns how hard the object is, on the Mohs scale
+pub fn hardness(object: &dyn WorldObject) -> f64 {
+    object.material().map_or(SOFT_HARDNESS, Material::hardness)
+}
+
+// swing returns the blow an arm strikes with the weapon it wields
+// at a target of the given hardness
+pub fn swing(punch_force: &Quantity<Force>, arm_reach: &Quantity<Distance>, weapon: &dyn InventoryItem, damage_type: DamageType, aim: Option<BodyPart>, target_hardness: f64) -> Blow {
+    let force = (punch_force / &newtons(1.0)).cancel().0.0;
+    let distance = ((arm_reach + &weapon.reach()) / meters(1.0)).cancel().0.0;
+    let mass = (weapon.mass() / kilograms(1.0)).cancel().0.0;
+
+    let momentum = (2.0 * force * distance * mass).sqrt();
+
+    let keenness = match (damage_type, weapon.material()) {
+        (DamageType::Bash, _) | (_, None) => 1.0,
+        (_, Some(material)) => material.hardness() / target_hardness / REFERENCE_HARDNESS_RATIO * weapon.sharpness(),
+    };
+    let swung_force = newtons(momentum * keenness / damage_type.contact_seconds());
+
+    // however light the weapon, a fist clenched around it hits no softer than a bare one
+    let force = if damage_type == DamageType::Bash && swung_force < *punch_force {
+        punch_force.clone()
+    } else {
+        swung_force
+    };
+
+    Blow { force, damage_type, aim }
+}
+
+// bite returns the blow a mouth strikes with the given teeth
+pub fn bite(teeth: &[Tooth], aim: Option<BodyPart>) -> Blow {
+    let force = teeth.iter()
+        .map(|tooth| {
+            let length = (tooth.length / centimeters(1.0)).cancel().0.0;
+            let newtons_per_centimeter = if tooth.sharp { SHARP_TOOTH_NEWTONS_PER_CENTIMETER } else { BLUNT_TOOTH_NEWTONS_PER_CENTIMETER };
+            length * newtons_per_centimeter
+        })
+        .sum();
+
+    Blow {
+        force: newtons(force),
+        damage_type: DamageType::Bite,
+        aim,
+    }
+}
+
+// StrikeError is what became of a blow the target couldn't receive
+#[derive(Debug)]
+pub struct StrikeError(Box<dyn std::error::Error>);
+
+impl fmt::Display for StrikeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "failed to strike: {}", self.0)
+    }
+}
+
+impl std::error::Error for StrikeError {}
+
+// strike lands a blow on the target while an action is being carried out:
+// whatever the blow knocks loose falls where the target stands, the target
+// is knocked away from the attacker, and whoever can see the target is told
+// what it suffered
+pub async fn strike(world: &mut World, attacker_handle: WorldObjectHandle, target_handle: WorldObjectHandle, blow: Blow) -> Result<Option<String>, Box<dyn std::error::Error>> {
+    let mut rng = world.fork_rng();
+    let object = world.get_object_mut(&target_handle)
+        .map_err(Box::new)?;
+
+    let outcome = object.receive_blow(&blow, &mut rng).await
+        .map_err(StrikeError)?;
+
+    let location = world.locate_object(&target_handle)?;
+    for item in outcome.shed {
+        world.add_object(item, location);
+    }
+
+    for effect in outcome.effects {
+        world.narrate(target_handle, effect);
+    }
+
+    // an attacker standing right where the target stands can't knock it any way
+    let attacker_location = world.locate_object(&attacker_handle)?;
+    if let Some((direction, _)) = location.offset_from(&attacker_location) {
+        world.shove(&target_handle, direction, &blow.force, &seconds(blow.damage_type.contact_seconds()))?;
+    }
+
+    Ok(outcome.description)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        quantities::{distance::{centimeters, meters}, force::newtons, mass::{grams, kilograms}, speed::meters_per_second},
+        tests::fixtures::sword,
+        worldobject::{
+            components::{body::head::Tooth, inventory::item::{InventoryItem, sword::Sword}},
+            rat::Rat
+        }
+    };
+
+    use super::{DamageType, bite, hardness, swing};
+
+    fn tooth(length: f64, sharp: bool) -> Tooth {
+        Tooth { mass: grams(1.0), length: centimeters(length), sharp }
+    }
+
+    #[test]
+    fn a_mouth_without_teeth_bites_with_no_force() {
+        let blow = bite(&[], None);
+        assert!(blow.force == newtons(0.0));
+        assert_eq!(blow.damage_type, DamageType::Bite);
+    }
+
+    #[test]
+    fn more_and_longer_teeth_bite_harder() {
+        let one = bite(&[tooth(1.0, true)], None).force;
+        let two = bite(&[tooth(1.0, true), tooth(1.0, true)], None).force;
+        let long = bite(&[tooth(2.0, true)], None).force;
+
+        assert!(two > one);
+        assert!(long > one);
+    }
+
+    #[test]
+    fn sharp_teeth_bite_harder_than_blunt_ones() {
+        let sharp = bite(&[tooth(1.0, true)], None);
+        let blunt = bite(&[tooth(1.0, false)], None);
+
+        assert!(sharp.force > blunt.force);
+        assert!(sharp.hit_points() > blunt.hit_points());
+    }
+
+    #[test]
+    fn things_of_no_particular_material_are_soft() {
+        assert_eq!(hardness(&Rat::new(kilograms(1.0), meters_per_second(1.0))), 1.0);
+        assert_eq!(hardness(&sword("steel")), 5.5);
+    }
+
+    #[test]
+    fn edges_strike_harder_the_harder_they_are_than_their_target() {
+        let slash = |weapon: &Sword, target_hardness| swing(&newtons(300.0), &meters(0.7), weapon, DamageType::Slash, None, target_hardness).force;
+
+        assert!(slash(&sword("steel"), 1.0) > slash(&sword("steel"), 6.0));
+        assert!(slash(&sword("steel"), 2.0) > slash(&sword("bronze"), 2.0));
+    }
+
+    #[test]
+    fn blunt_blows_take_no_account_of_hardness() {
+        let bash = |weapon: &Sword, target_hardness| swing(&newtons(300.0), &meters(0.7), weapon, DamageType::Bash, None, target_hardness).force;
+
+        assert!(bash(&sword("steel"), 1.0) == bash(&sword("steel"), 6.0));
+    }
+
+    #[test]
+    fn a_worn_edge_strikes_more_softly() {
+        let mut weapon = sword("iron");
+        let keen = swing(&newtons(300.0), &meters(0.7), &weapon, DamageType::Slash, None, 1.0).force;
+
+        weapon.dull(6.0);
+        assert!(weapon.sharpness() < 1.0);
+        let worn = swing(&newtons(300.0), &meters(0.7), &weapon, DamageType::Slash, None, 1.0).force;
+
+        assert!(worn < keen);
+    }
+}
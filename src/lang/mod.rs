@@ -33,7 +33,7 @@ impl VerbPhrase {
     }
 }
 
-pub trait IntransitiveVerbTrait {
+pub trait IntransitiveVerbTrait: Send + Sync {
     fn clone_box(&self) -> Box<dyn IntransitiveVerbTrait>;
     fn conjugate(&self, person: &GrammaticalPerson) -> String;
 }
@@ -63,7 +63,7 @@ impl IntransitiveVerbTrait for IntransitiveVerb {
     }
 }
 
-pub trait TransitiveVerbTrait {
+pub trait TransitiveVerbTrait: Send + Sync {
     fn clone_box(&self) -> Box<dyn TransitiveVerbTrait>;
     fn conjugate(&self, person: &GrammaticalPerson) -> String;
 }
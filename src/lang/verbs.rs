@@ -53,17 +53,65 @@ impl IntransitiveVerbTrait for ToMove {
 }
 
 #[derive(Copy, Clone)]
-pub struct ToAttack;
+pub struct ToSlash;
 
-impl TransitiveVerbTrait for ToAttack {
+impl TransitiveVerbTrait for ToSlash {
     fn clone_box(&self) -> Box<dyn TransitiveVerbTrait> {
         Box::new(*self)
     }
 
     fn conjugate(&self, person: &GrammaticalPerson) -> String {
         match person {
-            GrammaticalPerson::ThirdPersonSingularGendered => String::from("attacks"),
-            _ => String::from("attack"),
+            GrammaticalPerson::ThirdPersonSingularGendered => String::from("slashes"),
+            _ => String::from("slash"),
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct ToStab;
+
+impl TransitiveVerbTrait for ToStab {
+    fn clone_box(&self) -> Box<dyn TransitiveVerbTrait> {
+        Box::new(*self)
+    }
+
+    fn conjugate(&self, person: &GrammaticalPerson) -> String {
+        match person {
+            GrammaticalPerson::ThirdPersonSingularGendered => String::from("stabs"),
+            _ => String::from("stab"),
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct ToBash;
+
+impl TransitiveVerbTrait for ToBash {
+    fn clone_box(&self) -> Box<dyn TransitiveVerbTrait> {
+        Box::new(*self)
+    }
+
+    fn conjugate(&self, person: &GrammaticalPerson) -> String {
+        match person {
+            GrammaticalPerson::ThirdPersonSingularGendered => String::from("bashes"),
+            _ => String::from("bash"),
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct ToPunch;
+
+impl TransitiveVerbTrait for ToPunch {
+    fn clone_box(&self) -> Box<dyn TransitiveVerbTrait> {
+        Box::new(*self)
+    }
+
+    fn conjugate(&self, person: &GrammaticalPerson) -> String {
+        match person {
+            GrammaticalPerson::ThirdPersonSingularGendered => String::from("punches"),
+            _ => String::from("punch"),
         }
     }
 }
@@ -149,17 +197,81 @@ impl TransitiveVerbTrait for ToWield {
 }
 
 #[derive(Copy, Clone)]
-pub struct ToUse;
+pub struct ToUnwield;
+
+impl TransitiveVerbTrait for ToUnwield {
+    fn clone_box(&self) -> Box<dyn TransitiveVerbTrait> {
+        Box::new(*self)
+    }
+
+    fn conjugate(&self, person: &GrammaticalPerson) -> String {
+        match person {
+            GrammaticalPerson::ThirdPersonSingularGendered => String::from("unwields"),
+            _ => String::from("unwield"),
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct ToDrop;
+
+impl TransitiveVerbTrait for ToDrop {
+    fn clone_box(&self) -> Box<dyn TransitiveVerbTrait> {
+        Box::new(*self)
+    }
+
+    fn conjugate(&self, person: &GrammaticalPerson) -> String {
+        match person {
+            GrammaticalPerson::ThirdPersonSingularGendered => String::from("drops"),
+            _ => String::from("drop"),
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct ToGive;
+
+impl TransitiveVerbTrait for ToGive {
+    fn clone_box(&self) -> Box<dyn TransitiveVerbTrait> {
+        Box::new(*self)
+    }
+
+    fn conjugate(&self, person: &GrammaticalPerson) -> String {
+        match person {
+            GrammaticalPerson::ThirdPersonSingularGendered => String::from("gives"),
+            _ => String::from("give"),
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct ToOffer;
+
+impl TransitiveVerbTrait for ToOffer {
+    fn clone_box(&self) -> Box<dyn TransitiveVerbTrait> {
+        Box::new(*self)
+    }
+
+    fn conjugate(&self, person: &GrammaticalPerson) -> String {
+        match person {
+            GrammaticalPerson::ThirdPersonSingularGendered => String::from("offers"),
+            _ => String::from("offer"),
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct ToDecline;
 
-impl TransitiveVerbTrait for ToUse {
+impl TransitiveVerbTrait for ToDecline {
     fn clone_box(&self) -> Box<dyn TransitiveVerbTrait> {
         Box::new(*self)
     }
 
     fn conjugate(&self, person: &GrammaticalPerson) -> String {
         match person {
-            GrammaticalPerson::ThirdPersonSingularGendered => String::from("uses"),
-            _ => String::from("use"),
+            GrammaticalPerson::ThirdPersonSingularGendered => String::from("declines"),
+            _ => String::from("decline"),
         }
     }
 }
@@ -178,4 +290,99 @@ impl TransitiveVerbTrait for ToCast {
             _ => String::from("cast"),
         }
     }
-}
\ No newline at end of file
+}
+#[derive(Copy, Clone)]
+pub struct ToSuffer;
+
+impl TransitiveVerbTrait for ToSuffer {
+    fn clone_box(&self) -> Box<dyn TransitiveVerbTrait> {
+        Box::new(*self)
+    }
+
+    fn conjugate(&self, person: &GrammaticalPerson) -> String {
+        match person {
+            GrammaticalPerson::ThirdPersonSingularGendered => String::from("suffers"),
+            _ => String::from("suffer"),
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct ToLose;
+
+impl TransitiveVerbTrait for ToLose {
+    fn clone_box(&self) -> Box<dyn TransitiveVerbTrait> {
+        Box::new(*self)
+    }
+
+    fn conjugate(&self, person: &GrammaticalPerson) -> String {
+        match person {
+            GrammaticalPerson::ThirdPersonSingularGendered => String::from("loses"),
+            _ => String::from("lose"),
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct ToDie;
+
+impl IntransitiveVerbTrait for ToDie {
+    fn clone_box(&self) -> Box<dyn IntransitiveVerbTrait> {
+        Box::new(*self)
+    }
+
+    fn conjugate(&self, person: &GrammaticalPerson) -> String {
+        match person {
+            GrammaticalPerson::ThirdPersonSingularGendered => String::from("dies"),
+            _ => String::from("die"),
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct ToBite;
+
+impl TransitiveVerbTrait for ToBite {
+    fn clone_box(&self) -> Box<dyn TransitiveVerbTrait> {
+        Box::new(*self)
+    }
+
+    fn conjugate(&self, person: &GrammaticalPerson) -> String {
+        match person {
+            GrammaticalPerson::ThirdPersonSingularGendered => String::from("bites"),
+            _ => String::from("bite"),
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct ToPush;
+
+impl TransitiveVerbTrait for ToPush {
+    fn clone_box(&self) -> Box<dyn TransitiveVerbTrait> {
+        Box::new(*self)
+    }
+
+    fn conjugate(&self, person: &GrammaticalPerson) -> String {
+        match person {
+            GrammaticalPerson::ThirdPersonSingularGendered => String::from("pushes"),
+            _ => String::from("push"),
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct ToReel;
+
+impl TransitiveVerbTrait for ToReel {
+    fn clone_box(&self) -> Box<dyn TransitiveVerbTrait> {
+        Box::new(*self)
+    }
+
+    fn conjugate(&self, person: &GrammaticalPerson) -> String {
+        match person {
+            GrammaticalPerson::ThirdPersonSingularGendered => String::from("reels"),
+            _ => String::from("reel"),
+        }
+    }
+}
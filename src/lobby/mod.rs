@@ -1,6 +1,8 @@
 use std::{
     net::SocketAddr,
     io,
+    path::PathBuf,
+    time::{Duration, Instant},
 };
 
 use tokio::{
@@ -13,12 +15,19 @@ use tokio::{
 use tokio_util::io::SyncIoBridge;
 
 use crate::{
+    character_creation::create_bot,
     logging::{
         Logger,
         LoggerImpl,
     },
     worldobject::{
-        components::controllers::net::controller::NetworkController,
+        components::{
+            controllers::{
+                ai::personality::Personality,
+                net::controller::NetworkController
+            },
+            health::Vitality,
+        },
         WorldObject,
         TypedWorldObject,
         human::{
@@ -27,11 +36,17 @@ use crate::{
     },
     world::{
         World,
-        coord::WorldCoord
+        coord::WorldCoord,
+        rng::WorldRng
     },
     quantities::distance::meters
 };
 
+// how often the world is saved while it is played; a save writes out
+// the whole world, so it isn't done after every turn, and whatever
+// happened since the last one can be recovered from the journal
+const SAVE_INTERVAL: Duration = Duration::from_secs(30);
+
 #[derive(Debug)]
 pub enum HostError {
     AddCharacterError(Box<dyn std::error::Error>),
@@ -55,8 +70,12 @@ pub async fn host(
     logger: Logger<impl LoggerImpl + 'static>,
     new_controller_logger: Box<dyn Fn() -> Logger<Box<dyn LoggerImpl>>>,
     mut world: World,
+    save_path: PathBuf,
+    bots: usize,
 ) -> Result<(), Box<dyn std::error::Error>> {
     let mut lobby = Lobby::new(logger, new_controller_logger);
+    lobby.logger.info(format!("Hosting world with seed {}", world.seed())).await;
+    lobby.add_bots(bots, &mut world.fork_rng())?;
 
     let listener = TcpListener::bind(("0.0.0.0", 25565))
         .await
@@ -70,7 +89,10 @@ pub async fn host(
             stream_and_socket_addr_result = listener.accept() => match stream_and_socket_addr_result {
                 Ok((stream, socket_addr)) => {
                     lobby.logger.info(format!("Received connection from {}", socket_addr)).await;
-                    lobby.register_connection(stream, socket_addr).await;
+                    if let Err(err) = lobby.register_connection(stream, socket_addr).await {
+                        println!("Failed to register connection from {}: {}", socket_addr, err);
+                        lobby.logger.error(format!("Failed to register connection from {}: {}", socket_addr, err)).await;
+                    }
                 },
                 Err(e) => return Err(Box::new(std::io::Error::new(std::io::ErrorKind::Other, e.to_string()))),
             },
@@ -82,11 +104,18 @@ pub async fn host(
     };
 
     for character in lobby.characters {
-        println!("Adding character to world: {}", character.name());
-        world.add_object(character.name(), character, WorldCoord::new(meters(0.0), meters(0.0)));
+        join_world(&mut world, character, WorldCoord::new(meters(0.0), meters(0.0)))?;
+    }
+
+    // the journal is kept alongside the save, and the game can be replayed from it
+    let journal_path = save_path.with_extension("journal");
+    match world.open_journal(&journal_path) {
+        Ok(()) => println!("Recording the game to {}", journal_path.display()),
+        Err(err) => println!("Error opening journal {}: {}", journal_path.display(), err),
     }
     println!("\n");
 
+    let mut last_saved = Instant::now();
     loop {
         match world.update().await {
             Ok(()) => (),
@@ -94,7 +123,47 @@ pub async fn host(
                 println!("Error updating world: {:?}", err);
             }
         }
+
+        if last_saved.elapsed() >= SAVE_INTERVAL {
+            if let Err(err) = world.save(&save_path) {
+                println!("Error saving world: {}", err);
+            }
+            last_saved = Instant::now();
+        }
+    }
+}
+
+// join_world places a character into the world.  If the world was loaded
+// from a save and already contains an uncontrolled character of the same
+// name and type, the character's controller is handed to the saved
+// character instead, so that players resume where they left off; a saved
+// character who has since died is left where it fell, and the player
+// joins afresh.
+pub fn join_world(
+    world: &mut World,
+    mut character: Box<dyn WorldObject>,
+    position: WorldCoord,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let name = character.name();
+
+    let saved_character = world.objects.values_mut()
+        .map(|(_, object)| object)
+        .find(|object| object.name() == name && object.type_tag() == character.type_tag() && object.controller().is_err() && object.vitality() != Vitality::Dead);
+
+    match saved_character {
+        Some(saved_character) => {
+            println!("Restoring character from save: {}", name);
+            let controller = character.take_controller()?;
+            saved_character.set_controller(controller)
+                .map_err(|(_, err)| err)?;
+        },
+        None => {
+            println!("Adding character to world: {}", name);
+            world.add_object(character, position);
+        }
     }
+
+    Ok(())
 }
 
 pub struct Lobby {
@@ -105,13 +174,17 @@ pub struct Lobby {
 
 #[derive(Debug)]
 pub enum LobbyError {
-    HumanDeserializeError(String)
+    HumanDeserializeError(String),
+    ConnectionClosed,
+    InvalidJson(serde_json::Error),
 }
 
 impl std::fmt::Display for LobbyError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::HumanDeserializeError(error) => write!(f, "Error deserializing human: {}", error),
+            Self::ConnectionClosed => write!(f, "Connection closed before character information was received"),
+            Self::InvalidJson(error) => write!(f, "Invalid character information: {}", error),
         }
     }
 }
@@ -140,13 +213,25 @@ impl Lobby {
         Ok(())
     }
 
+    // add_bots fills the lobby with the given number of bots,
+    // taking each personality in turn
+    fn add_bots(&mut self, count: usize, rng: &mut WorldRng) -> Result<(), Box<dyn std::error::Error>> {
+        for (number, personality) in (1..=count).zip(Personality::ALL.into_iter().cycle()) {
+            self.add_character(create_bot(personality.bot_name(number), personality, rng))?;
+        }
+
+        Ok(())
+    }
+
     async fn register_connection(&mut self, mut stream: TcpStream, _:  SocketAddr) -> Result<(), Box<dyn std::error::Error>> {
         println!("Reading character information from connection...");
         let mut json_stream = serde_json::Deserializer::from_reader(SyncIoBridge::new(&mut stream)).into_iter::<serde_json::Value>();
 
         let next_json = tokio::task::block_in_place(|| {
             json_stream.next()
-        }).unwrap().unwrap();
+        })
+            .ok_or(LobbyError::ConnectionClosed)?
+            .map_err(LobbyError::InvalidJson)?;
 
         //println!("Received character information: {}", (&next_json).to_string());
 
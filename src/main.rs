@@ -2,6 +2,8 @@
 #![feature(async_trait_bounds)]
 
 mod worldobject;
+mod combat;
+mod physics;
 mod world;
 mod materials;
 mod quantities;
@@ -10,25 +12,53 @@ mod lang;
 mod util;
 mod character_creation;
 mod lobby;
+mod replay;
+#[cfg(test)]
+mod tests;
 
 use {
-    materials::Material,
-    lobby::host,
-    std::{collections::HashMap, io, fs::File},
-    logging::{basic::BasicLogger, channel::LoggingChannel},
+    materials::{Material, UnknownMaterialError},
+    lobby::{host, join_world},
+    replay::Replay,
+    std::{collections::HashMap, io, fs::File, path::{Path, PathBuf}},
+    logging::{Logger, LoggerImpl, basic::BasicLogger, channel::LoggingChannel, noop::NoopLogger},
     world::{World, coord::WorldCoord},
-    worldobject::{WorldObject, components::inventory::item::{
+    worldobject::{components::inventory::item::{
         sword::Sword, wand::Wand}, rat::Rat,
-        components::controllers::net::client::NetworkHumanControllerClient
+        components::controllers::{net::client::NetworkHumanControllerClient, commands::registry::CommandRegistry},
+        registry::Registry
     },
     quantities::{distance::meters, mass::kilograms, speed::meters_per_second}
 };
 
+// the file the host writes the world to after every round,
+// unless the world was loaded from a different file
+const DEFAULT_SAVE_PATH: &str = "world.json";
+
 #[tokio::main]
 async fn main() {
+    // the commands and types the game knows; anything added to these
+    // registries has to be added before they are installed
+    if let Err(err) = CommandRegistry::builtin().install() {
+        println!("Failed to install the command registry: {}", err);
+        return;
+    }
+    if let Err(err) = Registry::builtin().install() {
+        println!("Failed to install the type registry: {}", err);
+        return;
+    }
+
+    // materials in the working directory take the place of the built-in ones
+    if Path::new(materials::MATERIALS_PATH).exists() {
+        if let Err(err) = materials::load(materials::MATERIALS_PATH) {
+            println!("Failed to load {}: {}", materials::MATERIALS_PATH, err);
+            return;
+        }
+    }
+
     let character = character_creation::create_character();
 
-    println!("HOST or JOIN?");
+    println!("HOST, JOIN, LOAD, or REPLAY?");
 
     let mut choice = String::new();
     io::stdin().read_line(&mut choice).unwrap();
@@ -43,40 +73,46 @@ async fn main() {
     // in a thread-safe manner.
     let logging_channel = LoggingChannel::new(logger);
 
-    if choice == "host" {
-        let mut world = World::new(logging_channel.logger());
-
-        // populate non-character objects
-        world.add_object(
-            String::from("sword"),
-            Box::new(Sword::new(
-                meters(1.0),
-                Material::Iron
-            )),
-            WorldCoord::new(meters(1.0), meters(0.0))
-        );
-        world.add_object(
-            String::from("rat"),
-            Box::new(Rat::new(
-                kilograms(1.0),
-                meters_per_second(1.0)
-            )),
-            WorldCoord::new(meters(2.0), meters(0.0))
-        );
-        world.add_object(
-            String::from("wand"),
-            Box::new(Wand),
-            WorldCoord::new(meters(3.0), meters(0.0))
-        );
+    if choice == "host" || choice == "load" {
+        let (mut world, save_path) = if choice == "load" {
+            println!("Enter the path of the save file to load:");
+
+            let mut save_path = String::new();
+            io::stdin().read_line(&mut save_path).unwrap();
+            let save_path = PathBuf::from(save_path.trim());
+
+            (World::load(&save_path, logging_channel.logger()).unwrap(), save_path)
+        } else {
+            // the same seed, given the same commands, plays out the same game
+            println!("Enter a seed for the world (press enter for a random one):");
+            let mut seed = String::new();
+            io::stdin().read_line(&mut seed).unwrap();
+            let seed = seed.trim().parse::<u64>().unwrap_or_else(|_| rand::random());
+            println!("Using seed {}", seed);
+
+            match new_world(logging_channel.logger(), seed) {
+                Ok(world) => (world, PathBuf::from(DEFAULT_SAVE_PATH)),
+                Err(err) => {
+                    println!("Failed to create the world: {}", err);
+                    return;
+                }
+            }
+        };
+
+        // bots join alongside the players
+        println!("How many bots should join? (press enter for none)");
+        let mut bots = String::new();
+        io::stdin().read_line(&mut bots).unwrap();
+        let bots = bots.trim().parse::<usize>().unwrap_or(0);
 
         // add local player
-        world.add_object(
-            character.name(),
+        join_world(
+            &mut world,
             Box::new(character),
             WorldCoord::new(meters(0.0), meters(0.0))
-        );
+        ).unwrap();
 
-        host(logging_channel.logger(), Box::new(move || logging_channel.logger().to_dyn()), world).await.unwrap();
+        host(logging_channel.logger(), Box::new(move || logging_channel.logger().to_dyn()), world, save_path, bots).await.unwrap();
     } else if choice == "join" {
         println!("Enter the IP address of the lobby you want to join:");
 
@@ -85,5 +121,63 @@ async fn main() {
         ip_address = ip_address.trim().to_string();
 
         NetworkHumanControllerClient::connect(ip_address, character).await.unwrap();
+    } else if choice == "replay" {
+        println!("Enter the path of the journal to replay:");
+
+        let mut journal_path = String::new();
+        io::stdin().read_line(&mut journal_path).unwrap();
+        let replay = Replay::load(journal_path.trim()).unwrap();
+
+        println!("Whose point of view should the game be seen from? (press enter for everyone's)");
+        let mut viewer = String::new();
+        io::stdin().read_line(&mut viewer).unwrap();
+        let viewer = viewer.trim().to_lowercase();
+
+        // everything the replay does was logged when the game was hosted,
+        // so it isn't logged again; seen from everyone's point of view,
+        // each message says who received it
+        let result = replay.run(NoopLogger::new, |label, message| {
+            if viewer.is_empty() {
+                if !message.trim().is_empty() {
+                    println!("[{}] {}", label, message);
+                }
+            } else if label.eq_ignore_ascii_case(&viewer) {
+                println!("{}", message);
+            }
+        }).await;
+
+        match result {
+            Ok(()) => println!("The replay is over"),
+            Err(err) => println!("Error replaying journal: {}", err),
+        }
     }
+}
+
+// new_world creates a fresh world, seeded with the given seed,
+// populated with the default non-character objects; the materials in
+// use may have been loaded from a file that lacks some of them
+fn new_world(logger: Logger<impl LoggerImpl + 'static>, seed: u64) -> Result<World, UnknownMaterialError> {
+    let mut world = World::with_seed(logger, seed);
+
+    // populate non-character objects
+    world.add_object(
+        Box::new(Sword::new(
+            meters(1.0),
+            Material::named("iron")?
+        )),
+        WorldCoord::new(meters(1.0), meters(0.0))
+    );
+    world.add_object(
+        Box::new(Rat::new(
+            kilograms(1.0),
+            meters_per_second(1.0)
+        )),
+        WorldCoord::new(meters(2.0), meters(0.0))
+    );
+    world.add_object(
+        Box::new(Wand),
+        WorldCoord::new(meters(3.0), meters(0.0))
+    );
+
+    Ok(world)
 }
\ No newline at end of file
@@ -1,18 +1,218 @@
-use std::fmt;
+// This module houses materials and their physical properties.
+//
+// Materials are data rather than code: every material the game knows
+// about is read from a JSON file, so adding wood, stone or silver is a
+// matter of adding an entry there.  The game ships with materials.json
+// built in; a materials.json in the working directory is loaded in its
+// place at startup, so materials can be changed without rebuilding.
+// Each entry gives the material's name, its density in kilograms per
+// cubic meter, its hardness on the Mohs scale, how well its edges keep
+// their sharpness, from 0 to 1, and the temperature it melts at in
+// degrees Celsius, or null for materials that burn rather than melt.
+//
+// Objects refer to materials by name, and are saved with the name alone.
 
-#[derive(Clone)]
-pub enum Material {
-    Bronze,
-    Iron,
-    Steel,
+use std::{
+    collections::HashMap,
+    fmt,
+    fs,
+    io,
+    path::Path,
+    sync::OnceLock
+};
+
+use serde::{Serialize, Serializer};
+
+use crate::quantities::{
+    Quantity,
+    mass::{Mass, kilograms},
+    density::{Density, kilograms_per_cubic_meter},
+    volume::{Volume, cubic_meters}
+};
+
+// the materials the game ships with
+const BUILT_IN_MATERIALS: &str = include_str!("materials.json");
+
+// where to find materials to use in place of the built-in ones
+pub const MATERIALS_PATH: &str = "materials.json";
+
+// how much sharpness an edge loses striking something as hard as itself,
+// before its material's sharpness retention is taken into account
+const EDGE_WEAR: f64 = 0.05;
+
+static MATERIALS: OnceLock<HashMap<String, Material>> = OnceLock::new();
+
+#[derive(Debug)]
+pub enum MaterialsError {
+    FailedToRead(io::Error),
+    FailedToDecode(serde_json::Error),
+    NotAList,
+    InvalidMaterial(usize, String),
+    AlreadyLoaded,
 }
 
-impl fmt::Display for Material {
+impl fmt::Display for MaterialsError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Self::Bronze => write!(f, "bronze"),
-            Self::Iron => write!(f, "iron"),
-            Self::Steel => write!(f, "steel")
+            Self::FailedToRead(err) => write!(f, "failed to read materials: {}", err),
+            Self::FailedToDecode(err) => write!(f, "failed to decode materials: {}", err),
+            Self::NotAList => write!(f, "materials should be a list"),
+            Self::InvalidMaterial(index, err) => write!(f, "invalid material at index {}: {}", index, err),
+            Self::AlreadyLoaded => write!(f, "materials were already in use"),
+        }
+    }
+}
+
+impl std::error::Error for MaterialsError {}
+
+// parse reads a list of materials, keyed by name
+fn parse(json: &str) -> Result<HashMap<String, Material>, MaterialsError> {
+    let value = serde_json::from_str::<serde_json::Value>(json)
+        .map_err(MaterialsError::FailedToDecode)?;
+
+    value.as_array()
+        .ok_or(MaterialsError::NotAList)?
+        .iter()
+        .enumerate()
+        .map(|(index, entry)| Material::try_from(entry).map_err(|err| MaterialsError::InvalidMaterial(index, err)))
+        .map(|material| material.map(|material| (material.name.clone(), material)))
+        .collect()
+}
+
+// load reads the materials in the file at the given path for use in place
+// of the built-in ones; it must be called before any material is looked up
+pub fn load(path: impl AsRef<Path>) -> Result<(), MaterialsError> {
+    let json = fs::read_to_string(path).map_err(MaterialsError::FailedToRead)?;
+    let materials = parse(&json)?;
+
+    MATERIALS.set(materials).map_err(|_| MaterialsError::AlreadyLoaded)
+}
+
+// materials returns every material the game knows about; unless others
+// were loaded, these are the built-in ones, which are checked by a test,
+// so should they fail to parse anyway no material will be found
+fn materials() -> &'static HashMap<String, Material> {
+    MATERIALS.get_or_init(|| parse(BUILT_IN_MATERIALS).unwrap_or_default())
+}
+
+pub struct Material {
+    name: String,
+    density: Quantity<Density>,
+    hardness: f64,
+    sharpness_retention: f64,
+    melting_point: Option<f64>,
+}
+
+#[derive(Debug)]
+pub struct UnknownMaterialError(String);
+
+impl fmt::Display for UnknownMaterialError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "no material named \"{}\"", self.0)
+    }
+}
+
+impl std::error::Error for UnknownMaterialError {}
+
+impl Material {
+    // named returns the material with the given name
+    pub fn named(name: &str) -> Result<&'static Material, UnknownMaterialError> {
+        materials().get(name)
+            .ok_or_else(|| UnknownMaterialError(name.to_string()))
+    }
+
+    // how hard the material is, on the Mohs scale
+    pub fn hardness(&self) -> f64 {
+        self.hardness
+    }
+
+    // mass_of returns the mass of the given volume of the material
+    pub fn mass_of(&self, volume: &Quantity<Volume>) -> Quantity<Mass> {
+        let density = (&self.density / &kilograms_per_cubic_meter(1.0)).cancel().0.0;
+        let volume = (volume / &cubic_meters(1.0)).cancel().0.0;
+
+        kilograms(density * volume)
+    }
+
+    // wear returns how much sharpness an edge or point of the material loses
+    // striking something of the given hardness; edges dull faster on harder
+    // things, and edges of materials that keep their sharpness dull slower
+    pub fn wear(&self, target_hardness: f64) -> f64 {
+        EDGE_WEAR * (target_hardness / self.hardness) * (1.0 - self.sharpness_retention)
+    }
+
+    // describe returns the material's name along with how hard it is
+    // and what heat it takes to melt it
+    pub fn describe(&self) -> String {
+        match self.melting_point {
+            Some(melting_point) => format!("{} ({} on the Mohs scale, melting at {} °C)", self.name, self.hardness, melting_point),
+            None => format!("{} ({} on the Mohs scale, burning rather than melting)", self.name, self.hardness),
         }
     }
-}
\ No newline at end of file
+}
+
+impl fmt::Display for Material {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+impl Serialize for Material {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.name)
+    }
+}
+
+impl TryFrom<&serde_json::Value> for Material {
+    type Error = String;
+
+    fn try_from(value: &serde_json::Value) -> Result<Self, Self::Error> {
+        let name = value.get("name").and_then(|v| v.as_str()).ok_or("name not found")?.to_string();
+        let density = value.get("density").and_then(|v| v.as_f64()).ok_or("density not found")?;
+        let hardness = value.get("hardness").and_then(|v| v.as_f64()).ok_or("hardness not found")?;
+        let sharpness_retention = value.get("sharpness_retention").and_then(|v| v.as_f64()).ok_or("sharpness_retention not found")?;
+        let melting_point = value.get("melting_point").and_then(|v| v.as_f64());
+        if hardness <= 0.0 {
+            return Err(format!("{} has no hardness", name));
+        }
+        if !(0.0..=1.0).contains(&sharpness_retention) {
+            return Err(format!("{} keeps its sharpness by {}, which is not between 0 and 1", name, sharpness_retention));
+        }
+        Ok(Material { name, density: kilograms_per_cubic_meter(density), hardness, sharpness_retention, melting_point })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BUILT_IN_MATERIALS, Material, MaterialsError, parse};
+
+    #[test]
+    fn built_in_materials_parse() {
+        let materials = parse(BUILT_IN_MATERIALS).unwrap();
+        assert!(materials.contains_key("iron"));
+        assert!(materials.contains_key("steel"));
+    }
+
+    #[test]
+    fn invalid_materials_are_reported() {
+        assert!(matches!(parse("{"), Err(MaterialsError::FailedToDecode(_))));
+        assert!(matches!(parse("{}"), Err(MaterialsError::NotAList)));
+        assert!(matches!(
+            parse(r#"[{ "name": "iron", "density": 7870.0, "hardness": 4.0, "sharpness_retention": 0.6 }, { "name": "mud" }]"#),
+            Err(MaterialsError::InvalidMaterial(1, _))
+        ));
+        assert!(matches!(
+            parse(r#"[{ "name": "iron", "density": 7870.0, "hardness": 4.0, "sharpness_retention": 1.5 }]"#),
+            Err(MaterialsError::InvalidMaterial(0, _))
+        ));
+    }
+
+    #[test]
+    fn edges_wear_faster_on_harder_things_and_slower_if_they_keep_their_sharpness() {
+        let steel = Material::named("steel").unwrap();
+        let iron = Material::named("iron").unwrap();
+
+        assert!(steel.wear(6.0) > steel.wear(1.0));
+        assert!(iron.wear(2.0) > steel.wear(2.0));
+    }
+}
@@ -0,0 +1,88 @@
+// This module houses the mechanics of motion.
+//
+// A force acting on an object accelerates it, a = F/m, and an object
+// accelerated from rest for a time t covers ½·a·t².  Everything rests on
+// the ground, so friction, μ·m·g, takes away from any force that would
+// move it along, and a force no stronger than friction moves it not at all.
+// Heavy objects therefore barely budge under a blow that sends light ones
+// flying, although nothing is flung further than a few meters.
+
+use crate::quantities::{
+    Quantity,
+    force::{Force, newtons},
+    mass::{Mass, kilograms},
+    acceleration::{Acceleration, meters_per_second_squared},
+    duration::{Duration, seconds},
+    distance::{Distance, meters}
+};
+
+// how strongly the ground holds on to whatever rests on it
+const FRICTION_COEFFICIENT: f64 = 0.5;
+
+// gravitational acceleration, in meters per second squared
+const GRAVITY: f64 = 9.8;
+
+// the furthest, in meters, a single force can carry anything
+const MAX_DISPLACEMENT_METERS: f64 = 10.0;
+
+// acceleration returns how quickly the force speeds up the mass, a = F/m,
+// once friction has taken its share; a massless object isn't accelerated
+pub fn acceleration(force: &Quantity<Force>, mass: &Quantity<Mass>) -> Quantity<Acceleration> {
+    let mass = (mass / &kilograms(1.0)).cancel().0.0;
+    if mass <= 0.0 {
+        return meters_per_second_squared(0.0);
+    }
+
+    let force = (force / &newtons(1.0)).cancel().0.0;
+    let friction = FRICTION_COEFFICIENT * mass * GRAVITY;
+
+    meters_per_second_squared((force - friction).max(0.0) / mass)
+}
+
+// displacement returns how far the force carries the mass, starting
+// from rest, when it acts on it for the given duration, d = ½·a·t²
+pub fn displacement(force: &Quantity<Force>, mass: &Quantity<Mass>, duration: &Quantity<Duration>) -> Quantity<Distance> {
+    let acceleration = (acceleration(force, mass) / meters_per_second_squared(1.0)).cancel().0.0;
+    let duration = (duration / &seconds(1.0)).cancel().0.0;
+
+    meters((0.5 * acceleration * duration * duration).min(MAX_DISPLACEMENT_METERS))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::quantities::{
+        force::newtons,
+        mass::kilograms,
+        duration::seconds,
+        distance::meters
+    };
+
+    use super::displacement;
+
+    #[test]
+    fn a_force_no_stronger_than_friction_moves_nothing() {
+        assert_eq!(displacement(&newtons(4.9), &kilograms(1.0), &seconds(1.0)), meters(0.0));
+        assert_eq!(displacement(&newtons(100.0), &kilograms(0.0), &seconds(1.0)), meters(0.0));
+    }
+
+    #[test]
+    fn the_same_force_carries_light_objects_further_than_heavy_ones() {
+        let rat = displacement(&newtons(500.0), &kilograms(1.0), &seconds(0.05));
+        let human = displacement(&newtons(500.0), &kilograms(70.0), &seconds(0.05));
+
+        assert!(rat > meters(0.5));
+        assert!(human < meters(0.01));
+    }
+
+    #[test]
+    fn a_force_acting_from_rest_covers_half_a_t_squared() {
+        // 24.9 N against 4.9 N of friction leaves 20 m/s² on a kilogram
+        let distance = displacement(&newtons(24.9), &kilograms(1.0), &seconds(0.5));
+        assert!(((distance / meters(1.0)).cancel().0.0 - 2.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn nothing_is_flung_further_than_the_limit() {
+        assert_eq!(displacement(&newtons(1e9), &kilograms(1.0), &seconds(1.0)), meters(10.0));
+    }
+}
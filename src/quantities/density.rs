@@ -2,5 +2,10 @@ use crate::quantities::product;
 use crate::quantities::per;
 use crate::quantities::mass;
 use crate::quantities::volume;
+use crate::quantities;
 
-pub type Density = product::Product<mass::Mass, per::Per<volume::Volume>>;
\ No newline at end of file
+pub type Density = product::Product<mass::Mass, per::Per<volume::Volume>>;
+
+pub fn kilograms_per_cubic_meter(n: f64) -> quantities::Quantity<Density> {
+    mass::kilograms(n) / volume::cubic_meters(1.0)
+}
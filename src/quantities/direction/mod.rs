@@ -3,7 +3,7 @@ use serde::Deserialize;
 
 use std::fmt;
 
-#[derive(Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum DirectionHorizontal {
     Left,
     Right,
@@ -44,6 +44,15 @@ impl TryFrom<&str> for DirectionHorizontal {
     }
 }
 
+impl DirectionHorizontal {
+    pub fn opposite(self) -> Self {
+        match self {
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+        }
+    }
+}
+
 impl fmt::Display for DirectionHorizontal {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -53,7 +62,7 @@ impl fmt::Display for DirectionHorizontal {
     }
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum DirectionVertical {
     Up,
     Down,
@@ -83,12 +92,23 @@ impl fmt::Display for DirectionVertical {
     }
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum DirectionHorizontalOrVertical {
     Horizontal(DirectionHorizontal),
     Vertical(DirectionVertical),
 }
 
+impl DirectionHorizontalOrVertical {
+    // adverbial returns how the direction reads after a
+    // verb or a distance, e.g. "to the left" or "up"
+    pub fn adverbial(self) -> String {
+        match self {
+            Self::Horizontal(horizontal) => format!("to the {}", horizontal),
+            Self::Vertical(vertical) => vertical.to_string(),
+        }
+    }
+}
+
 impl fmt::Display for DirectionHorizontalOrVertical {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
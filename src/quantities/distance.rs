@@ -44,4 +44,88 @@ pub fn centimeters(n: f64) -> quantities::Quantity<Distance> {
 
 pub fn meters(n: f64) -> quantities::Quantity<Distance> {
     quantities::Quantity(Distance(n))
-}
\ No newline at end of file
+}
+
+pub fn kilometers(n: f64) -> quantities::Quantity<Distance> {
+    quantities::Quantity(Distance(n * 1000.0))
+}
+
+// unit returns the constructor for the named unit of distance, e.g. "m" or "meters"
+fn unit(name: &str) -> Option<fn(f64) -> quantities::Quantity<Distance>> {
+    match name {
+        "" | "m" | "meter" | "meters" | "metre" | "metres" => Some(meters),
+        "cm" | "centimeter" | "centimeters" | "centimetre" | "centimetres" => Some(centimeters),
+        "km" | "kilometer" | "kilometers" | "kilometre" | "kilometres" => Some(kilometers),
+        _ => None,
+    }
+}
+
+#[derive(Debug)]
+pub struct InvalidDistanceError(String);
+
+impl std::fmt::Display for InvalidDistanceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid distance: {}", self.0)
+    }
+}
+
+impl std::error::Error for InvalidDistanceError {}
+
+// distances are written as a positive number followed by an optional unit,
+// e.g. "3", "3m", "3 m" or "2.5 meters"; without a unit, meters are assumed
+impl TryFrom<&str> for quantities::Quantity<Distance> {
+    type Error = InvalidDistanceError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let value = value.trim();
+        let unit_start = value.find(|c: char| c.is_alphabetic()).unwrap_or(value.len());
+        let (number, unit_name) = value.split_at(unit_start);
+
+        let number = number.trim().parse::<f64>()
+            .map_err(|_| InvalidDistanceError(value.to_string()))?;
+
+        // there's no going nowhere, or going backwards
+        if number <= 0.0 || !number.is_finite() {
+            return Err(InvalidDistanceError(value.to_string()));
+        }
+
+        let unit = unit(&unit_name.trim().to_lowercase())
+            .ok_or(InvalidDistanceError(value.to_string()))?;
+
+        Ok(unit(number))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::quantities::Quantity;
+
+    use super::{Distance, centimeters, meters, kilometers};
+
+    fn parse(input: &str) -> Option<Quantity<Distance>> {
+        Quantity::<Distance>::try_from(input).ok()
+    }
+
+    #[test]
+    fn parses_numbers_with_and_without_units() {
+        assert_eq!(parse("3"), Some(meters(3.0)));
+        assert_eq!(parse("3m"), Some(meters(3.0)));
+        assert_eq!(parse(" 2.5 Meters "), Some(meters(2.5)));
+        assert_eq!(parse("50 cm"), Some(centimeters(50.0)));
+        assert_eq!(parse("1km"), Some(kilometers(1.0)));
+    }
+
+    #[test]
+    fn rejects_distances_that_are_not_positive() {
+        assert_eq!(parse("0m"), None);
+        assert_eq!(parse("-100m"), None);
+        assert_eq!(parse("-0.5"), None);
+    }
+
+    #[test]
+    fn rejects_unknown_units_and_missing_numbers() {
+        assert_eq!(parse("3 furlongs"), None);
+        assert_eq!(parse("meters"), None);
+        assert_eq!(parse(""), None);
+    }
+}
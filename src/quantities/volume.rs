@@ -1,5 +1,10 @@
 use crate::quantities::product;
 use crate::quantities::distance;
 use crate::quantities::area;
+use crate::quantities;
 
-pub type Volume = product::Product<distance::Distance, area::Area>;
\ No newline at end of file
+pub type Volume = product::Product<distance::Distance, area::Area>;
+
+pub fn cubic_meters(n: f64) -> quantities::Quantity<Volume> {
+    distance::meters(n) * (distance::meters(1.0) * distance::meters(1.0))
+}
@@ -0,0 +1,251 @@
+// This module replays journals (see the journal module in world), e.g. for
+// a post-mortem, a bug report or to watch an old match again.  Each session
+// in the journal is played again from its start record, with every actor
+// handed a ScriptedController in place of whoever played it.  Before each
+// journaled turn, the command given on it is handed to the actor whose turn
+// it is, or, if it was rejected, the reason it was, so that the actor does
+// nothing and is told why, just as it was originally.  Every option picked
+// while the turn's action was carried out is handed to whoever picked it.
+//
+// The world is seeded, so a faithful replay hands every turn to the same
+// actor, with the same seed, as the original did.  The replay checks this
+// before every journaled turn, and that every journaled choice was asked
+// for during it, and stops where it first diverges, which happens when the
+// game has changed since the journal was recorded.
+
+use std::{
+    collections::BTreeMap,
+    fmt,
+    path::Path
+};
+
+use crate::{
+    logging::{Logger, LoggerImpl},
+    world::{
+        World,
+        WorldObjectGetError,
+        WorldUpdateError,
+        handle::{WorldObjectHandle, WorldObjectLabel},
+        journal::{Journal, JournalError, JournalRecord},
+        save::WorldLoadError
+    },
+    worldobject::components::controllers::{
+        commands::registry::{self, CommandRegistryError},
+        scripted::{Script, ScriptedController, Transcript}
+    }
+};
+
+#[derive(Debug)]
+pub enum ReplayError {
+    FailedToReadJournal(JournalError),
+    // the journal records commands before the start of any session
+    NoStartRecord,
+    // the journal records a choice on a turn with no command
+    StrayChoice(u64),
+    FailedToLoadWorld(WorldLoadError),
+    NoSuchActor(WorldObjectHandle, WorldObjectGetError),
+    FailedToSetController(WorldObjectHandle, Box<dyn std::error::Error>),
+    InvalidCommand(u64, CommandRegistryError),
+    FailedToUpdateWorld(u64, WorldUpdateError),
+    // nothing in the world takes turns any more
+    Stalled(u64),
+    Diverged { turn: u64, reason: String },
+}
+
+impl fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::FailedToReadJournal(err) => write!(f, "failed to read journal: {}", err),
+            Self::NoStartRecord => write!(f, "the journal records commands before the start of any session"),
+            Self::StrayChoice(turn) => write!(f, "the journal records a choice on turn {}, on which no command was given", turn),
+            Self::FailedToLoadWorld(err) => write!(f, "failed to load the world a session started with: {}", err),
+            Self::NoSuchActor(handle, err) => write!(f, "failed to find actor \"{}\": {}", handle, err),
+            Self::FailedToSetController(handle, err) => write!(f, "failed to hand a script to actor \"{}\": {}", handle, err),
+            Self::InvalidCommand(turn, err) => write!(f, "invalid command on turn {}: {}", turn, err),
+            Self::FailedToUpdateWorld(turn, err) => write!(f, "failed to play turn {}: {}", turn, err),
+            Self::Stalled(turn) => write!(f, "nothing takes a turn after turn {}", turn),
+            Self::Diverged { turn, reason } => write!(f, "the replay diverged from the journal on turn {}: {}", turn, reason),
+        }
+    }
+}
+
+impl std::error::Error for ReplayError {}
+
+// JournaledCommand is a command or rejection record from the journal
+struct JournaledCommand {
+    turn: u64,
+    actor: WorldObjectHandle,
+    label: WorldObjectLabel,
+    seed: u64,
+    // the command given, or why the one given was rejected
+    command: Result<serde_json::Value, String>,
+    // the options picked while the command's action was carried out, and by whom
+    choices: Vec<(WorldObjectHandle, usize)>,
+}
+
+// Session is everything journaled from when a world was hosted
+// until it was hosted again, or until the journal ends
+struct Session {
+    snapshot: serde_json::Value,
+    actors: Vec<WorldObjectHandle>,
+    commands: Vec<JournaledCommand>,
+}
+
+// Actor is a character being replayed, along with
+// its script and what it has been told so far
+struct Actor {
+    label: WorldObjectLabel,
+    script: Script,
+    transcript: Transcript,
+}
+
+pub struct Replay {
+    sessions: Vec<Session>,
+}
+
+impl Replay {
+    // load reads the journal at the given path
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ReplayError> {
+        let records = Journal::read(path).map_err(ReplayError::FailedToReadJournal)?;
+        Self::from_records(records)
+    }
+
+    pub fn from_records(records: Vec<JournalRecord>) -> Result<Self, ReplayError> {
+        let mut sessions: Vec<Session> = Vec::new();
+        for record in records {
+            match record {
+                JournalRecord::Start { snapshot, actors } => sessions.push(Session { snapshot, actors, commands: Vec::new() }),
+                JournalRecord::Command { turn, actor, label, seed, command } => sessions.last_mut()
+                    .ok_or(ReplayError::NoStartRecord)?
+                    .commands.push(JournaledCommand { turn, actor, label, seed, command: Ok(command), choices: Vec::new() }),
+                JournalRecord::Rejected { turn, actor, label, seed, reason } => sessions.last_mut()
+                    .ok_or(ReplayError::NoStartRecord)?
+                    .commands.push(JournaledCommand { turn, actor, label, seed, command: Err(reason), choices: Vec::new() }),
+                JournalRecord::Choice { turn, actor, index, .. } => sessions.last_mut()
+                    .ok_or(ReplayError::NoStartRecord)?
+                    .commands.last_mut()
+                    .filter(|journaled| journaled.turn == turn)
+                    .ok_or(ReplayError::StrayChoice(turn))?
+                    .choices.push((actor, index)),
+            }
+        }
+
+        Ok(Self { sessions })
+    }
+
+    // run replays every session in turn, up to the last journaled command
+    // of each, handing every message an actor receives to on_message along
+    // with the actor's label as the message arrives; each session's world
+    // logs to a logger of its own
+    pub async fn run<L: LoggerImpl + 'static>(self, new_logger: impl Fn() -> Logger<L>, mut on_message: impl FnMut(&WorldObjectLabel, &str)) -> Result<(), ReplayError> {
+        for session in self.sessions {
+            Self::run_session(session, new_logger(), &mut on_message).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn run_session(session: Session, logger: Logger<impl LoggerImpl + 'static>, on_message: &mut impl FnMut(&WorldObjectLabel, &str)) -> Result<(), ReplayError> {
+        let mut world = World::from_snapshot(session.snapshot, logger)
+            .map_err(ReplayError::FailedToLoadWorld)?;
+
+        let mut actors = BTreeMap::new();
+        for handle in session.actors {
+            let label = world.label_of(&handle)
+                .map_err(|err| ReplayError::NoSuchActor(handle, err))?
+                .clone();
+
+            let controller = ScriptedController::new([], []);
+            let actor = Actor { label, script: controller.script(), transcript: controller.transcript() };
+            world.get_object_mut(&handle)
+                .map_err(|err| ReplayError::NoSuchActor(handle, err))?
+                .set_controller(Box::new(controller))
+                .map_err(|(_, err)| ReplayError::FailedToSetController(handle, err))?;
+
+            actors.insert(handle, actor);
+        }
+
+        for journaled in session.commands {
+            if journaled.turn <= world.turn() {
+                return Err(ReplayError::Diverged {
+                    turn: journaled.turn,
+                    reason: format!("the journal goes back in time, to before turn {}", world.turn()),
+                });
+            }
+
+            while world.turn() + 1 < journaled.turn {
+                Self::play_turn(&mut world, &actors, on_message).await?;
+            }
+
+            let next_actor = world.turn_order().first().copied();
+            if next_actor != Some(journaled.actor) {
+                let actual = next_actor
+                    .and_then(|handle| world.label_of(&handle).ok().cloned())
+                    .unwrap_or_else(|| String::from("nothing"));
+                return Err(ReplayError::Diverged {
+                    turn: journaled.turn,
+                    reason: format!("it was {}'s turn, but it is {}'s", journaled.label, actual),
+                });
+            }
+            if world.next_seed() != journaled.seed {
+                return Err(ReplayError::Diverged {
+                    turn: journaled.turn,
+                    reason: String::from("the world's generator has gone astray"),
+                });
+            }
+
+            let Some(actor) = actors.get(&journaled.actor) else {
+                return Err(ReplayError::Diverged {
+                    turn: journaled.turn,
+                    reason: format!("{} wasn't taking turns when the session started", journaled.label),
+                });
+            };
+            match &journaled.command {
+                Ok(command) => actor.script.push(
+                    registry::command_from_json(command)
+                        .map_err(|err| ReplayError::InvalidCommand(journaled.turn, err))?
+                ),
+                Err(reason) => actor.script.reject(reason.clone()),
+            }
+
+            for (chooser, choice) in &journaled.choices {
+                let Some(chooser) = actors.get(chooser) else {
+                    return Err(ReplayError::Diverged {
+                        turn: journaled.turn,
+                        reason: format!("an object that wasn't taking turns when the session started chose on {}'s turn", journaled.label),
+                    });
+                };
+                chooser.script.choose(*choice);
+            }
+
+            Self::play_turn(&mut world, &actors, on_message).await?;
+
+            if let Some(chooser) = actors.values().find(|actor| actor.script.pending_choices() > 0) {
+                return Err(ReplayError::Diverged {
+                    turn: journaled.turn,
+                    reason: format!("{} made a choice that wasn't asked for", chooser.label),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    // play_turn plays the next turn, then hands on everything the actors were told during it
+    async fn play_turn(world: &mut World, actors: &BTreeMap<WorldObjectHandle, Actor>, on_message: &mut impl FnMut(&WorldObjectLabel, &str)) -> Result<(), ReplayError> {
+        let turn = world.turn();
+        world.update().await
+            .map_err(|err| ReplayError::FailedToUpdateWorld(turn + 1, err))?;
+        if world.turn() == turn {
+            return Err(ReplayError::Stalled(turn));
+        }
+
+        for actor in actors.values() {
+            for message in actor.transcript.take() {
+                on_message(&actor.label, &message);
+            }
+        }
+
+        Ok(())
+    }
+}
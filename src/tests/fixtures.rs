@@ -0,0 +1,33 @@
+// This module houses the objects unit tests throughout the game are built
+// from, so that every test that needs e.g. a human to act on gets the
+// same one.
+
+use crate::{
+    character_creation::human_body,
+    materials::Material,
+    quantities::{direction::DirectionHorizontal, distance::meters},
+    worldobject::{
+        components::{
+            controllers::Controller,
+            gender::Gender,
+            inventory::{Inventory, item::sword::Sword}
+        },
+        human::Human
+    }
+};
+
+// human returns a human with the given name, dominant arm and
+// inventory, whom nobody controls
+pub fn human(name: &str, dominant_arm: DirectionHorizontal, inventory: Inventory) -> Human {
+    Human::new(String::from(name), Gender::Female, human_body(), dominant_arm, inventory, None::<Box<dyn Controller>>)
+}
+
+// alice is a right-handed human carrying nothing
+pub fn alice() -> Human {
+    human("alice", DirectionHorizontal::Right, Inventory::new())
+}
+
+// sword returns a freshly forged sword a meter long, made of the given built-in material
+pub fn sword(material: &str) -> Sword {
+    Sword::new(meters(1.0), Material::named(material).unwrap())
+}
@@ -0,0 +1,168 @@
+// This module houses the transcript harness.  A scenario builds a world,
+// puts scripted players in it, plays it for a number of rounds, and then
+// compares what each player was told against a golden file, so that a
+// change in any action or in the way it is narrated shows up as a diff.
+//
+// Golden files live in src/tests/golden/<scenario>/<player>.txt.  To
+// accept changed transcripts, e.g. after deliberately rewording a
+// message, run the tests with BLESS_TRANSCRIPTS=1 and review the result.
+//
+// Every scenario's world is seeded, so whatever it leaves to chance
+// plays out the same way every time it is run.
+
+use std::{fs, path::PathBuf};
+
+use crate::{
+    character_creation::human_body,
+    logging::noop::NoopLogger,
+    quantities::{direction::DirectionHorizontal, distance::meters},
+    world::{World, coord::WorldCoord, handle::WorldObjectHandle},
+    worldobject::{
+        WorldObject,
+        components::{
+            controllers::scripted::{ScriptedController, Transcript},
+            gender::Gender,
+            inventory::Inventory
+        },
+        human::Human
+    }
+};
+
+// set this environment variable to overwrite the golden files
+// with the transcripts the scenarios actually produce
+const BLESS_VARIABLE: &str = "BLESS_TRANSCRIPTS";
+
+// the seed scenarios are played with, unless they ask for another
+const SCENARIO_SEED: u64 = 1;
+
+pub struct Scenario {
+    name: &'static str,
+    pub world: World,
+    // each player's name and transcript, in the order they joined
+    players: Vec<(String, Transcript)>,
+}
+
+impl Scenario {
+    pub fn new(name: &'static str) -> Self {
+        Self::with_seed(name, SCENARIO_SEED)
+    }
+
+    pub fn with_seed(name: &'static str, seed: u64) -> Self {
+        Self { name, world: World::with_seed(NoopLogger::new(), seed), players: Vec::new() }
+    }
+
+    // add_object places an object the given number of meters along the ground
+    pub fn add_object(&mut self, object: Box<dyn WorldObject>, x: f64) -> WorldObjectHandle {
+        self.world.add_object(object, WorldCoord::new(meters(x), meters(0.0)))
+    }
+
+    // add_player places a human the given number of meters along the ground,
+    // who gives the scripted commands, one per turn, and picks the given
+    // options whenever asked to choose
+    pub fn add_player(&mut self, name: &str, x: f64, script: &[&str], choices: &[usize]) -> WorldObjectHandle {
+        let controller = ScriptedController::from_lines(script.iter().copied(), choices.iter().copied())
+            .unwrap_or_else(|err| panic!("invalid script for {}: {}", name, err));
+        self.players.push((String::from(name), controller.transcript()));
+
+        let player = Human::new(String::from(name), Gender::Other, human_body(), DirectionHorizontal::Right, Inventory::new(), Some(controller));
+        self.add_object(Box::new(player), x)
+    }
+
+    // run runs the world for the given number of rounds
+    pub async fn run(&mut self, rounds: usize) {
+        for round in 0..rounds {
+            if let Err(err) = self.world.update().await {
+                panic!("{}: failed to update world in round {}: {:?}", self.name, round, err);
+            }
+        }
+    }
+
+    // transcripts returns each player's name and transcript so far
+    pub fn transcripts(&self) -> Vec<(String, String)> {
+        self.players.iter()
+            .map(|(player, transcript)| (player.clone(), transcript.text()))
+            .collect()
+    }
+
+    // play runs the world for the given number of rounds, then checks every
+    // player's transcript, failing with a diff of each one that has changed
+    pub async fn play(mut self, rounds: usize) {
+        self.run(rounds).await;
+
+        let bless = std::env::var_os(BLESS_VARIABLE).is_some();
+        let mut failures = Vec::new();
+        for (player, actual) in self.transcripts() {
+            let path = golden_path(self.name, &player);
+
+            if bless {
+                fs::create_dir_all(path.parent().unwrap()).unwrap();
+                fs::write(&path, &actual).unwrap();
+                continue;
+            }
+
+            match fs::read_to_string(&path) {
+                Ok(expected) if expected == actual => (),
+                Ok(expected) => failures.push(format!("{}'s transcript differs from {}:\n{}", player, path.display(), diff(&expected, &actual))),
+                Err(err) => failures.push(format!("failed to read {} ({}); run with {}=1 to create it", path.display(), err, BLESS_VARIABLE)),
+            }
+        }
+
+        if !failures.is_empty() {
+            panic!("{}: {}", self.name, failures.join("\n\n"));
+        }
+    }
+}
+
+fn golden_path(scenario: &str, player: &str) -> PathBuf {
+    [env!("CARGO_MANIFEST_DIR"), "src", "tests", "golden", scenario, &format!("{}.txt", player)].iter().collect()
+}
+
+// diff lists the lines of both texts, marking those that were only
+// expected with "-" and those that are new with "+"; lines are
+// matched up along their longest common subsequence
+fn diff(expected: &str, actual: &str) -> String {
+    let expected = expected.lines().collect::<Vec<_>>();
+    let actual = actual.lines().collect::<Vec<_>>();
+
+    // common[i][j] is the length of the longest common
+    // subsequence of expected[i..] and actual[j..]
+    let mut common = vec![vec![0; actual.len() + 1]; expected.len() + 1];
+    for i in (0..expected.len()).rev() {
+        for j in (0..actual.len()).rev() {
+            common[i][j] = if expected[i] == actual[j] {
+                common[i + 1][j + 1] + 1
+            } else {
+                common[i + 1][j].max(common[i][j + 1])
+            };
+        }
+    }
+
+    let mut lines = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < expected.len() || j < actual.len() {
+        if i < expected.len() && j < actual.len() && expected[i] == actual[j] {
+            lines.push(format!("  {}", expected[i]));
+            i += 1;
+            j += 1;
+        } else if i < expected.len() && (j == actual.len() || common[i + 1][j] >= common[i][j + 1]) {
+            lines.push(format!("- {}", expected[i]));
+            i += 1;
+        } else {
+            lines.push(format!("+ {}", actual[j]));
+            j += 1;
+        }
+    }
+
+    lines.join("\n")
+}
+
+#[test]
+fn diff_marks_changed_lines() {
+    let expected = "you punch bob\nbob suffers a bruise\nbob reels 1 m to the right\n";
+    let actual = "you punch bob\nbob suffers a serious bruise\nbob reels 1 m to the right\n";
+
+    assert_eq!(
+        diff(expected, actual),
+        "  you punch bob\n- bob suffers a bruise\n+ bob suffers a serious bruise\n  bob reels 1 m to the right"
+    );
+}
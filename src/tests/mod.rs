@@ -0,0 +1,11 @@
+// This module houses the game's tests, which play the game with nobody
+// at the keyboard: a soak test that lets bots loose for thousands of
+// rounds, scripted scenarios whose transcripts are checked against
+// golden files (see the harness module), and a journaled game that
+// is replayed.  It also houses the fixtures unit tests elsewhere share.
+
+pub mod fixtures;
+mod harness;
+mod replay;
+mod soak;
+mod transcripts;
@@ -0,0 +1,38 @@
+// The soak test plays round after round of bots in the default world,
+// with nobody watching, to shake out panics in World::update.  Each
+// round is seeded with its number, so a failing round can be replayed.
+
+use crate::{
+    character_creation::create_bot,
+    lobby::join_world,
+    logging::noop::NoopLogger,
+    new_world,
+    quantities::distance::meters,
+    world::coord::WorldCoord,
+    worldobject::components::controllers::ai::personality::Personality
+};
+
+// how many fresh worlds the soak test plays, how many
+// bots play in each, and for how many turns
+const SOAK_ROUNDS: usize = 50;
+const SOAK_BOTS: usize = 9;
+const SOAK_TURNS: usize = 200;
+
+#[tokio::test]
+async fn soak() {
+    for round in 0..SOAK_ROUNDS {
+        let mut world = new_world(NoopLogger::new(), round as u64).unwrap();
+        let mut rng = world.fork_rng();
+        for (number, personality) in (1..=SOAK_BOTS).zip(Personality::ALL.into_iter().cycle()) {
+            let bot = create_bot(personality.bot_name(number), personality, &mut rng);
+            let position = WorldCoord::new(meters(number as f64 - 5.0), meters(0.0));
+            join_world(&mut world, Box::new(bot), position).unwrap();
+        }
+
+        for turn in 0..SOAK_TURNS {
+            if let Err(err) = world.update().await {
+                panic!("round {}, turn {}: failed to update world: {:?}", round, turn, err);
+            }
+        }
+    }
+}
@@ -0,0 +1,138 @@
+// These scenarios are checked against golden transcripts (see the harness
+// module).  Most aim every blow and leave no rat to the AI, so that they
+// don't hinge on chance; skirmish leaves things to chance on purpose, to
+// check that a seeded world plays out the same way every time.
+
+use crate::{
+    character_creation::human_body,
+    quantities::{direction::DirectionHorizontal, mass::kilograms, speed::meters_per_second},
+    worldobject::{
+        TypedWorldObject,
+        components::{
+            controllers::ai::AiController,
+            gender::Gender,
+            inventory::{Inventory, item::wand::Wand}
+        },
+        human::Human,
+        rat::Rat
+    }
+};
+
+use super::{fixtures::sword, harness::Scenario};
+
+// a rat that does nothing but sit there
+fn idle_rat() -> Rat {
+    let mut rat = Rat::new(kilograms(1.0), meters_per_second(1.0));
+    _ = <Rat as TypedWorldObject>::take_controller(&mut rat);
+    rat
+}
+
+#[tokio::test]
+async fn brawl() {
+    let mut scenario = Scenario::new("brawl");
+    scenario.add_player("alice", 0.0, &["hit bob in head", "bite bob in left arm", "look", "push bob right"], &[]);
+    scenario.add_player("bob", 0.5, &["punch alice in torso", "move left 1m", "examine alice", "attack alice in legs"], &[]);
+    scenario.play(10).await;
+}
+
+#[tokio::test]
+async fn swordplay() {
+    let mut scenario = Scenario::new("swordplay");
+    scenario.add_object(Box::new(sword("steel")), 0.5);
+    scenario.add_player("alice", 0.0, &["pick up the sword", "wield sword", "examine bob", "slash bob in right arm", "inventory", "unwield sword", "drop sword"], &[]);
+    scenario.add_player("bob", 1.5, &["look", "look", "look", "look", "look", "look", "look"], &[]);
+    scenario.play(16).await;
+}
+
+#[tokio::test]
+async fn rats() {
+    let mut scenario = Scenario::new("rats");
+    scenario.add_object(Box::new(idle_rat()), 0.5);
+    scenario.add_object(Box::new(idle_rat()), 1.0);
+    scenario.add_player("alice", 0.0, &["x rat", "interact with rat#2", "hit rat", "take rat#2"], &[]);
+    scenario.play(24).await;
+}
+
+// unaimed blows land wherever chance has them land, and the rat is left to the AI
+fn skirmish() -> Scenario {
+    let mut scenario = Scenario::new("skirmish");
+    scenario.add_object(Box::new(Rat::new(kilograms(1.0), meters_per_second(1.0))), 0.5);
+    scenario.add_player("alice", 0.0, &["hit rat", "punch bob", "look", "punch bob", "hit rat"], &[]);
+    scenario.add_player("bob", -0.5, &["punch alice", "punch alice", "look", "punch alice", "punch alice"], &[]);
+    scenario
+}
+
+#[tokio::test]
+async fn chance() {
+    skirmish().play(20).await;
+}
+
+// the same seed and the same commands play out byte for byte the same
+#[tokio::test]
+async fn determinism() {
+    let mut first = skirmish();
+    let mut second = skirmish();
+    first.run(20).await;
+    second.run(20).await;
+
+    assert_eq!(first.transcripts(), second.transcripts());
+}
+
+// a phrase that describes more than one thing asks the player which they mean
+#[tokio::test]
+async fn ambiguity() {
+    let mut scenario = Scenario::new("ambiguity");
+    scenario.add_player("alice", 0.0, &["examine person", "interact with the person"], &[1, 0]);
+    scenario.add_player("bob", 1.0, &[], &[]);
+    scenario.add_player("carol", 2.0, &[], &[]);
+    scenario.play(8).await;
+}
+
+// the recipient of a gift is told of it in the second person
+#[tokio::test]
+async fn giving() {
+    let mut scenario = Scenario::new("giving");
+    scenario.add_object(Box::new(sword("steel")), 0.5);
+    scenario.add_player("alice", 0.0, &["pick up the sword", "give sword to bob"], &[]);
+    scenario.add_player("bob", 1.0, &["look", "inventory"], &[]);
+    scenario.add_player("carol", 1.5, &["look", "look"], &[]);
+    scenario.play(6).await;
+}
+
+// bob answers alice's offer with his wand, which alice accepts, and then
+// turns down her offer to trade it back, which alice is told as much of
+#[tokio::test]
+async fn trading() {
+    let mut scenario = Scenario::new("trading");
+    scenario.add_object(Box::new(sword("steel")), 0.0);
+    scenario.add_object(Box::new(Wand), 1.0);
+    scenario.add_player("alice", 0.0, &["pick up the sword", "trade sword with bob", "trade wand with bob", "inventory"], &[0, 0]);
+    scenario.add_player("bob", 1.0, &["pick up the wand", "look", "look"], &[0, 1]);
+    scenario.play(7).await;
+}
+
+// a character left to the AI turns down whatever it is offered
+#[tokio::test]
+async fn trading_with_a_bot() {
+    let mut scenario = Scenario::new("trading_with_a_bot");
+    scenario.add_object(Box::new(Wand), 0.0);
+    scenario.add_player("alice", 0.0, &["pick up the wand", "trade wand with dave", "inventory"], &[]);
+
+    let mut inventory = Inventory::new();
+    inventory.stow(Box::new(sword("steel")));
+    let bot = Human::new(String::from("dave"), Gender::Other, human_body(), DirectionHorizontal::Right, inventory, Some(AiController::new(Vec::new())));
+    scenario.add_object(Box::new(bot), 0.5);
+
+    scenario.play(5).await;
+}
+
+// a wand cast on something with no mind of its own fails,
+// leaving the caster in control of themselves
+#[tokio::test]
+async fn misfire() {
+    let mut scenario = Scenario::new("misfire");
+    scenario.add_object(Box::new(Wand), 0.0);
+    scenario.add_object(Box::new(sword("steel")), 1.0);
+    scenario.add_player("alice", 0.0, &["pick up the wand", "wield wand", "use wand on sword", "look"], &[]);
+    scenario.play(4).await;
+}
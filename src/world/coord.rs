@@ -1,6 +1,8 @@
+use serde::{Serialize, Deserialize};
+
 use crate::quantities::{
     Quantity,
-    distance::Distance,
+    distance::{Distance, meters},
     direction::{
         DirectionHorizontalOrVertical,
         DirectionVertical,
@@ -8,7 +10,7 @@ use crate::quantities::{
     },
 };
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct WorldCoord {
     pub x: Quantity<Distance>,
     pub y: Quantity<Distance>
@@ -39,4 +41,60 @@ impl WorldCoord {
             },
         }
     }
-}
\ No newline at end of file
+
+    // distance_to returns the straight-line distance between two coordinates
+    pub fn distance_to(&self, other: &WorldCoord) -> Quantity<Distance> {
+        let dx = ((other.x - self.x) / meters(1.0)).cancel().0.0;
+        let dy = ((other.y - self.y) / meters(1.0)).cancel().0.0;
+        meters(dx.hypot(dy))
+    }
+
+    // offset_from returns the direction leading away from the other
+    // coordinate along whichever axis the two lie further apart on, and
+    // how far apart they lie along it, or None if they're in the same place
+    pub fn offset_from(&self, other: &WorldCoord) -> Option<(DirectionHorizontalOrVertical, Quantity<Distance>)> {
+        let dx = ((self.x - other.x) / meters(1.0)).cancel().0.0;
+        let dy = ((self.y - other.y) / meters(1.0)).cancel().0.0;
+
+        let direction = if dx.abs() >= dy.abs() {
+            match dx {
+                dx if dx > 0.0 => DirectionHorizontalOrVertical::Horizontal(DirectionHorizontal::Right),
+                dx if dx < 0.0 => DirectionHorizontalOrVertical::Horizontal(DirectionHorizontal::Left),
+                _ => return None,
+            }
+        } else if dy > 0.0 {
+            DirectionHorizontalOrVertical::Vertical(DirectionVertical::Up)
+        } else {
+            DirectionHorizontalOrVertical::Vertical(DirectionVertical::Down)
+        };
+
+        Some((direction, meters(dx.abs().max(dy.abs()))))
+    }
+
+    // describe_relative_to describes where this coordinate lies as seen
+    // from the observer, e.g. "2 m to the right" or "1 m up and 3.5 m to the left"
+    pub fn describe_relative_to(&self, observer: &WorldCoord) -> String {
+        // distances are rounded to the nearest tenth of a meter
+        let round = |distance: Quantity<Distance>| ((distance / meters(1.0)).cancel().0.0 * 10.0).round() / 10.0;
+        let dx = round(self.x - observer.x);
+        let dy = round(self.y - observer.y);
+
+        let vertical = match dy {
+            dy if dy > 0.0 => Some(format!("{} m {}", dy, DirectionVertical::Up)),
+            dy if dy < 0.0 => Some(format!("{} m {}", -dy, DirectionVertical::Down)),
+            _ => None,
+        };
+
+        let horizontal = match dx {
+            dx if dx > 0.0 => Some(format!("{} m to the {}", dx, DirectionHorizontal::Right)),
+            dx if dx < 0.0 => Some(format!("{} m to the {}", -dx, DirectionHorizontal::Left)),
+            _ => None,
+        };
+
+        match (vertical, horizontal) {
+            (Some(vertical), Some(horizontal)) => format!("{} and {}", vertical, horizontal),
+            (Some(offset), None) | (None, Some(offset)) => offset,
+            (None, None) => String::from("right here"),
+        }
+    }
+}
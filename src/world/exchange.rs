@@ -0,0 +1,89 @@
+// This module houses item exchanges between objects, as when one character
+// gives another an item, or two characters trade.  Every item changing
+// hands is checked for before any of them moves, so that an exchange
+// either happens in full or not at all.
+
+use std::fmt;
+
+use crate::worldobject::{
+    fns::Error as WorldObjectError,
+    components::inventory::item::InventoryItemHandle
+};
+
+use super::{
+    World,
+    handle::WorldObjectHandle
+};
+
+#[derive(Debug)]
+pub enum ExchangeError {
+    SameObject(WorldObjectHandle),
+    NoSuchObject(WorldObjectHandle),
+    CouldNotGetInventory(WorldObjectHandle, WorldObjectError),
+    NoSuchItem(WorldObjectHandle, InventoryItemHandle),
+}
+
+impl fmt::Display for ExchangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SameObject(handle) => write!(f, "object with handle \"{}\" can't exchange items with itself", handle),
+            Self::NoSuchObject(handle) => write!(f, "no object found for handle \"{}\"", handle),
+            Self::CouldNotGetInventory(handle, err) => write!(f, "could not get inventory for object \"{}\": {}", handle, err),
+            Self::NoSuchItem(handle, item) => write!(f, "object with handle \"{}\" is not carrying item {}", handle, item),
+        }
+    }
+}
+
+impl std::error::Error for ExchangeError {}
+
+impl World {
+    // exchange_items moves the given item from the giver's inventory into the
+    // receiver's and, if there is one, the returned item the other way round
+    pub fn exchange_items(
+        &mut self,
+        giver: &WorldObjectHandle,
+        given: &InventoryItemHandle,
+        receiver: &WorldObjectHandle,
+        returned: Option<&InventoryItemHandle>
+    ) -> Result<(), ExchangeError> {
+        if giver == receiver {
+            return Err(ExchangeError::SameObject(*giver));
+        }
+
+        let (mut giver_object, mut receiver_object) = (None, None);
+        for (handle, (_, object)) in self.objects.iter_mut() {
+            if handle == giver {
+                giver_object = Some(object);
+            } else if handle == receiver {
+                receiver_object = Some(object);
+            }
+        }
+        let giver_object = giver_object.ok_or(ExchangeError::NoSuchObject(*giver))?;
+        let receiver_object = receiver_object.ok_or(ExchangeError::NoSuchObject(*receiver))?;
+
+        let giver_inventory = giver_object.inventory_mut()
+            .map_err(|err| ExchangeError::CouldNotGetInventory(*giver, err))?;
+        let receiver_inventory = receiver_object.inventory_mut()
+            .map_err(|err| ExchangeError::CouldNotGetInventory(*receiver, err))?;
+
+        if !giver_inventory.0.contains_key(given) {
+            return Err(ExchangeError::NoSuchItem(*giver, given.clone()));
+        }
+        if let Some(returned) = returned.filter(|returned| !receiver_inventory.0.contains_key(returned)) {
+            return Err(ExchangeError::NoSuchItem(*receiver, returned.clone()));
+        }
+
+        // both items are known to be there, so nothing can fail from here on;
+        // handles are only unique within an inventory, so the items get new ones
+        let returned_item = returned.and_then(|returned| receiver_inventory.take(returned));
+
+        if let Some(item) = giver_inventory.take(given) {
+            receiver_inventory.stow(item);
+        }
+        if let Some(item) = returned_item {
+            giver_inventory.stow(item);
+        }
+
+        Ok(())
+    }
+}
@@ -1 +1,78 @@
-pub type WorldObjectHandle = String;
\ No newline at end of file
+// This module houses the identifiers used to refer to objects in the world.
+//
+// A WorldObjectHandle is an opaque, copyable ID used internally.  Handles
+// are generational: when an object leaves the world its slot may be handed
+// out again, but with a new generation, so a stale handle can never refer
+// to a different object.
+//
+// Players never see handles; instead, each object is given a unique,
+// human-readable label derived from its name (e.g. "rat", "rat#2").
+
+use std::fmt;
+
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct WorldObjectHandle {
+    index: u32,
+    generation: u32,
+}
+
+impl fmt::Display for WorldObjectHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{}:{}", self.index, self.generation)
+    }
+}
+
+// the label players use to refer to an object, e.g. "rat#2"
+pub type WorldObjectLabel = String;
+
+// HandleAllocator hands out handles, reusing the slots of objects that
+// have left the world.  Slots are always reused lowest-index first, so
+// the same sequence of allocations always yields the same handles.
+#[derive(Default, Clone, Serialize, Deserialize)]
+pub struct HandleAllocator {
+    // the current generation of each slot, and whether it is in use
+    slots: Vec<(u32, bool)>,
+}
+
+impl HandleAllocator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn allocate(&mut self) -> WorldObjectHandle {
+        match self.slots.iter().position(|(_, in_use)| !in_use) {
+            Some(index) => {
+                let (generation, in_use) = &mut self.slots[index];
+                *generation += 1;
+                *in_use = true;
+                WorldObjectHandle { index: index as u32, generation: *generation }
+            },
+            None => {
+                self.slots.push((0, true));
+                WorldObjectHandle { index: (self.slots.len() - 1) as u32, generation: 0 }
+            }
+        }
+    }
+
+    // claim marks a specific handle as in use; this is used to
+    // restore the handles of objects loaded from a snapshot
+    pub fn claim(&mut self, handle: WorldObjectHandle) {
+        let index = handle.index as usize;
+        if self.slots.len() <= index {
+            self.slots.resize(index + 1, (0, false));
+        }
+        self.slots[index] = (handle.generation, true);
+    }
+
+    // release frees the handle's slot for reuse; releasing a
+    // stale handle has no effect
+    pub fn release(&mut self, handle: WorldObjectHandle) {
+        if let Some((generation, in_use)) = self.slots.get_mut(handle.index as usize) {
+            if *generation == handle.generation {
+                *in_use = false;
+            }
+        }
+    }
+}
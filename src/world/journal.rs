@@ -0,0 +1,188 @@
+// This module houses the journal: an append-only record of the commands
+// given in a world, one JSON record per line.  Each hosting session opens
+// with a start record, which holds a snapshot of the world as the session
+// began and the handles of the objects that had a controller, and so took
+// turns.  Every command an actor gives that is turned into an action is
+// then recorded along with the turn it was given on and the seed the
+// world handed out for that turn, as is why the actor did nothing on
+// any turn its command was rejected, and every option picked while an
+// action was carried out, such as whether to accept a trade.  Since
+// everything left to chance is drawn from the world's seeded generator,
+// a session can be replayed from its start record, its commands and its
+// choices alone (see the replay module).
+
+use std::{
+    fmt,
+    fs::{File, OpenOptions},
+    io::{self, BufRead, BufReader, Write},
+    path::Path
+};
+
+use serde::{Serialize, Deserialize};
+
+use super::{
+    World,
+    handle::{WorldObjectHandle, WorldObjectLabel},
+    save::WorldSaveError
+};
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JournalRecord {
+    // the world as a hosting session began
+    Start {
+        snapshot: serde_json::Value,
+        actors: Vec<WorldObjectHandle>,
+    },
+    // a command an actor gave, which was turned into an action
+    Command {
+        turn: u64,
+        actor: WorldObjectHandle,
+        // the actor's label, so that people reading the journal know who acted
+        label: WorldObjectLabel,
+        seed: u64,
+        command: serde_json::Value,
+    },
+    // a turn on which the actor's command was rejected, or on which it
+    // gave none, along with why, as the actor was told it
+    Rejected {
+        turn: u64,
+        actor: WorldObjectHandle,
+        label: WorldObjectLabel,
+        seed: u64,
+        reason: String,
+    },
+    // an option an object picked while the action taken on
+    // the given turn was carried out, counting from zero
+    Choice {
+        turn: u64,
+        actor: WorldObjectHandle,
+        label: WorldObjectLabel,
+        index: usize,
+    },
+}
+
+#[derive(Debug)]
+pub enum JournalError {
+    FailedToSnapshot(WorldSaveError),
+    FailedToOpen(io::Error),
+    FailedToEncode(serde_json::Error),
+    FailedToWrite(io::Error),
+    FailedToRead(io::Error),
+    InvalidRecord(usize, serde_json::Error),
+}
+
+impl fmt::Display for JournalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::FailedToSnapshot(err) => write!(f, "failed to snapshot the world: {}", err),
+            Self::FailedToOpen(err) => write!(f, "failed to open journal: {}", err),
+            Self::FailedToEncode(err) => write!(f, "failed to encode journal record: {}", err),
+            Self::FailedToWrite(err) => write!(f, "failed to write journal record: {}", err),
+            Self::FailedToRead(err) => write!(f, "failed to read journal: {}", err),
+            Self::InvalidRecord(line, err) => write!(f, "invalid journal record on line {}: {}", line, err),
+        }
+    }
+}
+
+impl std::error::Error for JournalError {}
+
+pub struct Journal {
+    file: File,
+}
+
+impl Journal {
+    // open opens the journal at the given path for appending, creating it if need be
+    fn open(path: impl AsRef<Path>) -> Result<Self, JournalError> {
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(JournalError::FailedToOpen)?;
+
+        Ok(Self { file })
+    }
+
+    // append writes the record in one go, so that a journal cut short,
+    // e.g. by a crash, still holds every record up to that point
+    fn append(&mut self, record: &JournalRecord) -> Result<(), JournalError> {
+        let mut line = serde_json::to_vec(record).map_err(JournalError::FailedToEncode)?;
+        line.push(b'\n');
+        self.file.write_all(&line).map_err(JournalError::FailedToWrite)
+    }
+
+    // read returns every record in the journal at the given path, in order
+    pub fn read(path: impl AsRef<Path>) -> Result<Vec<JournalRecord>, JournalError> {
+        let file = File::open(path).map_err(JournalError::FailedToRead)?;
+
+        let mut records = Vec::new();
+        for (number, line) in BufReader::new(file).lines().enumerate() {
+            let line = line.map_err(JournalError::FailedToRead)?;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let record = serde_json::from_str(&line)
+                .map_err(|err| JournalError::InvalidRecord(number + 1, err))?;
+            records.push(record);
+        }
+
+        Ok(records)
+    }
+}
+
+impl World {
+    // open_journal starts recording the world's commands to the journal at
+    // the given path, beginning a new session; the journal is appended to,
+    // so a world hosted again adds a session to the end of its journal
+    pub fn open_journal(&mut self, path: impl AsRef<Path>) -> Result<(), JournalError> {
+        let snapshot = self.to_snapshot().map_err(JournalError::FailedToSnapshot)?;
+        let actors = self.objects.iter()
+            .filter(|(_, (_, object))| object.controller().is_ok())
+            .map(|(handle, _)| *handle)
+            .collect();
+
+        let mut journal = Journal::open(path)?;
+        journal.append(&JournalRecord::Start { snapshot, actors })?;
+        self.journal = Some(journal);
+
+        Ok(())
+    }
+
+    // journal_command records a command the actor gave on the current
+    // turn, along with the seed it was handed; without a journal, the
+    // command goes unrecorded
+    pub(super) fn journal_command(&mut self, actor: WorldObjectHandle, seed: u64, command: serde_json::Value) -> Result<(), JournalError> {
+        let label = self.labels.get(&actor).cloned().unwrap_or_default();
+        let turn = self.turn;
+
+        match &mut self.journal {
+            Some(journal) => journal.append(&JournalRecord::Command { turn, actor, label, seed, command }),
+            None => Ok(()),
+        }
+    }
+
+    // journal_rejection records that the actor did nothing on the current
+    // turn, along with why; without a journal, it goes unrecorded
+    pub(super) fn journal_rejection(&mut self, actor: WorldObjectHandle, seed: u64, reason: String) -> Result<(), JournalError> {
+        let label = self.labels.get(&actor).cloned().unwrap_or_default();
+        let turn = self.turn;
+
+        match &mut self.journal {
+            Some(journal) => journal.append(&JournalRecord::Rejected { turn, actor, label, seed, reason }),
+            None => Ok(()),
+        }
+    }
+
+    // journal_choice records an option the actor picked during the current
+    // turn; without a journal, it goes unrecorded
+    pub(super) fn journal_choice(&mut self, actor: WorldObjectHandle, index: usize) -> Result<(), JournalError> {
+        let label = self.labels.get(&actor).cloned().unwrap_or_default();
+        let turn = self.turn;
+
+        match &mut self.journal {
+            Some(journal) => journal.append(&JournalRecord::Choice { turn, actor, label, index }),
+            None => Ok(()),
+        }
+    }
+}
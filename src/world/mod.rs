@@ -1,23 +1,60 @@
 pub mod coord;
 pub mod handle;
-
-use std::collections::{HashMap};
+pub mod save;
+pub mod range;
+pub mod perception;
+pub mod turns;
+pub mod exchange;
+pub mod view;
+pub mod motion;
+pub mod rng;
+pub mod journal;
+
+use std::collections::BTreeMap;
 use std::fmt;
 use std::error;
 
+use rand::{RngCore, SeedableRng};
+
 use crate::{
     lang::{GrammaticalPerson, TransitiveVerb, verbs::ToDo, TransitiveVerbPhrase, VerbPhrase},
-    worldobject::{WorldObject, fns::Error as WorldObjectError, components::inventory::item::InventoryItem},
-    quantities::{Quantity, distance::Distance, direction::DirectionHorizontalOrVertical},
-    logging::{Logger, LoggerImpl, DynLogger, noop::NoopLogger}
+    worldobject::{WorldObject, fns::{Error as WorldObjectError, update::Action}, components::inventory::item::InventoryItem},
+    quantities::{Quantity, distance::Distance, duration::{Duration, seconds}, direction::DirectionHorizontalOrVertical},
+    logging::{Logger, LoggerImpl, DynLogger}
 };
 
-use handle::WorldObjectHandle;
+use handle::{WorldObjectHandle, WorldObjectLabel, HandleAllocator};
 use coord::WorldCoord;
+use journal::Journal;
+use rng::WorldRng;
+use view::WorldView;
+
+// Addressed is the object an action is done to, along with
+// the verb phrase telling them of it in the second person
+type Addressed = (WorldObjectHandle, VerbPhrase);
 
 pub struct World {
     logger: DynLogger,
-    pub objects: HashMap<WorldObjectHandle, (WorldCoord, Box<dyn WorldObject>)>,
+    pub objects: BTreeMap<WorldObjectHandle, (WorldCoord, Box<dyn WorldObject>)>,
+    // handles are allocated by the world, and every object
+    // is given a unique label by which players refer to it
+    handles: HandleAllocator,
+    labels: BTreeMap<WorldObjectHandle, WorldObjectLabel>,
+    // the simulation clock, and when each acting object is next due to act
+    clock: Quantity<Duration>,
+    schedule: BTreeMap<WorldObjectHandle, Quantity<Duration>>,
+    // how many turns have been taken in the world
+    turn: u64,
+    // what objects did in response to the action being carried out,
+    // to be narrated once the action itself has been
+    narrations: Vec<(WorldObjectHandle, VerbPhrase, Option<Addressed>)>,
+    // how the action being carried out reads to the object it is done to
+    addressed: Option<Addressed>,
+    // everything left to chance is drawn from rng, which was seeded with seed
+    seed: u64,
+    rng: WorldRng,
+    // where the commands actors give are recorded, if anywhere
+    journal: Option<Journal>,
 }
 
 #[derive(Debug)]
@@ -51,12 +88,16 @@ impl error::Error for WorldObjectMoveError {}
 #[derive(Debug)]
 pub enum WorldObjectGetError {
     NoSuchObject(WorldObjectHandle),
+    NoObjectLabelled(WorldObjectLabel),
+    ObjectIsActing(WorldObjectHandle),
 }
 
 impl fmt::Display for WorldObjectGetError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::NoSuchObject(handle) => write!(f, "no object found for handle \"{}\"", handle)
+            Self::NoSuchObject(handle) => write!(f, "no object found for handle \"{}\"", handle),
+            Self::NoObjectLabelled(label) => write!(f, "there is nothing labelled \"{}\"", label),
+            Self::ObjectIsActing(handle) => write!(f, "object with handle \"{}\" is in the middle of acting", handle)
         }
     }
 }
@@ -98,51 +139,113 @@ impl fmt::Display for WorldGiveItemError {
 impl error::Error for WorldGiveItemError {}
 
 impl World {
-    pub fn new(logger: Logger<impl LoggerImpl + 'static>) -> Self {
+    // with_seed makes an empty world whose every chance
+    // is settled by a generator seeded with the given seed
+    pub fn with_seed(logger: Logger<impl LoggerImpl + 'static>, seed: u64) -> Self {
         World {
             logger: logger.to_dyn(),
-            objects: HashMap::new(),
+            objects: BTreeMap::new(),
+            handles: HandleAllocator::new(),
+            labels: BTreeMap::new(),
+            clock: seconds(0.0),
+            schedule: BTreeMap::new(),
+            turn: 0,
+            narrations: Vec::new(),
+            addressed: None,
+            seed,
+            rng: WorldRng::seed_from_u64(seed),
+            journal: None,
         }
     }
 
-    pub fn add_object(&mut self, handle: WorldObjectHandle, object: Box<dyn WorldObject>, position: WorldCoord) {
+    // add_object places an object in the world, returning its new handle;
+    // the object is labelled after its name, numbered if the name is taken
+    pub fn add_object(&mut self, object: Box<dyn WorldObject>, position: WorldCoord) -> WorldObjectHandle {
+        let handle = self.handles.allocate();
+        let label = self.unique_label(&object.name());
+        self.labels.insert(handle, label);
         self.objects.insert(handle, (position, object));
+        handle
+    }
+
+    // restore_object places an object back in the world under a known
+    // handle and label, e.g. when loading a snapshot
+    pub fn restore_object(&mut self, handle: WorldObjectHandle, label: WorldObjectLabel, object: Box<dyn WorldObject>, position: WorldCoord) {
+        self.handles.claim(handle);
+        self.labels.insert(handle, label);
+        self.objects.insert(handle, (position, object));
+    }
+
+    // unique_label derives a label no other object has from the given name,
+    // e.g. the second object named "rat" is labelled "rat#2"
+    fn unique_label(&self, name: &str) -> WorldObjectLabel {
+        // labels are single words, so that they can be typed in commands
+        let base = name.split_whitespace().collect::<Vec<_>>().join("-");
+        let is_taken = |label: &str| self.labels.values().any(|taken| taken == label);
+
+        if !is_taken(&base) {
+            return base;
+        }
+
+        (2..)
+            .map(|n| format!("{}#{}", base, n))
+            .find(|label| !is_taken(label))
+            .expect("ran out of labels")
+    }
+
+    pub fn label_of(&self, handle: &WorldObjectHandle) -> Result<&WorldObjectLabel, WorldObjectGetError> {
+        self.labels.get(handle)
+            .ok_or(WorldObjectGetError::NoSuchObject(*handle))
+    }
+
+    // find_object returns the handle of the object with the given label
+    pub fn find_object(&self, label: &str) -> Result<WorldObjectHandle, WorldObjectGetError> {
+        self.labels.iter()
+            .find(|(_, candidate)| *candidate == label)
+            .map(|(handle, _)| *handle)
+            .ok_or(WorldObjectGetError::NoObjectLabelled(String::from(label)))
     }
 
     pub fn get_object(&self, handle: &WorldObjectHandle) -> Result<&dyn WorldObject, WorldObjectGetError> {
         let (_, obj_box) = self.objects.get(handle)
-            .ok_or(WorldObjectGetError::NoSuchObject(handle.clone()))?;
+            .ok_or(WorldObjectGetError::NoSuchObject(*handle))?;
 
         Ok(obj_box.as_ref())
     }
 
     pub fn get_object_mut(&mut self, handle: &WorldObjectHandle) -> Result<&mut dyn WorldObject, WorldObjectGetError> {
         let (_, obj_box) = self.objects.get_mut(handle)
-            .ok_or(WorldObjectGetError::NoSuchObject(handle.clone()))?;
+            .ok_or(WorldObjectGetError::NoSuchObject(*handle))?;
 
         Ok(obj_box.as_mut())
     }
 
     pub fn locate_object(&self, handle: &WorldObjectHandle) -> Result<WorldCoord, WorldObjectGetError> {
         let (coord, _) = self.objects.get(handle)
-            .ok_or(WorldObjectGetError::NoSuchObject(handle.clone()))?;
+            .ok_or(WorldObjectGetError::NoSuchObject(*handle))?;
 
         Ok(*coord)
     }
 
+    // take_object removes an object from the world; its handle
+    // and label are freed up for use by other objects
     pub fn take_object(&mut self, handle: &WorldObjectHandle) -> Result<Box<dyn WorldObject>, WorldObjectGetError> {
         let (_, obj_box) = self.objects.remove(handle)
-            .ok_or(WorldObjectGetError::NoSuchObject(handle.clone()))?;
+            .ok_or(WorldObjectGetError::NoSuchObject(*handle))?;
+
+        self.labels.remove(handle);
+        self.schedule.remove(handle);
+        self.handles.release(*handle);
 
         Ok(obj_box)
     }
 
     pub fn give_item_to<Item: InventoryItem + 'static>(&mut self, handle: &WorldObjectHandle, item: Item) -> Result<(), WorldGiveItemError> {
         let (_, object) = self.objects.get_mut(handle)
-            .ok_or(WorldGiveItemError::NoSuchObject(handle.clone()))?;
+            .ok_or(WorldGiveItemError::NoSuchObject(*handle))?;
 
         let inventory = object.inventory_mut()
-            .map_err(|err| WorldGiveItemError::CoultNotGetInventory(String::from(handle), err))?;
+            .map_err(|err| WorldGiveItemError::CoultNotGetInventory(*handle, err))?;
 
         inventory.give(item);
 
@@ -153,7 +256,7 @@ impl World {
         self.logger.info(format!("Sending message to object with handle {}...", handle)).await;
 
         let (_, object) = self.objects.get_mut(handle)
-            .ok_or(WorldObjectSendMessageError::NoSuchObject(handle.clone()))?;
+            .ok_or(WorldObjectSendMessageError::NoSuchObject(*handle))?;
 
         if let Err(err) = object.send_message(message).await {
             self.logger.error(format!("Failed to send message to object with handle {}: {}", handle, err)).await;
@@ -165,25 +268,106 @@ impl World {
 
     pub fn move_object(&mut self, handle: &WorldObjectHandle, direction: &DirectionHorizontalOrVertical, distance: &Quantity<Distance>) -> Result<(), WorldObjectMoveError> {
         if !self.objects.contains_key(handle) {
-            return Err(WorldObjectMoveError::NoSuchObject(handle.clone()))
+            return Err(WorldObjectMoveError::NoSuchObject(*handle))
         }
 
         self.objects
-            .entry(handle.clone())
+            .entry(*handle)
             .and_modify(|(coord, _)| coord.translate_direction(direction, distance));
 
         Ok(())
     }
 
-    async fn broadcast(&mut self, message: String) -> Result<(), WorldObjectSendMessageError> {
-        self.broadcast_by_recipient(|handle| message.clone()).await
+    // broadcast_by_recipient sends a message to every object
+    // that can perceive an event taking place at the origin
+    async fn broadcast_by_recipient(&mut self, origin: &WorldCoord, message_by_recipient: impl Fn(WorldObjectHandle) -> String) -> Result<(), WorldObjectSendMessageError> {
+        for handle in self.perceivers_of(origin) {
+            if let Some((_, object)) = self.objects.get_mut(&handle) {
+                _ = object.send_message(message_by_recipient(handle)).await;
+            }
+        }
+        Ok(())
+    }
+
+    // narrate tells everyone who can perceive the actor what it did, right
+    // after the action being carried out is narrated; actions use it to
+    // narrate what others do in response, such as accepting a trade
+    pub fn narrate(&mut self, actor: WorldObjectHandle, verb_phrase: VerbPhrase) {
+        self.narrations.push((actor, verb_phrase, None));
+    }
+
+    // narrate_to narrates what the actor did to the addressee, who is told
+    // it in the second person, e.g. "bob declines your offer"
+    pub fn narrate_to(&mut self, actor: WorldObjectHandle, verb_phrase: VerbPhrase, addressee: WorldObjectHandle, addressed_verb_phrase: VerbPhrase) {
+        self.narrations.push((actor, verb_phrase, Some((addressee, addressed_verb_phrase))));
+    }
+
+    // address tells the object the action being carried out is done to what
+    // was done in the second person, e.g. "alice gives you a sword" rather
+    // than "alice gives a sword to bob"; everyone else is told as usual
+    pub fn address(&mut self, addressee: WorldObjectHandle, addressed_verb_phrase: VerbPhrase) {
+        self.addressed = Some((addressee, addressed_verb_phrase));
     }
 
-    async fn broadcast_by_recipient(&mut self, message_by_recipient: impl Fn(WorldObjectHandle) -> String) -> Result<(), WorldObjectSendMessageError> {
-        for (handle, (_, object)) in self.objects.iter_mut() {
-            _ = object.send_message(message_by_recipient(handle.clone())).await;
+    // choose asks the chooser to pick one of the options, e.g. what to give
+    // in return for an offer; the answer is journaled, since nothing else
+    // records it and a replay has to give the same one
+    pub async fn choose(&mut self, chooser: WorldObjectHandle, prompt: String, options: Vec<String>) -> Result<usize, WorldObjectError> {
+        let controller = self.get_object_mut(&chooser)?.controller_mut()?;
+        let choice = controller.choose(prompt, options).await?;
+
+        // the error is formatted straight away, since it can't be held while logging
+        let journaled = self.journal_choice(chooser, choice)
+            .map_err(|err| format!("Failed to journal choice of object with handle {}: {}", chooser, err));
+        if let Err(message) = journaled {
+            self.logger.error(message).await;
+        }
+
+        Ok(choice)
+    }
+
+    async fn broadcast_narrations(&mut self) {
+        for (actor, verb_phrase, addressed) in std::mem::take(&mut self.narrations) {
+            let (Ok(actor_description), Ok(origin)) = (
+                self.get_object(&actor).map(|object| object.definite_description()),
+                self.locate_object(&actor)
+            ) else {
+                continue;
+            };
+
+            let addressed = addressed.map(|(addressee, addressed_verb_phrase)| (
+                addressee,
+                Self::addressed_message(&actor_description, addressed_verb_phrase)
+            ));
+            let (second_person_message, third_person_message) = Self::second_and_third_person_messages(actor_description, verb_phrase);
+            _ = self.broadcast_by_recipient(&origin, |recipient_handle| {
+                Self::message_for(recipient_handle, actor, &second_person_message, &third_person_message, &addressed)
+            }).await;
         }
-        Ok(())
+    }
+
+    // message_for picks which of the messages telling of what the actor
+    // did is meant for the recipient
+    fn message_for(
+        recipient_handle: WorldObjectHandle,
+        actor: WorldObjectHandle,
+        second_person_message: &str,
+        third_person_message: &str,
+        addressed: &Option<(WorldObjectHandle, String)>
+    ) -> String {
+        match addressed {
+            _ if recipient_handle == actor => second_person_message.to_string(),
+            Some((addressee, addressed_message)) if *addressee == recipient_handle => addressed_message.clone(),
+            _ => third_person_message.to_string(),
+        }
+    }
+
+    fn addressed_message(third_person_subject: &str, addressed_verb_phrase: VerbPhrase) -> String {
+        format!(
+            "{} {}",
+            third_person_subject,
+            addressed_verb_phrase.conjugate(&GrammaticalPerson::ThirdPersonSingularGendered)
+        )
     }
 
     fn second_and_third_person_messages(third_person_subject: String, update_verb_phrase: VerbPhrase) -> (String, String) {
@@ -201,35 +385,66 @@ impl World {
         (second_person_message, third_person_message)
     }
 
-    async fn update_object(&mut self, handle: &WorldObjectHandle, object_description: String) -> Result<(), WorldUpdateError> {
-        if !self.objects.contains_key(handle) {
+    async fn update_object(&mut self, handle: &WorldObjectHandle, object_description: String, up_next: Option<(WorldObjectHandle, String)>) -> Result<(), WorldUpdateError> {
+        let Ok(origin) = self.locate_object(handle) else {
             return Ok(());
-        }
+        };
         
-        // broadcast the start of the turn
-        _ = self.broadcast_by_recipient(|recipient_handle| {
-            if recipient_handle == *handle {
+        // broadcast the start of the turn, along with who is up next
+        _ = self.broadcast_by_recipient(&origin, |recipient_handle| {
+            let turn_message = if recipient_handle == *handle {
                 String::from("It is your turn to act")
             } else {
                 format!("It is {}'s turn to act", object_description)
+            };
+
+            match &up_next {
+                Some((next_handle, _)) if *next_handle == recipient_handle => format!("{}; you are up next", turn_message),
+                Some((_, next_description)) => format!("{}; {} is up next", turn_message, next_description),
+                None => turn_message,
             }
         }).await;
     
         let action_res = {
-            // create a dummy world to avoid double-borrowing the acting object
-            let world_dummy = self.dummy();
-            // try getting the object; if we succeed, call the object's update method.
-            // This borrows the object from the world, necessitating the dummy world
-            match self.get_object_mut(&handle) {
-                Ok(object) => object.update(handle.clone(), &world_dummy).await,
-                Err(err) => {
-                    let b: Box<dyn std::error::Error> = Box::new(err);
+            // take the object out of the world while it decides what to do,
+            // so that it can be borrowed mutably while it looks at everything
+            // else through a read-only view; it is put back before acting
+            match self.objects.remove(handle) {
+                Some((coord, mut object)) => {
+                    let seed = self.rng.next_u64();
+                    let view = WorldView::new(self, handle, coord, seed);
+                    let action_res = object.update(*handle, &view).await;
+                    let accepted = view.into_accepted();
+                    self.objects.insert(*handle, (coord, object));
+
+                    let journaled = match (&action_res, accepted) {
+                        (Ok(_), Some(command)) => Some(self.journal_command(*handle, seed, command)),
+                        (Err(err), _) => Some(self.journal_rejection(*handle, seed, err.to_string())),
+                        (Ok(_), None) => None,
+                    };
+                    if let Some(Err(err)) = journaled {
+                        self.logger.error(format!("Failed to journal command of object with handle {}: {}", handle, err)).await;
+                    }
+
+                    action_res
+                },
+                None => {
+                    let b: Box<dyn std::error::Error> = Box::new(WorldObjectGetError::NoSuchObject(*handle));
                     Err(b)
                 }
             }
         };
     
-        let (second_person_message, third_person_message) = match action_res {
+        // the actor acts again once its action is over; if it failed to
+        // choose an action, it loses a moment rather than a whole turn
+        let cost = match &action_res {
+            Ok(action) => action.cost.clone(),
+            Err(_) => Action::idle_cost(),
+        };
+        self.schedule_next_turn(handle, cost);
+
+        self.addressed = None;
+        let outcome = match action_res {
             Ok(action) => {
                 let object_description = object_description.clone();
                 async {
@@ -250,7 +465,17 @@ impl World {
                 }.await
             },
             Err(err) => Err(err)
-        }.unwrap_or_else(
+        };
+
+        // an action that failed did nothing to anyone
+        let addressed = std::mem::take(&mut self.addressed)
+            .filter(|_| outcome.is_ok())
+            .map(|(addressee, addressed_verb_phrase)| (
+                addressee,
+                Self::addressed_message(&object_description, addressed_verb_phrase)
+            ));
+
+        let (second_person_message, third_person_message) = outcome.unwrap_or_else(
             |err| {
                 let (second_person_message, third_person_message) = Self::second_and_third_person_messages(
                     object_description,
@@ -262,39 +487,46 @@ impl World {
             }
         );
     
-        // broadcast the results of the action to all objects in the world
-        self.broadcast_by_recipient(|recipient_handle| {
-            if recipient_handle == *handle {
-                second_person_message.clone()
-            } else {
-                third_person_message.clone()
-            }
-        }).await.map_err(|err| WorldUpdateError::ObjectUpdateFailed(handle.clone(), Box::new(err)))
+        // broadcast the results of the action to every object that could
+        // perceive them; the actor may have moved during its turn
+        let origin = self.locate_object(handle).unwrap_or(origin);
+        let result = self.broadcast_by_recipient(&origin, |recipient_handle| {
+            Self::message_for(recipient_handle, *handle, &second_person_message, &third_person_message, &addressed)
+        }).await.map_err(|err| WorldUpdateError::ObjectUpdateFailed(*handle, Box::new(err)));
+
+        self.broadcast_narrations().await;
+
+        // separate this turn from the next for everyone who witnessed it
+        _ = self.broadcast_by_recipient(&origin, |_| String::from("\n")).await;
+
+        result
     }
 
     pub async fn update(&mut self) -> Result<(), WorldUpdateError> {
         self.logger.info(String::from("Updating world...")).await;
 
-        let handles = self.objects.iter().map(
-            |(handle, obj)|
-            (handle.clone(), obj.1.definite_description())
-        ).collect::<Vec<_>>();
+        self.forget_departed_objects();
 
-        for (handle, object_description) in handles {
-            match self.update_object(&handle, object_description).await {
-                Ok(_) => self.logger.info(format!("Updated object with handle {}...", handle)).await,
-                Err(err) => self.logger.error(format!("Failed to update object with handle {}: {}", handle, err)).await,
-            };
-            let _ = self.broadcast(String::from("\n")).await;
-        }
+        let describe = |handle: &WorldObjectHandle| self.get_object(handle)
+            .map(|object| (*handle, object.definite_description()))
+            .ok();
 
-        Ok(())
-    }
+        // the earliest due object takes the next turn; if nothing acts, time stands still
+        let turn_order = self.turn_order();
+        let Some((handle, object_description)) = turn_order.first().and_then(describe) else {
+            return Ok(());
+        };
+        let up_next = turn_order.get(1).and_then(describe);
 
-    pub fn dummy(&self) -> Self {
-        Self {
-            logger: NoopLogger::new().to_dyn(),
-            objects: self.objects.iter().map(|(handle, (coord, object))| (handle.clone(), (coord.clone(), object.dummy()))).collect(),
-        }
+        self.clock = self.due_time(&handle);
+        self.turn += 1;
+        self.logger.info(format!("The time is now {} seconds", (self.clock() / seconds(1.0)).cancel().0.0)).await;
+
+        match self.update_object(&handle, object_description, up_next).await {
+            Ok(_) => self.logger.info(format!("Updated object with handle {}...", handle)).await,
+            Err(err) => self.logger.error(format!("Failed to update object with handle {}: {}", handle, err)).await,
+        };
+
+        Ok(())
     }
 }
\ No newline at end of file
@@ -0,0 +1,160 @@
+// This module houses how objects get about the world.  Objects that move
+// under their own power go at their own speed, and may only go so far in a
+// single move.  How far an object is carried by a force is worked out by
+// the physics module from the force and the object's mass; the world moves
+// the object and lets whoever can see it know, so long as it moved far
+// enough to notice.
+
+use std::fmt;
+
+use crate::{
+    lang::{VerbPhrase, TransitiveVerb, verbs::ToReel},
+    physics,
+    quantities::{
+        Quantity,
+        force::Force,
+        duration::{Duration, seconds},
+        distance::{Distance, meters},
+        speed::Speed,
+        direction::DirectionHorizontalOrVertical
+    }
+};
+
+use super::{
+    World,
+    WorldObjectMoveError,
+    handle::WorldObjectHandle
+};
+
+// the longest, in seconds, a single move may take; anything
+// further away has to be reached over several moves
+const MAX_MOVE_SECONDS: f64 = 5.0;
+
+#[derive(Debug)]
+pub enum MoveError {
+    DistanceNotPositive,
+    DistanceTooGreat,
+}
+
+impl fmt::Display for MoveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DistanceNotPositive => write!(f, "distance must be greater than zero"),
+            Self::DistanceTooGreat => write!(f, "distance too great"),
+        }
+    }
+}
+
+impl std::error::Error for MoveError {}
+
+// stride returns the furthest an object going at the given speed can get in a single move
+pub fn stride(speed: &Quantity<Speed>) -> Quantity<Distance> {
+    (seconds(MAX_MOVE_SECONDS) * speed.clone().commute()).associate_left().commute().cancel()
+}
+
+// move_cost returns how long it takes to go the given distance at the given
+// speed, so long as the distance is one that can be gone in a single move
+pub fn move_cost(distance: &Quantity<Distance>, speed: &Quantity<Speed>) -> Result<Quantity<Duration>, MoveError> {
+    let distance_f64 = (*distance / meters(1.0)).cancel().0.0;
+    if distance_f64.is_nan() || distance_f64 <= 0.0 {
+        return Err(MoveError::DistanceNotPositive);
+    }
+    if *distance > stride(speed) {
+        return Err(MoveError::DistanceTooGreat);
+    }
+
+    let distance_per_second = (seconds(1.0) * speed.clone().commute()).associate_left().commute().cancel();
+    Ok(seconds((*distance / distance_per_second).cancel().0.0))
+}
+
+impl World {
+    // shove moves an object in the given direction as far as the force,
+    // acting on it for the given duration, carries it, and returns how far
+    // that was; a move of under a tenth of a meter goes unremarked
+    pub fn shove(&mut self, handle: &WorldObjectHandle, direction: DirectionHorizontalOrVertical, force: &Quantity<Force>, duration: &Quantity<Duration>) -> Result<Quantity<Distance>, WorldObjectMoveError> {
+        let mass = self.get_object(handle)
+            .map(|object| object.mass())
+            .map_err(|_| WorldObjectMoveError::NoSuchObject(*handle))?;
+
+        let distance = physics::displacement(force, &mass, duration);
+        self.move_object(handle, &direction, &distance)?;
+
+        // distances are rounded to the nearest tenth of a meter
+        let rounded = ((distance / meters(1.0)).cancel().0.0 * 10.0).round() / 10.0;
+        if rounded > 0.0 {
+            self.narrate(*handle, VerbPhrase::Transitive(
+                TransitiveVerb::new(ToReel).with_direct_object(format!("{} m {}", rounded, direction.adverbial()))
+            ));
+        }
+
+        Ok(distance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        logging::noop::NoopLogger,
+        quantities::{
+            direction::{DirectionHorizontal, DirectionHorizontalOrVertical, DirectionVertical},
+            distance::meters,
+            duration::seconds,
+            force::newtons,
+            mass::kilograms,
+            speed::meters_per_second
+        },
+        tests::fixtures::alice,
+        world::{World, coord::WorldCoord},
+        worldobject::rat::Rat
+    };
+
+    use super::{MoveError, move_cost, stride};
+
+    #[test]
+    fn move_cost_is_the_time_taken_to_go_the_distance() {
+        let cost = move_cost(&meters(10.0), &meters_per_second(5.0)).unwrap();
+        assert!(cost == seconds(2.0));
+    }
+
+    #[test]
+    fn move_cost_rejects_distances_that_are_not_positive() {
+        for distance in [0.0, -100.0, f64::NAN] {
+            assert!(matches!(move_cost(&meters(distance), &meters_per_second(5.0)), Err(MoveError::DistanceNotPositive)));
+        }
+    }
+
+    #[test]
+    fn move_cost_rejects_distances_beyond_a_single_stride() {
+        let speed = meters_per_second(5.0);
+        assert_eq!(stride(&speed), meters(25.0));
+        assert!(move_cost(&meters(25.0), &speed).is_ok());
+        assert!(matches!(move_cost(&meters(25.5), &speed), Err(MoveError::DistanceTooGreat)));
+    }
+
+    #[test]
+    fn a_blow_knocks_a_rat_back_but_barely_moves_a_human() {
+        let mut world = World::with_seed(NoopLogger::new(), 1);
+        let rat = world.add_object(Box::new(Rat::new(kilograms(1.0), meters_per_second(1.0))), WorldCoord::new(meters(1.0), meters(0.0)));
+        let human = world.add_object(Box::new(alice()), WorldCoord::new(meters(-1.0), meters(0.0)));
+
+        let knocked = world.shove(&rat, DirectionHorizontalOrVertical::Horizontal(DirectionHorizontal::Right), &newtons(500.0), &seconds(0.05)).unwrap();
+        let budged = world.shove(&human, DirectionHorizontalOrVertical::Horizontal(DirectionHorizontal::Left), &newtons(500.0), &seconds(0.05)).unwrap();
+
+        assert!(knocked > meters(0.5));
+        assert!(budged < meters(0.01));
+        assert_eq!(world.locate_object(&rat).unwrap().x, meters(1.0) + knocked);
+        assert_eq!(world.locate_object(&human).unwrap().x, meters(-1.0) - budged);
+    }
+
+    #[test]
+    fn a_blow_from_below_knocks_its_target_up() {
+        let mut world = World::with_seed(NoopLogger::new(), 1);
+        let rat = world.add_object(Box::new(Rat::new(kilograms(1.0), meters_per_second(1.0))), WorldCoord::new(meters(0.0), meters(1.0)));
+
+        let (direction, _) = world.locate_object(&rat).unwrap().offset_from(&WorldCoord::new(meters(0.2), meters(0.0))).unwrap();
+        assert_eq!(direction, DirectionHorizontalOrVertical::Vertical(DirectionVertical::Up));
+
+        let knocked = world.shove(&rat, direction, &newtons(500.0), &seconds(0.05)).unwrap();
+        assert_eq!(world.locate_object(&rat).unwrap().y, meters(1.0) + knocked);
+    }
+}
@@ -0,0 +1,83 @@
+// This module houses the perception model.  Every object has
+// a sight and a hearing range; objects only learn about the
+// parts of the world that fall within those ranges.
+
+use crate::{
+    quantities::{
+        Quantity,
+        distance::{Distance, meters}
+    },
+    worldobject::WorldObject
+};
+
+use super::{
+    World,
+    coord::WorldCoord,
+    handle::WorldObjectHandle
+};
+
+#[derive(Clone, Copy)]
+pub struct Perception {
+    // how far away an object can be for this object to see it
+    pub sight: Quantity<Distance>,
+    // how far away an event can be for this object to hear it
+    pub hearing: Quantity<Distance>,
+}
+
+impl Perception {
+    pub fn new(sight: Quantity<Distance>, hearing: Quantity<Distance>) -> Self {
+        Self { sight, hearing }
+    }
+
+    // the perception of inanimate objects, which perceive nothing
+    pub fn none() -> Self {
+        Self { sight: meters(0.0), hearing: meters(0.0) }
+    }
+
+    // can_see_from returns whether something at the target coordinate
+    // is visible to an observer at the given coordinate
+    pub fn can_see_from(&self, observer: &WorldCoord, target: &WorldCoord) -> bool {
+        observer.distance_to(target) <= self.sight
+    }
+
+    // can_perceive_from returns whether an event at the origin can be
+    // either seen or heard by an observer at the given coordinate
+    pub fn can_perceive_from(&self, observer: &WorldCoord, origin: &WorldCoord) -> bool {
+        let distance = observer.distance_to(origin);
+        distance <= self.sight || distance <= self.hearing
+    }
+}
+
+impl World {
+    // visible_objects returns every other object the observer can see,
+    // along with its position, ordered by handle
+    pub fn visible_objects(&self, observer: &WorldObjectHandle) -> Vec<(&WorldObjectHandle, WorldCoord, &dyn WorldObject)> {
+        let Some((observer_coord, observer_object)) = self.objects.get(observer) else {
+            return Vec::new();
+        };
+
+        self.objects_visible_from(observer, observer_coord, &observer_object.perception())
+    }
+
+    // objects_visible_from returns every object other than the observer
+    // visible from the given coordinate, ordered by handle
+    pub(super) fn objects_visible_from(&self, observer: &WorldObjectHandle, observer_coord: &WorldCoord, perception: &Perception) -> Vec<(&WorldObjectHandle, WorldCoord, &dyn WorldObject)> {
+        let mut visible = self.objects.iter()
+            .filter(|(handle, _)| *handle != observer)
+            .filter(|(_, (coord, _))| perception.can_see_from(observer_coord, coord))
+            .map(|(handle, (coord, object))| (handle, *coord, object.as_ref()))
+            .collect::<Vec<_>>();
+
+        visible.sort_by_key(|(handle, _, _)| *handle);
+        visible
+    }
+
+    // perceivers_of returns the handles of every object that can
+    // see or hear an event taking place at the given coordinate
+    pub fn perceivers_of(&self, origin: &WorldCoord) -> Vec<WorldObjectHandle> {
+        self.objects.iter()
+            .filter(|(_, (coord, object))| object.perception().can_perceive_from(coord, origin))
+            .map(|(handle, _)| *handle)
+            .collect()
+    }
+}
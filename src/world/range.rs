@@ -0,0 +1,60 @@
+// This module houses the range-checking layer, which is used
+// to reject actions whose target is too far from the actor
+// (e.g. punching a rat on the other side of the map).
+
+use std::fmt;
+
+use crate::quantities::{
+    Quantity,
+    distance::{Distance, meters}
+};
+
+use super::{
+    WorldObjectGetError,
+    handle::WorldObjectHandle,
+    view::WorldView
+};
+
+#[derive(Debug)]
+pub enum RangeError {
+    FailedToLocateObject(WorldObjectGetError),
+    OutOfRange {
+        distance: Quantity<Distance>,
+        range: Quantity<Distance>,
+    },
+}
+
+impl fmt::Display for RangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::FailedToLocateObject(err) => write!(f, "failed to locate object: {}", err),
+            Self::OutOfRange { distance, range } => write!(
+                f,
+                "it is {:.1} meters away, but can only be reached from {:.1} meters",
+                (distance / &meters(1.0)).cancel().0.0,
+                (range / &meters(1.0)).cancel().0.0
+            ),
+        }
+    }
+}
+
+impl std::error::Error for RangeError {}
+
+impl WorldView<'_> {
+    // distance_between returns the straight-line distance between two objects
+    pub fn distance_between(&self, a: &WorldObjectHandle, b: &WorldObjectHandle) -> Result<Quantity<Distance>, WorldObjectGetError> {
+        Ok(self.locate_object(a)?.distance_to(&self.locate_object(b)?))
+    }
+
+    // check_range succeeds if the target is within the given range of the actor
+    pub fn check_range(&self, actor: &WorldObjectHandle, target: &WorldObjectHandle, range: &Quantity<Distance>) -> Result<(), RangeError> {
+        let distance = self.distance_between(actor, target)
+            .map_err(RangeError::FailedToLocateObject)?;
+
+        if &distance > range {
+            return Err(RangeError::OutOfRange { distance, range: *range });
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,34 @@
+// This module houses the world's source of randomness.  Everything left
+// to chance in the world, from where an unaimed blow lands to which way
+// a rat wanders, is drawn from a single generator seeded when the world
+// is made, so that a world started from the same seed, and given the same
+// commands, always plays out the same way.  The generator is ChaCha8,
+// whose output, unlike that of rand's StdRng, is fixed across versions
+// and platforms, so journals and transcripts stay good.
+
+use rand::{RngCore, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use super::World;
+
+pub type WorldRng = ChaCha8Rng;
+
+impl World {
+    // the seed the world was made with
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    // fork_rng draws a new generator from the world's, for chance
+    // that is settled away from the world, e.g. by a struck object
+    pub fn fork_rng(&mut self) -> WorldRng {
+        WorldRng::seed_from_u64(self.rng.next_u64())
+    }
+
+    // next_seed is the seed the world's generator would hand out next,
+    // without drawing it; it is the seed the next actor to take a turn
+    // will be handed
+    pub fn next_seed(&self) -> u64 {
+        self.rng.clone().next_u64()
+    }
+}
@@ -0,0 +1,288 @@
+// This module implements saving a world to, and loading it from,
+// a versioned JSON snapshot on disk.  Objects are persisted via
+// the type registry, so every object type that can appear in the
+// world must be registered there.  Controllers are not persisted;
+// characters in a loaded world must be reclaimed by their players.
+
+use std::{
+    fmt,
+    fs,
+    path::Path
+};
+
+use serde::{Serialize, Deserialize};
+
+use crate::{
+    logging::{Logger, LoggerImpl},
+    quantities::{
+        Quantity,
+        duration::Duration
+    },
+    worldobject::registry::{self, RegistryError}
+};
+
+use super::{
+    World,
+    coord::WorldCoord,
+    handle::{HandleAllocator, WorldObjectHandle, WorldObjectLabel},
+    rng::WorldRng
+};
+
+// the snapshot format version written by World::save;
+// bump this whenever the layout of the snapshot changes
+pub const SNAPSHOT_VERSION: u64 = 3;
+
+#[derive(Deserialize)]
+struct SnapshotVersion {
+    version: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+struct WorldSnapshot {
+    version: u64,
+    clock: Quantity<Duration>,
+    turn: u64,
+    // the seed the world was made with, and the state its generator carries on from
+    seed: u64,
+    rng: WorldRng,
+    // every handle slot, so that the handles of objects that have
+    // left the world aren't handed out again
+    handles: HandleAllocator,
+    objects: Vec<WorldObjectSnapshot>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct WorldObjectSnapshot {
+    handle: WorldObjectHandle,
+    label: WorldObjectLabel,
+    coord: WorldCoord,
+    object: serde_json::Value,
+    // when the object is next due to act, if it has acted before
+    #[serde(default)]
+    next_turn: Option<Quantity<Duration>>,
+}
+
+#[derive(Debug)]
+pub enum WorldSaveError {
+    ObjectNotSerializable(WorldObjectHandle, RegistryError),
+    FailedToEncode(serde_json::Error),
+    FailedToWrite(std::io::Error),
+}
+
+impl fmt::Display for WorldSaveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ObjectNotSerializable(handle, err) => write!(f, "failed to serialize object with handle \"{}\": {}", handle, err),
+            Self::FailedToEncode(err) => write!(f, "failed to encode snapshot: {}", err),
+            Self::FailedToWrite(err) => write!(f, "failed to write snapshot: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for WorldSaveError {}
+
+#[derive(Debug)]
+pub enum WorldLoadError {
+    FailedToRead(std::io::Error),
+    FailedToDecode(serde_json::Error),
+    UnsupportedVersion(u64),
+    FailedToDeserializeObject(WorldObjectLabel, RegistryError),
+}
+
+impl fmt::Display for WorldLoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::FailedToRead(err) => write!(f, "failed to read snapshot: {}", err),
+            Self::FailedToDecode(err) => write!(f, "failed to decode snapshot: {}", err),
+            Self::UnsupportedVersion(version) => write!(f, "unsupported snapshot version {} (expected {})", version, SNAPSHOT_VERSION),
+            Self::FailedToDeserializeObject(label, err) => write!(f, "failed to deserialize object \"{}\": {}", label, err),
+        }
+    }
+}
+
+impl std::error::Error for WorldLoadError {}
+
+impl World {
+    // to_snapshot serializes every object in the world, along with its position
+    pub fn to_snapshot(&self) -> Result<serde_json::Value, WorldSaveError> {
+        let mut objects = self.objects.iter()
+            .map(|(handle, (coord, object))| {
+                let object = registry::world_object_to_json(object.as_ref())
+                    .map_err(|err| WorldSaveError::ObjectNotSerializable(*handle, err))?;
+                let label = self.labels.get(handle).cloned().unwrap_or_default();
+                let next_turn = self.schedule.get(handle).cloned();
+                Ok(WorldObjectSnapshot { handle: *handle, label, coord: *coord, object, next_turn })
+            })
+            .collect::<Result<Vec<_>, WorldSaveError>>()?;
+
+        // sort the objects so that saving the same world always yields the same file
+        objects.sort_by_key(|object| object.handle);
+
+        let snapshot = WorldSnapshot {
+            version: SNAPSHOT_VERSION,
+            clock: self.clock.clone(),
+            turn: self.turn,
+            seed: self.seed,
+            rng: self.rng.clone(),
+            handles: self.handles.clone(),
+            objects,
+        };
+
+        serde_json::to_value(snapshot)
+            .map_err(WorldSaveError::FailedToEncode)
+    }
+
+    // from_snapshot reconstructs a world from the output of to_snapshot
+    pub fn from_snapshot(snapshot: serde_json::Value, logger: Logger<impl LoggerImpl + 'static>) -> Result<Self, WorldLoadError> {
+        // the version is checked first, as snapshots of other
+        // versions may not decode as this one's layout
+        let SnapshotVersion { version } = SnapshotVersion::deserialize(&snapshot)
+            .map_err(WorldLoadError::FailedToDecode)?;
+        if version != SNAPSHOT_VERSION {
+            return Err(WorldLoadError::UnsupportedVersion(version));
+        }
+
+        let snapshot: WorldSnapshot = serde_json::from_value(snapshot)
+            .map_err(WorldLoadError::FailedToDecode)?;
+
+        let mut world = World::with_seed(logger, snapshot.seed);
+        world.clock = snapshot.clock;
+        world.turn = snapshot.turn;
+
+        // a loaded world leaves things to the same chances
+        // as the one that was saved would have
+        world.rng = snapshot.rng;
+        world.handles = snapshot.handles;
+
+        for object_snapshot in snapshot.objects {
+            let WorldObjectSnapshot { handle, label, coord, object, next_turn } = object_snapshot;
+
+            let object = registry::world_object_from_json(&object)
+                .map_err(|err| WorldLoadError::FailedToDeserializeObject(label.clone(), err))?;
+
+            if let Some(next_turn) = next_turn {
+                world.schedule.insert(handle, next_turn);
+            }
+
+            world.restore_object(handle, label, object, coord);
+        }
+
+        Ok(world)
+    }
+
+    // save writes a snapshot of the world to the given path
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), WorldSaveError> {
+        let snapshot = self.to_snapshot()?;
+        let json = serde_json::to_vec_pretty(&snapshot)
+            .map_err(WorldSaveError::FailedToEncode)?;
+        fs::write(path, json).map_err(WorldSaveError::FailedToWrite)
+    }
+
+    // load reads a world previously written by save
+    pub fn load(path: impl AsRef<Path>, logger: Logger<impl LoggerImpl + 'static>) -> Result<Self, WorldLoadError> {
+        let json = fs::read(path).map_err(WorldLoadError::FailedToRead)?;
+        let snapshot = serde_json::from_slice(&json)
+            .map_err(WorldLoadError::FailedToDecode)?;
+        Self::from_snapshot(snapshot, logger)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::{
+        logging::noop::NoopLogger,
+        materials::Material,
+        quantities::{direction::DirectionHorizontal, distance::meters, mass::kilograms, speed::meters_per_second},
+        tests::fixtures::{human, sword},
+        worldobject::{
+            components::inventory::{Inventory, item::sword::Sword},
+            rat::Rat
+        }
+    };
+
+    use super::{World, WorldCoord, WorldLoadError, SNAPSHOT_VERSION};
+
+    // a world that has been played for a few turns, so that
+    // its clock, turn count, schedule and generator have moved on
+    async fn played_world() -> World {
+        let mut world = World::with_seed(NoopLogger::new(), 7);
+
+        let mut inventory = Inventory::new();
+        inventory.stow(Box::new(sword("steel")));
+        world.add_object(Box::new(human("alice", DirectionHorizontal::Left, inventory)), WorldCoord::new(meters(0.0), meters(0.0)));
+        world.add_object(Box::new(Rat::new(kilograms(1.0), meters_per_second(1.0))), WorldCoord::new(meters(2.0), meters(0.0)));
+        world.add_object(Box::new(Sword::new(meters(0.5), Material::named("bronze").unwrap())), WorldCoord::new(meters(1.0), meters(0.0)));
+
+        for _ in 0..3 {
+            world.update().await.unwrap();
+        }
+        world
+    }
+
+    // a loaded world carries on just as the saved one does
+    #[tokio::test]
+    async fn a_world_survives_a_round_trip_through_a_snapshot() {
+        let mut world = played_world().await;
+        let snapshot = world.to_snapshot().unwrap();
+
+        let mut loaded = World::from_snapshot(snapshot, NoopLogger::new()).unwrap();
+        assert_eq!(loaded.turn(), world.turn());
+        assert_eq!(loaded.next_seed(), world.next_seed());
+        assert_eq!(loaded.to_snapshot().unwrap(), world.to_snapshot().unwrap());
+
+        for _ in 0..3 {
+            world.update().await.unwrap();
+            loaded.update().await.unwrap();
+        }
+        assert_eq!(loaded.to_snapshot().unwrap(), world.to_snapshot().unwrap());
+    }
+
+    // every quantity, e.g. the length of a tooth, is read back exactly as it was written
+    #[tokio::test]
+    async fn a_world_survives_a_round_trip_through_a_file() {
+        let path = std::env::temp_dir().join(format!("modus-save-{}.json", std::process::id()));
+        let world = played_world().await;
+
+        world.save(&path).unwrap();
+        let loaded = World::load(&path, NoopLogger::new());
+        _ = std::fs::remove_file(&path);
+
+        assert_eq!(loaded.unwrap().to_snapshot().unwrap(), world.to_snapshot().unwrap());
+    }
+
+    // the slot of an object that left the world is handed out again
+    // under a new generation, so its old handle stays stale
+    #[test]
+    fn a_loaded_world_never_hands_out_a_stale_handle() {
+        let mut world = World::with_seed(NoopLogger::new(), 7);
+        world.add_object(Box::new(Rat::new(kilograms(1.0), meters_per_second(1.0))), WorldCoord::new(meters(1.0), meters(0.0)));
+        let mut departed = Vec::new();
+        for _ in 0..2 {
+            let handle = world.add_object(Box::new(sword("steel")), WorldCoord::new(meters(0.0), meters(0.0)));
+            world.take_object(&handle).unwrap();
+            departed.push(handle);
+        }
+
+        let mut loaded = World::from_snapshot(world.to_snapshot().unwrap(), NoopLogger::new()).unwrap();
+        let handle = loaded.add_object(Box::new(sword("steel")), WorldCoord::new(meters(0.0), meters(0.0)));
+        assert!(!departed.contains(&handle));
+        assert_eq!(handle, world.add_object(Box::new(sword("steel")), WorldCoord::new(meters(0.0), meters(0.0))));
+    }
+
+    #[test]
+    fn rejects_snapshots_of_other_versions() {
+        let snapshot = json!({ "version": SNAPSHOT_VERSION + 1, "objects": "whatever the layout" });
+        assert!(matches!(World::from_snapshot(snapshot, NoopLogger::new()), Err(WorldLoadError::UnsupportedVersion(_))));
+    }
+
+    #[test]
+    fn rejects_snapshots_missing_a_field() {
+        let world = World::with_seed(NoopLogger::new(), 7);
+        let mut snapshot = world.to_snapshot().unwrap();
+        snapshot.as_object_mut().unwrap().remove("rng");
+
+        assert!(matches!(World::from_snapshot(snapshot, NoopLogger::new()), Err(WorldLoadError::FailedToDecode(_))));
+    }
+}
@@ -0,0 +1,93 @@
+// This module houses the turn scheduler.  The world keeps a simulation
+// clock, and every object that acts is due to act at some point in time.
+// Each update, the clock advances to the earliest due object, which
+// takes its turn and is then rescheduled according to how long its
+// action took.  Objects due at the same time act in descending order of
+// initiative, and ties are broken by handle so that the order never
+// depends on the iteration order of the world's object map.
+
+use std::cmp::Ordering;
+
+use crate::{
+    quantities::{
+        Quantity,
+        duration::Duration
+    },
+    worldobject::fns::update::Action
+};
+
+use super::{
+    World,
+    handle::WorldObjectHandle
+};
+
+impl World {
+    // the current time on the simulation clock
+    pub fn clock(&self) -> Quantity<Duration> {
+        self.clock.clone()
+    }
+
+    // how many turns have been taken in the world
+    pub fn turn(&self) -> u64 {
+        self.turn
+    }
+
+    // due_time returns when the object is next due to act; objects
+    // that have never acted are due immediately
+    pub fn due_time(&self, handle: &WorldObjectHandle) -> Quantity<Duration> {
+        self.schedule.get(handle).cloned().unwrap_or(self.clock.clone())
+    }
+
+    // turn_order returns the handles of every object that acts,
+    // in the order in which they are due to act
+    pub fn turn_order(&self) -> Vec<WorldObjectHandle> {
+        let mut actors = self.objects.iter()
+            .filter_map(|(handle, (_, object))| object.initiative().map(|initiative| (handle, self.due_time(handle), initiative)))
+            .collect::<Vec<_>>();
+
+        actors.sort_by(|(handle_a, due_a, initiative_a), (handle_b, due_b, initiative_b)| {
+            due_a.partial_cmp(due_b)
+                .unwrap_or(Ordering::Equal)
+                .then_with(|| initiative_b.partial_cmp(initiative_a).unwrap_or(Ordering::Equal))
+                .then_with(|| handle_a.cmp(handle_b))
+        });
+
+        actors.into_iter().map(|(handle, _, _)| *handle).collect()
+    }
+
+    // schedule_next_turn makes the object due again once an action taking
+    // the given amount of time has finished; no action takes less than
+    // the minimum cost, so that no object can act without the clock moving
+    pub(super) fn schedule_next_turn(&mut self, handle: &WorldObjectHandle, cost: Quantity<Duration>) {
+        let cost = if cost > Action::min_cost() { cost } else { Action::min_cost() };
+        self.schedule.insert(*handle, self.clock.clone() + cost);
+    }
+
+    // forget_departed_objects drops the schedule entries
+    // of objects that are no longer in the world
+    pub(super) fn forget_departed_objects(&mut self) {
+        let objects = &self.objects;
+        self.schedule.retain(|handle, _| objects.contains_key(handle));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        logging::noop::NoopLogger,
+        quantities::duration::seconds,
+        world::{World, handle::HandleAllocator}
+    };
+
+    #[test]
+    fn no_action_is_over_in_no_time() {
+        let mut world = World::with_seed(NoopLogger::new(), 1);
+        let handle = HandleAllocator::new().allocate();
+
+        world.schedule_next_turn(&handle, seconds(0.0));
+        assert!(world.due_time(&handle) > world.clock());
+
+        world.schedule_next_turn(&handle, seconds(-5.0));
+        assert!(world.due_time(&handle) > world.clock());
+    }
+}
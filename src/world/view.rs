@@ -0,0 +1,93 @@
+// This module houses WorldView, a read-only view of the world
+// handed to an object while it decides what to do on its turn.
+//
+// The acting object is taken out of the world for the duration
+// of its update so that it can be borrowed mutably while the rest
+// of the world is borrowed immutably.  The view stands in for the
+// world during that time, and still knows where the actor is.
+
+use std::sync::Mutex;
+
+use crate::worldobject::WorldObject;
+
+use super::{
+    World,
+    WorldObjectGetError,
+    coord::WorldCoord,
+    handle::{WorldObjectHandle, WorldObjectLabel},
+    perception::Perception
+};
+
+pub struct WorldView<'a> {
+    world: &'a World,
+    actor_handle: &'a WorldObjectHandle,
+    actor_coord: WorldCoord,
+    // drawn from the world's generator for this turn
+    seed: u64,
+    // the command the actor gave, once it has been turned into an action
+    accepted: Mutex<Option<serde_json::Value>>,
+}
+
+impl<'a> WorldView<'a> {
+    // world must not contain the actor, which is
+    // instead described by its handle and position
+    pub(super) fn new(world: &'a World, actor_handle: &'a WorldObjectHandle, actor_coord: WorldCoord, seed: u64) -> Self {
+        Self { world, actor_handle, actor_coord, seed, accepted: Mutex::new(None) }
+    }
+
+    // accept tells the world the actor gave the given command, serialized,
+    // and that it was turned into an action, so that it can be journaled
+    pub fn accept(&self, command: serde_json::Value) {
+        if let Ok(mut accepted) = self.accepted.lock() {
+            *accepted = Some(command);
+        }
+    }
+
+    pub(super) fn into_accepted(self) -> Option<serde_json::Value> {
+        self.accepted.into_inner().ok().flatten()
+    }
+
+    // seed returns a seed for whatever the actor leaves to chance while
+    // it decides what to do; it is drawn afresh from the world each turn
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    // get_object returns any object other than the actor,
+    // which is busy acting and can't be looked at
+    pub fn get_object(&self, handle: &WorldObjectHandle) -> Result<&'a dyn WorldObject, WorldObjectGetError> {
+        if handle == self.actor_handle {
+            return Err(WorldObjectGetError::ObjectIsActing(*handle));
+        }
+
+        self.world.get_object(handle)
+    }
+
+    pub fn locate_object(&self, handle: &WorldObjectHandle) -> Result<WorldCoord, WorldObjectGetError> {
+        if handle == self.actor_handle {
+            return Ok(self.actor_coord);
+        }
+
+        self.world.locate_object(handle)
+    }
+
+    pub fn actor_coord(&self) -> WorldCoord {
+        self.actor_coord
+    }
+
+    // visible_objects returns every object the actor can see
+    // with the given perception, along with its position
+    pub fn visible_objects(&self, perception: &Perception) -> Vec<(&'a WorldObjectHandle, WorldCoord, &'a dyn WorldObject)> {
+        self.world.objects_visible_from(self.actor_handle, &self.actor_coord, perception)
+    }
+
+    // labels stay with the world while the actor is acting,
+    // so the actor can be found by its label too
+    pub fn find_object(&self, label: &str) -> Result<WorldObjectHandle, WorldObjectGetError> {
+        self.world.find_object(label)
+    }
+
+    pub fn label_of(&self, handle: &WorldObjectHandle) -> Result<&'a WorldObjectLabel, WorldObjectGetError> {
+        self.world.label_of(handle)
+    }
+}
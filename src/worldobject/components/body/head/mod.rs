@@ -1,31 +1,30 @@
 use serde::Serialize;
 
-use crate::quantities::{
-    Quantity,
-    distance::Distance,
-    mass::{
-        Mass,
-        grams,
+use crate::{
+    quantities::{
+        Quantity,
+        distance::Distance,
+        mass::{
+            Mass,
+            grams,
+        },
     },
+    worldobject::components::health::Health,
 };
 
+pub const HEAD_HIT_POINTS: f64 = 30.0;
+
 #[derive(Serialize)]
 pub struct Head {
     pub base_mass: Quantity<Mass>,
     pub mouth: Mouth,
+    pub health: Health,
 }
 
 impl Head {
     pub fn mass(&self) -> Quantity<Mass> {
         self.base_mass.clone() + self.mouth.mass()
     }
-
-    pub fn dummy(&self) -> Head {
-        Head {
-            base_mass: self.base_mass.clone(),
-            mouth: self.mouth.dummy(),
-        }
-    }
 }
 
 impl TryFrom<&serde_json::Value> for Head {
@@ -34,7 +33,8 @@ impl TryFrom<&serde_json::Value> for Head {
     fn try_from(value: &serde_json::Value) -> Result<Self, Self::Error> {
         let base_mass = Quantity::<Mass>::try_from(value.get("base_mass").ok_or("base_mass not found")?.clone()).map_err(|err| format!("failed to parse base_mass: {}", err))?;
         let mouth = Mouth::try_from(value.get("mouth").ok_or("mouth not found")?).map_err(|err| format!("failed to parse mouth: {}", err))?;
-        Ok(Head { base_mass, mouth })
+        let health = Health::try_from(value.get("health").ok_or("health not found")?).map_err(|err| format!("failed to parse health: {}", err))?;
+        Ok(Head { base_mass, mouth, health })
     }
 }
 
@@ -48,13 +48,6 @@ impl Mouth {
     pub fn mass(&self) -> Quantity<Mass> {
         self.base_mass.clone() + self.teeth.iter().fold(grams(0.0), |acc, tooth| acc + tooth.mass())
     }
-
-    pub fn dummy(&self) -> Mouth {
-        Mouth {
-            base_mass: self.base_mass.clone(),
-            teeth: self.teeth.iter().map(|tooth| tooth.dummy()).collect(),
-        }
-    }
 }
 
 impl TryFrom<&serde_json::Value> for Mouth {
@@ -78,14 +71,6 @@ impl Tooth {
     pub fn mass(&self) -> Quantity<Mass> {
         self.mass.clone()
     }
-
-    pub fn dummy(&self) -> Tooth {
-        Tooth {
-            mass: self.mass.clone(),
-            length: self.length.clone(),
-            sharp: self.sharp,
-        }
-    }
 }
 
 impl TryFrom<&serde_json::Value> for Tooth {
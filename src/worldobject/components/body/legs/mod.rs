@@ -1,15 +1,25 @@
 use serde::Serialize;
 
-use crate::quantities::{
-    Quantity,
-    speed::Speed,
-    mass::Mass,
+use crate::{
+    quantities::{
+        Quantity,
+        speed::{Speed, meters_per_second},
+        mass::Mass,
+    },
+    worldobject::components::health::Health,
 };
 
+pub const LEGS_HIT_POINTS: f64 = 50.0;
+
+// the fraction of their speed that broken legs can still manage by crawling
+const CRAWL_FRACTION: f64 = 0.1;
+
 #[derive(Serialize)]
 pub struct Legs {
     pub base_mass: Quantity<Mass>,
+    // how fast the legs are when unhurt
     pub speed: Quantity<Speed>,
+    pub health: Health,
 }
 
 impl Legs {
@@ -17,11 +27,11 @@ impl Legs {
         self.base_mass.clone()
     }
 
-    pub fn dummy(&self) -> Legs {
-        Legs {
-            base_mass: self.base_mass.clone(),
-            speed: self.speed.clone(),
-        }
+    // how fast the legs are as they are; injured legs slow
+    // down, and broken legs can do no better than a crawl
+    pub fn current_speed(&self) -> Quantity<Speed> {
+        let speed = (&self.speed / &meters_per_second(1.0)).cancel().0.0;
+        meters_per_second(speed * self.health.fraction().max(CRAWL_FRACTION))
     }
 }
 
@@ -31,6 +41,7 @@ impl TryFrom<&serde_json::Value> for Legs {
     fn try_from(value: &serde_json::Value) -> Result<Self, Self::Error> {
         let base_mass = Quantity::<Mass>::try_from(value.get("base_mass").ok_or("base_mass not found")?.clone()).map_err(|err| format!("failed to parse base_mass: {}", err))?;
         let speed = Quantity::<Speed>::try_from(value.get("speed").ok_or("speed not found")?.clone()).map_err(|err| format!("failed to parse speed: {}", err))?;
-        Ok(Legs { base_mass, speed })
+        let health = Health::try_from(value.get("health").ok_or("health not found")?).map_err(|err| format!("failed to parse health: {}", err))?;
+        Ok(Legs { base_mass, speed, health })
     }
-}
\ No newline at end of file
+}
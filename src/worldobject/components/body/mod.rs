@@ -2,19 +2,102 @@ pub mod head;
 pub mod torso;
 pub mod legs;
 
-use serde::Serialize;
+use std::fmt;
 
-use crate::quantities::{
-    Quantity,
-    mass::Mass,
+use rand::{Rng, seq::IndexedRandom};
+use serde::{Serialize, Deserialize};
+
+use crate::{
+    quantities::{
+        Quantity,
+        mass::Mass,
+        direction::DirectionHorizontal,
+    },
+    worldobject::components::{
+        health::Health,
+        inventory::item::InventoryItem,
+    },
 };
 
 use {
     legs::Legs,
-    torso::Torso,
+    torso::{Torso, arm::Arm},
     head::Head,
 };
 
+// words that may come before the name of a body part, as in "stab rat in its head"
+const BODY_PART_FILLER_WORDS: [&str; 6] = ["my", "his", "her", "its", "their", "your"];
+
+// BodyPart is a part of a body that a blow can land on
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum BodyPart {
+    Head,
+    Torso,
+    Arm(DirectionHorizontal),
+    Hand(DirectionHorizontal),
+    Legs,
+}
+
+// how likely a blow that isn't aimed is to land on each
+// body part, roughly in proportion to the part's size
+const BODY_PART_WEIGHTS: [(BodyPart, u32); 7] = [
+    (BodyPart::Head, 10),
+    (BodyPart::Torso, 35),
+    (BodyPart::Arm(DirectionHorizontal::Left), 10),
+    (BodyPart::Arm(DirectionHorizontal::Right), 10),
+    (BodyPart::Hand(DirectionHorizontal::Left), 5),
+    (BodyPart::Hand(DirectionHorizontal::Right), 5),
+    (BodyPart::Legs, 25),
+];
+
+impl fmt::Display for BodyPart {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Head => write!(f, "head"),
+            Self::Torso => write!(f, "torso"),
+            Self::Arm(side) => write!(f, "{} arm", side),
+            Self::Hand(side) => write!(f, "{} hand", side),
+            Self::Legs => write!(f, "legs"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct InvalidBodyPartError(String);
+
+impl fmt::Display for InvalidBodyPartError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid body part: {}; try head, torso, legs, or a left or right arm or hand", self.0)
+    }
+}
+
+impl std::error::Error for InvalidBodyPartError {}
+
+impl TryFrom<&[&str]> for BodyPart {
+    type Error = InvalidBodyPartError;
+
+    fn try_from(words: &[&str]) -> Result<Self, Self::Error> {
+        let name = words.iter()
+            .filter(|word| !BODY_PART_FILLER_WORDS.contains(word))
+            .copied()
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        match name.split_once(' ') {
+            Some((side, "arm")) => DirectionHorizontal::try_from(side).map(Self::Arm).map_err(|_| InvalidBodyPartError(name.clone())),
+            Some((side, "hand")) => DirectionHorizontal::try_from(side).map(Self::Hand).map_err(|_| InvalidBodyPartError(name.clone())),
+            // the legs are hurt as one, whichever leg is named
+            Some((side, "leg")) => DirectionHorizontal::try_from(side).map(|_| Self::Legs).map_err(|_| InvalidBodyPartError(name.clone())),
+            _ => match name.as_str() {
+                "head" => Ok(Self::Head),
+                "torso" | "chest" | "body" => Ok(Self::Torso),
+                "legs" | "leg" => Ok(Self::Legs),
+                _ => Err(InvalidBodyPartError(name)),
+            }
+        }
+    }
+}
+
 #[derive(Serialize)]
 pub struct Body {
     pub base_mass: Quantity<Mass>,
@@ -29,14 +112,70 @@ impl Body {
         self.base_mass.clone() + self.torso.mass() + self.legs.mass() + self.head.mass()
     }
 
-    pub fn dummy(&self) -> Body {
-        Body {
-            base_mass: self.base_mass.clone(),
-            head: self.head.dummy(),
-            torso: self.torso.dummy(),
-            legs: self.legs.dummy(),
+    // arm returns the arm on the given side, or None if it has been severed
+    pub fn arm(&self, side: DirectionHorizontal) -> Option<&Arm> {
+        match side {
+            DirectionHorizontal::Left => self.torso.left_arm.as_ref(),
+            DirectionHorizontal::Right => self.torso.right_arm.as_ref(),
         }
     }
+
+    pub fn arm_mut(&mut self, side: DirectionHorizontal) -> Option<&mut Arm> {
+        match side {
+            DirectionHorizontal::Left => self.torso.left_arm.as_mut(),
+            DirectionHorizontal::Right => self.torso.right_arm.as_mut(),
+        }
+    }
+
+    // has_part says whether the body still has the given part
+    pub fn has_part(&self, part: BodyPart) -> bool {
+        match part {
+            BodyPart::Arm(side) => self.arm(side).is_some(),
+            BodyPart::Hand(side) => self.arm(side).is_some_and(|arm| arm.hand.is_some()),
+            BodyPart::Head | BodyPart::Torso | BodyPart::Legs => true,
+        }
+    }
+
+    // health_mut returns the health of the given part,
+    // or None if the body doesn't have that part
+    pub fn health_mut(&mut self, part: BodyPart) -> Option<&mut Health> {
+        match part {
+            BodyPart::Head => Some(&mut self.head.health),
+            BodyPart::Torso => Some(&mut self.torso.health),
+            BodyPart::Arm(side) => self.arm_mut(side).map(|arm| &mut arm.health),
+            BodyPart::Hand(side) => self.arm_mut(side).and_then(|arm| arm.hand.as_mut()).map(|hand| &mut hand.health),
+            BodyPart::Legs => Some(&mut self.legs.health),
+        }
+    }
+
+    // sever cuts an arm or a hand from the body, along with everything
+    // attached to it, returning it; other parts can't be severed
+    pub fn sever(&mut self, part: BodyPart) -> Option<Box<dyn InventoryItem>> {
+        match part {
+            BodyPart::Arm(DirectionHorizontal::Left) => self.torso.left_arm.take().map(|arm| Box::new(arm) as Box<dyn InventoryItem>),
+            BodyPart::Arm(DirectionHorizontal::Right) => self.torso.right_arm.take().map(|arm| Box::new(arm) as Box<dyn InventoryItem>),
+            BodyPart::Hand(side) => self.arm_mut(side).and_then(|arm| arm.hand.take()).map(|hand| Box::new(hand) as Box<dyn InventoryItem>),
+            BodyPart::Head | BodyPart::Torso | BodyPart::Legs => None,
+        }
+    }
+
+    // random_part picks the part an unaimed blow lands on
+    pub fn random_part(&self, rng: &mut impl Rng) -> BodyPart {
+        BODY_PART_WEIGHTS.choose_weighted(rng, |(_, weight)| *weight)
+            .map(|(part, _)| *part)
+            .unwrap_or(BodyPart::Torso)
+    }
+
+    // health returns the fraction of its life the body has left, which is
+    // that of whichever of its head and torso is the worse off
+    pub fn health(&self) -> f64 {
+        self.head.health.fraction().min(self.torso.health.fraction())
+    }
+
+    // a body lives for as long as its head and torso hold out
+    pub fn is_alive(&self) -> bool {
+        !self.head.health.is_disabled() && !self.torso.health.is_disabled()
+    }
 }
 
 impl TryFrom<&serde_json::Value> for Body {
@@ -49,4 +188,4 @@ impl TryFrom<&serde_json::Value> for Body {
         let legs = Legs::try_from(value.get("legs").ok_or("legs not found")?).map_err(|err| format!("failed to parse legs: {}", err))?;
         Ok(Body { base_mass, head, torso, legs })
     }
-}
\ No newline at end of file
+}
@@ -3,31 +3,43 @@ use serde::{Serialize, Deserialize};
 use async_trait::async_trait;
 
 use crate::{
-    worldobject::components::inventory::{
-        Inventory,
-        item::InventoryItem
+    combat::BlowOutcome,
+    worldobject::components::{
+        health::Health,
+        inventory::{
+            Inventory,
+            item::InventoryItem
+        }
     },
     quantities::{
         Quantity,
         mass::Mass,
-        force::Force
+        force::Force,
+        distance::{Distance, meters},
+        speed::Speed
     },
     worldobject::{
         TypedWorldObject,
         Error as WorldObjectError,
+        registry,
         fns::update::Action
     },
     world::{
         handle::WorldObjectHandle,
-        World
+        rng::WorldRng,
+        view::WorldView,
+        perception::Perception
     },
     worldobject::components::controllers::Controller
 };
 use std::fmt;
 
+pub const HAND_HIT_POINTS: f64 = 20.0;
+
 pub struct Hand {
     base_mass: Quantity<Mass>,
     held_item: Option<Box<dyn InventoryItem>>,
+    pub health: Health,
 }
 
 #[derive(Debug)]
@@ -41,14 +53,6 @@ impl fmt::Display for HandInventoryError {
 
 impl std::error::Error for HandInventoryError {}
 
-pub struct HandDeserializeError;
-
-impl fmt::Display for HandDeserializeError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "HandDeserializeError")
-    }
-}
-
 #[derive(Debug)]
 pub struct HandControllerError;
 
@@ -62,35 +66,27 @@ impl std::error::Error for HandControllerError {}
 
 #[async_trait]
 impl TypedWorldObject for Hand {
-    type Dummy = Self;
     type CollectInventoryItem = Self;
+    const TYPE_TAG: &'static str = "hand";
 
     fn name(&self) -> String {
         String::from("hand")
     }
 
+    // a hand is only ever seen on its own once it has been severed
     fn definite_description(&self) -> String {
-        String::from("the hand")
+        String::from("the severed hand")
     }
 
     fn indefinite_description(&self) -> String {
-        String::from("a hand")
+        String::from("a severed hand")
     }
 
     fn pronoun(&self) -> String {
         String::from("it")
     }
 
-    fn dummy(&self) -> Self::Dummy {
-        Hand {
-            held_item: self.held_item.as_ref().map(
-                |held_item| InventoryItem::dummy(&*held_item)
-            ),
-            base_mass: self.base_mass.clone()
-        }
-    }
-
-    async fn update(&mut self, my_handle: WorldObjectHandle, world: &World) -> Result<Action, WorldObjectError> {
+    async fn update(&mut self, _my_handle: WorldObjectHandle, _world: &WorldView) -> Result<Action, WorldObjectError> {
         Ok(Action::no_op())
     }
 
@@ -116,12 +112,23 @@ impl TypedWorldObject for Hand {
         ret
     }
 
-    async fn apply_force(&mut self, force: &Quantity<Force>) -> Result<String, WorldObjectError> {
-        Ok(String::from(""))
+    fn perception(&self) -> Perception {
+        Perception::none()
+    }
+
+    fn initiative(&self) -> Option<Quantity<Speed>> {
+        None
+    }
+
+    async fn apply_force(&mut self, _force: &Quantity<Force>, _rng: &mut WorldRng) -> Result<BlowOutcome, WorldObjectError> {
+        Ok(BlowOutcome::from(String::new()))
     }
     
     fn examine(&self) -> String {
-        String::from("an arm")
+        match self.wielded_item() {
+            Some(item) => format!("a severed hand, still clutching {}", item.indefinite_description()),
+            None => String::from("a severed hand"),
+        }
     }
 
     async fn send_message(&mut self, message: String) -> Result<(), WorldObjectError> {
@@ -161,11 +168,15 @@ impl std::fmt::Display for HandUseError {
 }
 
 impl InventoryItem for Hand {
-    fn dummy(&self) -> Box<dyn InventoryItem> {
-        Box::new(<Hand as TypedWorldObject>::dummy(self))
+    fn reach(&self) -> Quantity<Distance> {
+        meters(0.0)
     }
 
-    fn use_item(&mut self, _: &World, _: WorldObjectHandle, _: Option<WorldObjectHandle>) -> Result<Action, Box<dyn std::error::Error>> {
+    fn range(&self) -> Quantity<Distance> {
+        meters(0.0)
+    }
+
+    fn use_item(&mut self, _: &WorldView, _: WorldObjectHandle, _: Option<WorldObjectHandle>) -> Result<Action, Box<dyn std::error::Error>> {
         return Err(Box::new(HandUseError));
     }
 }
@@ -176,18 +187,31 @@ impl<'de> TryFrom<&serde_json::Value> for Hand {
     fn try_from(value: &serde_json::Value) -> Result<Self, Self::Error> {
         let base_mass = value.get("base_mass").ok_or(String::from("base_mass not found"))
             .and_then(|v| Quantity::<Mass>::try_from(v.clone()).map_err(|err| format!("failed to parse base_mass: {}", err)))?;
+        let held_item = value.get("held_item").filter(|v| !v.is_null())
+            .map(registry::inventory_item_from_json).transpose()
+            .map_err(|err| format!("failed to parse held_item: {}", err))?;
+        let health = Health::try_from(value.get("health").ok_or("health not found")?)
+            .map_err(|err| format!("failed to parse health: {}", err))?;
         
         Ok(Hand {
-            held_item: None,
+            held_item,
             base_mass: base_mass,
+            health,
         })
     }
 }
 
 impl serde::Serialize for Hand {
     fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        let mut state = serializer.serialize_struct("Hand", 1)?;
+        let held_item = self.held_item.as_ref()
+            .map(|item| registry::inventory_item_to_json(item.as_ref()))
+            .transpose()
+            .map_err(serde::ser::Error::custom)?;
+
+        let mut state = serializer.serialize_struct("Hand", 3)?;
         state.serialize_field("base_mass", &self.base_mass)?;
+        state.serialize_field("held_item", &held_item)?;
+        state.serialize_field("health", &self.health)?;
         state.end()
     }
 }
@@ -199,12 +223,19 @@ pub fn hand(base_mass: Quantity<Mass>, held_item: Option<impl InventoryItem + 's
             item
         }),
         base_mass,
+        health: Health::new(HAND_HIT_POINTS),
     }
 }
 
 impl Hand {
-    pub fn wield(&mut self, item: Box<dyn InventoryItem>) {
-        self.held_item = Some(item);
+    // wield puts the item in the hand, returning whatever the hand held before
+    pub fn wield(&mut self, item: Box<dyn InventoryItem>) -> Option<Box<dyn InventoryItem>> {
+        self.held_item.replace(item)
+    }
+
+    // unwield empties the hand, returning whatever it held
+    pub fn unwield(&mut self) -> Option<Box<dyn InventoryItem>> {
+        self.held_item.take()
     }
 
     pub fn wielded_item(&self) -> Option<&dyn InventoryItem> {
@@ -216,4 +247,36 @@ impl Hand {
             item.as_mut()
         })
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use crate::{
+        quantities::mass::grams,
+        tests::fixtures::sword,
+        worldobject::components::inventory::item::none::NoInventoryItem
+    };
+
+    use super::{Hand, hand};
+
+    #[test]
+    fn a_held_item_survives_a_round_trip() {
+        let hand = hand(grams(500.0), Some(sword("steel")));
+
+        let value = serde_json::to_value(&hand).unwrap();
+        assert_eq!(value["held_item"]["type"], "sword");
+
+        let loaded = Hand::try_from(&value).unwrap();
+        assert_eq!(serde_json::to_value(&loaded).unwrap(), value);
+    }
+
+    #[test]
+    fn an_empty_hand_survives_a_round_trip() {
+        let hand = hand(grams(500.0), None::<NoInventoryItem>);
+
+        let value = serde_json::to_value(&hand).unwrap();
+        assert!(value["held_item"].is_null());
+
+        let loaded = Hand::try_from(&value).unwrap();
+        assert_eq!(serde_json::to_value(&loaded).unwrap(), value);
+    }
+}
@@ -8,6 +8,7 @@ use serde::{
 use async_trait::async_trait;
 
 use crate::{
+    combat::BlowOutcome,
     quantities::{
         self, Quantity, distance::{
             Distance,
@@ -18,11 +19,13 @@ use crate::{
         }, mass::{
             Mass,
             grams
-        }
+        }, speed::Speed
     },
     world::{
-        World,
         handle::WorldObjectHandle,
+        rng::WorldRng,
+        view::WorldView,
+        perception::Perception,
     },
     worldobject::{
         Error,
@@ -31,6 +34,7 @@ use crate::{
         fns::update::Action,
         components::{
             controllers::Controller,
+            health::Health,
             inventory::{
                 Inventory,
                 item::InventoryItem
@@ -39,6 +43,8 @@ use crate::{
     }
 };
 
+pub const ARM_HIT_POINTS: f64 = 30.0;
+
 #[derive(Serialize)]
 pub struct Arm {
     pub reach: Quantity<Distance>,
@@ -48,6 +54,7 @@ pub struct Arm {
     pub base_mass: Quantity<Mass>,
 
     pub hand: Option<hand::Hand>,
+    pub health: Health,
 }
 
 #[derive(Debug)]
@@ -83,6 +90,7 @@ pub fn arm(
         reach,
         punch_force,
         hand,
+        health: Health::new(ARM_HIT_POINTS),
     }
 }
 
@@ -97,43 +105,35 @@ impl TryFrom<&serde_json::Value> for Arm {
         let punch_force = value.get("punch_force").ok_or(String::from("punch_force not found"))
             .and_then(|value| Quantity::<Force>::try_from(value.clone()).map_err(|err| format!("failed to parse punch_force: {}", err)))?;
 
-        Ok(Arm { base_mass, reach, punch_force, hand })
+        let health = Health::try_from(value.get("health").ok_or("health not found")?).map_err(|err| format!("failed to parse health: {}", err))?;
+
+        Ok(Arm { base_mass, reach, punch_force, hand, health })
     }
 }
 
 #[async_trait]
 impl TypedWorldObject for Arm {
-    type Dummy = Self;
     type CollectInventoryItem = Self;
+    const TYPE_TAG: &'static str = "arm";
 
     fn name(&self) -> String {
         String::from("arm")
     }
 
+    // an arm is only ever seen on its own once it has been severed
     fn definite_description(&self) -> String {
-        String::from("the arm")
+        String::from("the severed arm")
     }
 
     fn indefinite_description(&self) -> String {
-        String::from("an arm")
+        String::from("a severed arm")
     }
 
     fn pronoun(&self) -> String {
         String::from("it")
     }
 
-    fn dummy(&self) -> Self::Dummy {
-        Arm {
-            base_mass: self.base_mass.clone(),
-            reach: self.reach.clone(),
-            punch_force: self.punch_force.clone(),
-            hand: self.hand.as_ref().map(
-                |hand| <hand::Hand as TypedWorldObject>::dummy(&hand)
-            )
-        }
-    }
-
-    async fn update(&mut self, my_handle: WorldObjectHandle, world: &World) -> Result<Action, Error> {
+    async fn update(&mut self, _my_handle: WorldObjectHandle, _world: &WorldView) -> Result<Action, Error> {
         Ok(Action::no_op())
     }
 
@@ -155,12 +155,23 @@ impl TypedWorldObject for Arm {
         ).unwrap_or(self.base_mass.clone())
     }
 
-    async fn apply_force(&mut self, force: &Quantity<Force>) -> Result<String, Error> {
-        Ok(String::from(""))
+    fn perception(&self) -> Perception {
+        Perception::none()
+    }
+
+    fn initiative(&self) -> Option<Quantity<Speed>> {
+        None
+    }
+
+    async fn apply_force(&mut self, _force: &Quantity<Force>, _rng: &mut WorldRng) -> Result<BlowOutcome, Error> {
+        Ok(BlowOutcome::from(String::new()))
     }
     
     fn examine(&self) -> String {
-        String::from("an arm")
+        match self.wielded_item() {
+            Some(item) => format!("a severed arm, its hand still clutching {}", item.indefinite_description()),
+            None => String::from("a severed arm"),
+        }
     }
 
     async fn send_message(&mut self, message: String) -> Result<(), Error> {
@@ -200,11 +211,15 @@ impl std::fmt::Display for ArmUseError {
 }
 
 impl InventoryItem for Arm {
-    fn dummy(&self) -> Box<dyn InventoryItem> {
-        Box::new(<Arm as TypedWorldObject>::dummy(self))
+    fn reach(&self) -> Quantity<Distance> {
+        self.reach
+    }
+
+    fn range(&self) -> Quantity<Distance> {
+        meters(0.0)
     }
 
-    fn use_item(&mut self, _: &World, _: WorldObjectHandle, _: Option<WorldObjectHandle>) -> Result<Action, Box<dyn std::error::Error>> {
+    fn use_item(&mut self, _: &WorldView, _: WorldObjectHandle, _: Option<WorldObjectHandle>) -> Result<Action, Box<dyn std::error::Error>> {
         return Err(Box::new(ArmUseError));
     }
 }
@@ -212,6 +227,8 @@ impl InventoryItem for Arm {
 #[derive(Debug)]
 pub enum ArmWieldError {
     NoHand,
+    Crippled,
+    HandCrippled,
 }
 
 impl std::error::Error for ArmWieldError {}
@@ -220,14 +237,42 @@ impl std::fmt::Display for ArmWieldError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::NoHand => write!(f, "arm has no hand"),
+            Self::Crippled => write!(f, "arm is crippled"),
+            Self::HandCrippled => write!(f, "hand is crippled"),
         }
     }
 }
 
+// whatever a hand held before it was given something else to hold
+type DisplacedItem = Option<Box<dyn InventoryItem>>;
+
 impl Arm {
-    pub fn wield(&mut self, item: Box<dyn InventoryItem>) -> Result<(), ArmWieldError> {
-        self.hand.as_mut().map(|hand| hand.wield(item)).ok_or(ArmWieldError::NoHand)?;
-        Ok(())
+    // wield puts the item in the arm's hand, returning whatever the hand held
+    // before; an arm that can't grip the item gives it back along with the error
+    pub fn wield(&mut self, item: Box<dyn InventoryItem>) -> Result<DisplacedItem, (ArmWieldError, Box<dyn InventoryItem>)> {
+        if let Err(err) = self.check_grip() {
+            return Err((err, item));
+        }
+
+        match self.hand.as_mut() {
+            Some(hand) => Ok(hand.wield(item)),
+            None => Err((ArmWieldError::NoHand, item)),
+        }
+    }
+
+    // check_grip says why the arm can't hold on to anything, if it can't
+    pub fn check_grip(&self) -> Result<(), ArmWieldError> {
+        match &self.hand {
+            _ if self.health.is_disabled() => Err(ArmWieldError::Crippled),
+            None => Err(ArmWieldError::NoHand),
+            Some(hand) if hand.health.is_disabled() => Err(ArmWieldError::HandCrippled),
+            Some(_) => Ok(()),
+        }
+    }
+
+    // unwield empties the arm's hand, returning whatever it held
+    pub fn unwield(&mut self) -> Option<Box<dyn InventoryItem>> {
+        self.hand.as_mut().and_then(|hand| hand.unwield())
     }
 
     pub fn wielded_item(&self) -> Option<&dyn InventoryItem> {
@@ -237,4 +282,9 @@ impl Arm {
     pub fn wielded_item_mut(&mut self) -> Option<&mut dyn InventoryItem> {
         self.hand.as_mut().and_then(|hand| hand.wielded_item_mut())
     }
+
+    // armed_reach is the arm's reach, extended by whatever it is wielding
+    pub fn armed_reach(&self) -> Quantity<Distance> {
+        self.wielded_item().map(|item| self.reach + item.reach()).unwrap_or(self.reach)
+    }
 }
\ No newline at end of file
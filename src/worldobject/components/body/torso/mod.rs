@@ -8,30 +8,29 @@ use crate::{
         mass::Mass,
     },
     worldobject::{
-        TypedWorldObject
+        TypedWorldObject,
+        components::health::Health,
     },
 };
 
 use arm::Arm;
 
+pub const TORSO_HIT_POINTS: f64 = 60.0;
+
+// a torso's arms are None once they have been severed
 #[derive(Serialize)]
 pub struct Torso {
     pub base_mass: Quantity<Mass>,
-    pub left_arm: Arm,
-    pub right_arm: Arm,
+    pub left_arm: Option<Arm>,
+    pub right_arm: Option<Arm>,
+    pub health: Health,
 }
 
 impl Torso {
     pub fn mass(&self) -> Quantity<Mass> {
-        self.base_mass.clone() + self.left_arm.mass() + self.right_arm.mass()
-    }
-
-    pub fn dummy(&self) -> Torso {
-        Torso {
-            base_mass: self.base_mass.clone(),
-            left_arm: <Arm as TypedWorldObject>::dummy(&self.left_arm),
-            right_arm: <Arm as TypedWorldObject>::dummy(&self.right_arm),
-        }
+        [&self.left_arm, &self.right_arm].into_iter()
+            .flatten()
+            .fold(self.base_mass.clone(), |mass, arm| mass + arm.mass())
     }
 }
 
@@ -40,8 +39,11 @@ impl TryFrom<&serde_json::Value> for Torso {
 
     fn try_from(value: &serde_json::Value) -> Result<Self, Self::Error> {
         let base_mass = Quantity::<Mass>::try_from(value.get("base_mass").ok_or("base_mass not found")?.clone()).map_err(|err| format!("failed to parse base_mass: {}", err))?;
-        let left_arm = Arm::try_from(value.get("left_arm").ok_or("left_arm not found")?).map_err(|err| format!("failed to parse left_arm: {}", err))?;
-        let right_arm = Arm::try_from(value.get("right_arm").ok_or("right_arm not found")?).map_err(|err| format!("failed to parse right_arm: {}", err))?;
-        Ok(Torso { base_mass, left_arm, right_arm })
+        let left_arm = value.get("left_arm").ok_or("left_arm not found")?;
+        let left_arm = (!left_arm.is_null()).then(|| Arm::try_from(left_arm)).transpose().map_err(|err| format!("failed to parse left_arm: {}", err))?;
+        let right_arm = value.get("right_arm").ok_or("right_arm not found")?;
+        let right_arm = (!right_arm.is_null()).then(|| Arm::try_from(right_arm)).transpose().map_err(|err| format!("failed to parse right_arm: {}", err))?;
+        let health = Health::try_from(value.get("health").ok_or("health not found")?).map_err(|err| format!("failed to parse health: {}", err))?;
+        Ok(Torso { base_mass, left_arm, right_arm, health })
     }
 }
\ No newline at end of file
@@ -0,0 +1,543 @@
+// This module houses the behaviours the AI is built from.  How pressing
+// each behaviour is, when it applies, is fixed, so that e.g. running
+// away always comes before wandering about:
+//
+//   attack when cornered   0.9
+//   flee                   0.8
+//   retaliate              0.6
+//   hunt                   0.5
+//   loot                   0.4
+//   follow                 0.3
+//   explore                0.2
+//   wander                 0.1
+
+use std::collections::HashSet;
+
+use rand::Rng;
+
+use crate::{
+    quantities::{
+        Quantity,
+        direction::{DirectionHorizontal, DirectionHorizontalOrVertical, DirectionVertical},
+        distance::{Distance, meters}
+    },
+    world::{handle::WorldObjectLabel, rng::WorldRng},
+    worldobject::components::{
+        controllers::{
+            commands::{Command, attack_command::AttackCommand, collect_command::CollectCommand, move_command::MoveCommand},
+            observation::{Observation, Sighting}
+        },
+        health::Vitality
+    }
+};
+
+use super::Behaviour;
+
+const CORNERED_SCORE: f64 = 0.9;
+const FLEE_SCORE: f64 = 0.8;
+const RETALIATE_SCORE: f64 = 0.6;
+const HUNT_SCORE: f64 = 0.5;
+const LOOT_SCORE: f64 = 0.4;
+const FOLLOW_SCORE: f64 = 0.3;
+const EXPLORE_SCORE: f64 = 0.2;
+const WANDER_SCORE: f64 = 0.1;
+
+// how far, in meters, a fleeing character runs each turn
+const FLEE_STEP_METERS: f64 = 2.0;
+
+// moves shorter than this, in meters, aren't worth making
+const MIN_STEP_METERS: f64 = 0.05;
+
+fn in_meters(distance: Quantity<Distance>) -> f64 {
+    (distance / meters(1.0)).cancel().0.0
+}
+
+// step returns the command to move the given number of meters, or as far
+// as the observer can get in a single move, if it's worth moving at all
+fn step(observation: &Observation, direction: DirectionHorizontalOrVertical, distance: f64) -> Option<Command> {
+    let distance = distance.min(in_meters(observation.stride));
+    (distance >= MIN_STEP_METERS).then(|| Command::new(MoveCommand {
+        direction,
+        distance: meters(distance),
+    }))
+}
+
+// approach returns the command to close in on the target, along whichever
+// axis it lies further off on, until it is no further than the given
+// distance off along that axis, or None if it is already that close
+fn approach(observation: &Observation, target: &Sighting, within: Quantity<Distance>) -> Option<Command> {
+    let (direction, offset) = target.position.offset_from(&observation.position)?;
+    step(observation, direction, in_meters(offset) - in_meters(within))
+}
+
+// strike returns the command to attack the target if it is within reach,
+// or else to close in on it until it is
+fn strike(observation: &Observation, target: &Sighting) -> Option<Command> {
+    if observation.within_reach(target) {
+        Some(Command::new(AttackCommand { target: target.label.clone(), aim: None }))
+    } else {
+        // stop well within reach, rather than right at its edge
+        approach(observation, target, meters(in_meters(observation.reach) * 0.5))
+    }
+}
+
+fn random_direction(rng: &mut WorldRng) -> DirectionHorizontal {
+    if rng.random_bool(0.5) { DirectionHorizontal::Left } else { DirectionHorizontal::Right }
+}
+
+// Wander ambles about at random every so often
+pub struct Wander {
+    // the furthest the character wanders in one go
+    stride: Quantity<Distance>,
+    // the chance, between 0 and 1, of wandering on any given turn
+    restlessness: f64,
+}
+
+impl Wander {
+    pub fn new(stride: Quantity<Distance>, restlessness: f64) -> Self {
+        Self { stride, restlessness }
+    }
+}
+
+impl Behaviour for Wander {
+    fn consider(&mut self, observation: &Observation, rng: &mut WorldRng) -> Option<(f64, Command)> {
+        if !rng.random_bool(self.restlessness.clamp(0.0, 1.0)) {
+            return None;
+        }
+
+        let distance = rng.random_range(0.5..=1.0) * in_meters(self.stride);
+        step(observation, DirectionHorizontalOrVertical::Horizontal(random_direction(rng)), distance).map(|command| (WANDER_SCORE, command))
+    }
+}
+
+// Follow keeps close to the nearest living thing of some kind
+pub struct Follow {
+    // the type tag of what to follow, e.g. "rat"
+    kind: &'static str,
+    // how close to keep to it
+    within: Quantity<Distance>,
+}
+
+impl Follow {
+    pub fn new(kind: &'static str, within: Quantity<Distance>) -> Self {
+        Self { kind, within }
+    }
+}
+
+impl Behaviour for Follow {
+    fn consider(&mut self, observation: &Observation, _rng: &mut WorldRng) -> Option<(f64, Command)> {
+        let companions = observation.sightings.iter()
+            .filter(|sighting| sighting.kind == self.kind && sighting.vitality == Vitality::Alive);
+        let nearest = observation.nearest(companions)?;
+        if observation.distance_to(nearest) <= self.within {
+            return None;
+        }
+
+        approach(observation, nearest, self.within).map(|command| (FOLLOW_SCORE, command))
+    }
+}
+
+// Retaliate attacks the nearest threat once the character has been hurt,
+// and keeps on attacking whatever threatens it from then on
+#[derive(Default)]
+pub struct Retaliate {
+    // the character's health as of its previous turn
+    last_health: Option<f64>,
+    provoked: bool,
+}
+
+impl Behaviour for Retaliate {
+    fn consider(&mut self, observation: &Observation, _rng: &mut WorldRng) -> Option<(f64, Command)> {
+        if self.last_health.is_some_and(|last_health| observation.health < last_health) {
+            self.provoked = true;
+        }
+        self.last_health = Some(observation.health);
+
+        if !self.provoked {
+            return None;
+        }
+
+        let target = observation.nearest(observation.threats())?;
+        strike(observation, target).map(|command| (RETALIATE_SCORE, command))
+    }
+}
+
+// Flee runs away from the nearest threat once the character is badly hurt
+pub struct Flee {
+    // the fraction of its health, between 0 and 1, below which the character flees
+    below_health: f64,
+}
+
+impl Flee {
+    pub fn new(below_health: f64) -> Self {
+        Self { below_health }
+    }
+}
+
+impl Behaviour for Flee {
+    fn consider(&mut self, observation: &Observation, rng: &mut WorldRng) -> Option<(f64, Command)> {
+        if observation.health >= self.below_health {
+            return None;
+        }
+
+        let threat = observation.nearest(observation.threats())?;
+        // with the threat right where the character stands, any way leads away from it
+        let direction = observation.position.offset_from(&threat.position)
+            .map_or_else(|| DirectionHorizontalOrVertical::Horizontal(random_direction(rng)), |(direction, _)| direction);
+        step(observation, direction, FLEE_STEP_METERS).map(|command| (FLEE_SCORE, command))
+    }
+}
+
+// AttackWhenCornered fights back when running away won't help: when
+// threats stand on either side, or when the character is too badly hurt
+// to get away from a threat that is already within its reach
+pub struct AttackWhenCornered {
+    // the fraction of its health, between 0 and 1, below which the character can't get away
+    below_health: f64,
+}
+
+impl AttackWhenCornered {
+    pub fn new(below_health: f64) -> Self {
+        Self { below_health }
+    }
+}
+
+// is_surrounded returns whether there are threats on either side
+// of the observer, or both above and below it
+fn is_surrounded(observation: &Observation) -> bool {
+    let sides = observation.threats()
+        .filter_map(|threat| threat.position.offset_from(&observation.position))
+        .map(|(direction, _)| direction)
+        .collect::<Vec<_>>();
+    let on_both = |one, other| sides.contains(&one) && sides.contains(&other);
+
+    on_both(DirectionHorizontalOrVertical::Horizontal(DirectionHorizontal::Left), DirectionHorizontalOrVertical::Horizontal(DirectionHorizontal::Right))
+        || on_both(DirectionHorizontalOrVertical::Vertical(DirectionVertical::Up), DirectionHorizontalOrVertical::Vertical(DirectionVertical::Down))
+}
+
+impl Behaviour for AttackWhenCornered {
+    fn consider(&mut self, observation: &Observation, _rng: &mut WorldRng) -> Option<(f64, Command)> {
+        let target = observation.nearest(observation.threats())?;
+        if !observation.within_reach(target) {
+            return None;
+        }
+
+        if observation.health >= self.below_health && !is_surrounded(observation) {
+            return None;
+        }
+
+        let command = Command::new(AttackCommand { target: target.label.clone(), aim: None });
+        Some((CORNERED_SCORE, command))
+    }
+}
+
+// Hunt attacks the nearest living thing in sight, whatever its kind
+pub struct Hunt;
+
+impl Behaviour for Hunt {
+    fn consider(&mut self, observation: &Observation, _rng: &mut WorldRng) -> Option<(f64, Command)> {
+        let prey = observation.sightings.iter()
+            .filter(|sighting| sighting.vitality == Vitality::Alive);
+        let target = observation.nearest(prey)?;
+        strike(observation, target).map(|command| (HUNT_SCORE, command))
+    }
+}
+
+// Loot picks up whatever lies about that isn't alive, nearest first
+#[derive(Default)]
+pub struct Loot {
+    // what the character would pick up this turn, were Loot acted on
+    proposed: Option<WorldObjectLabel>,
+    // what the character tried to pick up on its previous turn
+    reaching_for: Option<WorldObjectLabel>,
+    // what the character has given up on picking up; if something it
+    // reached for is still lying there a turn later, it can't be picked up
+    unobtainable: HashSet<WorldObjectLabel>,
+}
+
+impl Behaviour for Loot {
+    fn consider(&mut self, observation: &Observation, _rng: &mut WorldRng) -> Option<(f64, Command)> {
+        if let Some(label) = self.reaching_for.take() {
+            if observation.sightings.iter().any(|sighting| sighting.label == label) {
+                self.unobtainable.insert(label);
+            }
+        }
+        self.proposed = None;
+
+        let loot = observation.sightings.iter()
+            .filter(|sighting| sighting.vitality != Vitality::Alive && !self.unobtainable.contains(&sighting.label));
+        let item = observation.nearest(loot)?;
+
+        if observation.distance_to(item) <= observation.grasp {
+            self.proposed = Some(item.label.clone());
+            Some((LOOT_SCORE, Command::new(CollectCommand { target: item.label.clone() })))
+        } else {
+            approach(observation, item, meters(in_meters(observation.grasp) * 0.5))
+                .map(|command| (LOOT_SCORE, command))
+        }
+    }
+
+    fn acted_on(&mut self) {
+        self.reaching_for = self.proposed.take();
+    }
+}
+
+// Explore strides off in one direction, turning back once it has gone far enough
+pub struct Explore {
+    stride: Quantity<Distance>,
+    // how far to go before turning back
+    range: Quantity<Distance>,
+    // which way it is headed, picked at random the first time it is considered
+    heading: Option<DirectionHorizontal>,
+    // how far, in meters, it has headed this way since it last turned
+    travelled: f64,
+}
+
+impl Explore {
+    // whether it has gone far enough to turn back
+    fn turning(&self) -> bool {
+        self.travelled >= in_meters(self.range)
+    }
+}
+
+impl Explore {
+    pub fn new(stride: Quantity<Distance>, range: Quantity<Distance>) -> Self {
+        Self { stride, range, heading: None, travelled: 0.0 }
+    }
+}
+
+impl Behaviour for Explore {
+    fn consider(&mut self, observation: &Observation, rng: &mut WorldRng) -> Option<(f64, Command)> {
+        let mut heading = *self.heading.get_or_insert_with(|| random_direction(rng));
+        if self.turning() {
+            heading = heading.opposite();
+        }
+
+        step(observation, DirectionHorizontalOrVertical::Horizontal(heading), in_meters(self.stride))
+            .map(|command| (EXPLORE_SCORE, command))
+    }
+
+    // it has only gone anywhere on turns the character set off exploring
+    fn acted_on(&mut self) {
+        if self.turning() {
+            self.heading = self.heading.map(DirectionHorizontal::opposite);
+            self.travelled = 0.0;
+        }
+        self.travelled += in_meters(self.stride);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+
+    use crate::{
+        quantities::{direction::{DirectionHorizontal, DirectionHorizontalOrVertical, DirectionVertical}, distance::meters},
+        world::{coord::WorldCoord, rng::WorldRng},
+        worldobject::components::{
+            controllers::{
+                commands::{Command, attack_command::AttackCommand, collect_command::CollectCommand, move_command::MoveCommand},
+                observation::{Observation, Sighting}
+            },
+            health::Vitality
+        }
+    };
+
+    use super::{
+        super::Behaviour,
+        AttackWhenCornered, Explore, Flee, Follow, Hunt, Loot, Retaliate, Wander, in_meters
+    };
+
+    const LEFT: DirectionHorizontalOrVertical = DirectionHorizontalOrVertical::Horizontal(DirectionHorizontal::Left);
+    const RIGHT: DirectionHorizontalOrVertical = DirectionHorizontalOrVertical::Horizontal(DirectionHorizontal::Right);
+    const UP: DirectionHorizontalOrVertical = DirectionHorizontalOrVertical::Vertical(DirectionVertical::Up);
+    const DOWN: DirectionHorizontalOrVertical = DirectionHorizontalOrVertical::Vertical(DirectionVertical::Down);
+
+    fn sighting_at(label: &str, kind: &'static str, vitality: Vitality, x: f64, y: f64) -> Sighting {
+        Sighting { label: label.to_string(), kind, vitality, position: WorldCoord::new(meters(x), meters(y)) }
+    }
+
+    fn sighting(label: &str, kind: &'static str, vitality: Vitality, x: f64) -> Sighting {
+        sighting_at(label, kind, vitality, x, 0.0)
+    }
+
+    // what a rat standing at the origin, with a meter's reach and
+    // half a meter's grasp, sees of the given sightings
+    fn observation(health: f64, sightings: Vec<Sighting>) -> Observation {
+        Observation {
+            kind: "rat",
+            position: WorldCoord::new(meters(0.0), meters(0.0)),
+            health,
+            reach: meters(1.0),
+            grasp: meters(0.5),
+            stride: meters(5.0),
+            sightings,
+            seed: 0,
+        }
+    }
+
+    fn consider(behaviour: &mut impl Behaviour, observation: &Observation) -> Option<(f64, Command)> {
+        behaviour.consider(observation, &mut WorldRng::seed_from_u64(0))
+    }
+
+    // the way and the distance, in meters, the command moves, if it is a move
+    fn movement(command: Command) -> Option<(DirectionHorizontalOrVertical, f64)> {
+        command.downcast::<MoveCommand>().ok()
+            .map(|command| (command.direction, in_meters(command.distance)))
+    }
+
+    fn attack_target(command: Command) -> Option<String> {
+        command.downcast::<AttackCommand>().ok().map(|command| command.target)
+    }
+
+    fn collect_target(command: Command) -> Option<String> {
+        command.downcast::<CollectCommand>().ok().map(|command| command.target)
+    }
+
+    #[test]
+    fn wander_ambles_up_to_its_stride_only_when_restless() {
+        let observation = observation(1.0, vec![]);
+        assert!(consider(&mut Wander::new(meters(2.0), 0.0), &observation).is_none());
+
+        let (score, command) = consider(&mut Wander::new(meters(2.0), 1.0), &observation).unwrap();
+        let (_, distance) = movement(command).unwrap();
+        assert_eq!(score, 0.1);
+        assert!((1.0..=2.0).contains(&distance));
+    }
+
+    #[test]
+    fn follow_closes_in_on_the_nearest_living_companion() {
+        let mut follow = Follow::new("rat", meters(1.0));
+
+        let far = observation(1.0, vec![sighting("rat#2", "rat", Vitality::Alive, -4.0), sighting("rat#3", "rat", Vitality::Dead, 2.0)]);
+        let (score, command) = consider(&mut follow, &far).unwrap();
+        assert_eq!(score, 0.3);
+        assert_eq!(movement(command), Some((LEFT, 3.0)));
+
+        let near = observation(1.0, vec![sighting("rat#2", "rat", Vitality::Alive, -0.5)]);
+        assert!(consider(&mut follow, &near).is_none());
+    }
+
+    // a target off to one side and above is closed in on along whichever
+    // axis it lies further off on, by that axis's offset alone
+    #[test]
+    fn follow_closes_in_along_the_axis_the_companion_is_furthest_off_on() {
+        let mut follow = Follow::new("rat", meters(1.0));
+
+        let diagonal = observation(1.0, vec![sighting_at("rat#2", "rat", Vitality::Alive, 3.0, 2.5)]);
+        assert_eq!(movement(consider(&mut follow, &diagonal).unwrap().1), Some((RIGHT, 2.0)));
+
+        let overhead = observation(1.0, vec![sighting_at("rat#2", "rat", Vitality::Alive, 0.0, 4.0)]);
+        assert_eq!(movement(consider(&mut follow, &overhead).unwrap().1), Some((UP, 3.0)));
+    }
+
+    #[test]
+    fn retaliate_strikes_back_once_hurt_and_keeps_at_it() {
+        let mut retaliate = Retaliate::default();
+        let threatened = |health| observation(health, vec![sighting("alice", "human", Vitality::Alive, 0.5)]);
+
+        assert!(consider(&mut retaliate, &threatened(1.0)).is_none());
+        assert!(consider(&mut retaliate, &threatened(1.0)).is_none());
+
+        let (score, command) = consider(&mut retaliate, &threatened(0.9)).unwrap();
+        assert_eq!(score, 0.6);
+        assert_eq!(attack_target(command).as_deref(), Some("alice"));
+
+        assert!(consider(&mut retaliate, &threatened(0.9)).is_some());
+    }
+
+    #[test]
+    fn flee_runs_from_the_nearest_threat_when_badly_hurt() {
+        let mut flee = Flee::new(0.3);
+        let threatened = |health| observation(health, vec![sighting("alice", "human", Vitality::Alive, 0.5), sighting("rat#2", "rat", Vitality::Alive, -0.5)]);
+
+        assert!(consider(&mut flee, &threatened(0.5)).is_none());
+
+        let (score, command) = consider(&mut flee, &threatened(0.2)).unwrap();
+        assert_eq!(score, 0.8);
+        assert_eq!(movement(command), Some((LEFT, 2.0)));
+
+        let from_below = observation(0.2, vec![sighting_at("alice", "human", Vitality::Alive, 0.1, -0.5)]);
+        assert_eq!(movement(consider(&mut flee, &from_below).unwrap().1), Some((UP, 2.0)));
+    }
+
+    #[test]
+    fn attack_when_cornered_fights_when_surrounded_or_too_hurt_to_run() {
+        let mut cornered = AttackWhenCornered::new(0.3);
+
+        let surrounded = observation(1.0, vec![sighting("alice", "human", Vitality::Alive, 0.5), sighting("bob", "human", Vitality::Alive, -2.0)]);
+        let (score, command) = consider(&mut cornered, &surrounded).unwrap();
+        assert_eq!(score, 0.9);
+        assert_eq!(attack_target(command).as_deref(), Some("alice"));
+
+        let above_and_below = observation(1.0, vec![sighting_at("alice", "human", Vitality::Alive, 0.0, 0.5), sighting_at("bob", "human", Vitality::Alive, 0.0, -2.0)]);
+        assert!(consider(&mut cornered, &above_and_below).is_some());
+
+        let threatened = |health, x| observation(health, vec![sighting("alice", "human", Vitality::Alive, x)]);
+        assert!(consider(&mut cornered, &threatened(1.0, 0.5)).is_none());
+        assert!(consider(&mut cornered, &threatened(0.2, 0.5)).is_some());
+        assert!(consider(&mut cornered, &threatened(0.2, 3.0)).is_none());
+    }
+
+    #[test]
+    fn hunt_strikes_the_nearest_living_thing_or_closes_in_on_it() {
+        let near = observation(1.0, vec![sighting("alice", "human", Vitality::Alive, 0.8), sighting("bob", "human", Vitality::Dead, 0.2)]);
+        let (score, command) = consider(&mut Hunt, &near).unwrap();
+        assert_eq!(score, 0.5);
+        assert_eq!(attack_target(command).as_deref(), Some("alice"));
+
+        // closing in stops half its reach away
+        let far = observation(1.0, vec![sighting("alice", "human", Vitality::Alive, 3.0)]);
+        let (_, command) = consider(&mut Hunt, &far).unwrap();
+        assert_eq!(movement(command), Some((RIGHT, 2.5)));
+
+        let below = observation(1.0, vec![sighting_at("alice", "human", Vitality::Alive, 0.0, -3.0)]);
+        assert_eq!(movement(consider(&mut Hunt, &below).unwrap().1), Some((DOWN, 2.5)));
+
+        assert!(consider(&mut Hunt, &observation(1.0, vec![])).is_none());
+    }
+
+    #[test]
+    fn loot_picks_up_what_is_within_grasp_and_gives_up_on_what_it_cannot() {
+        let mut loot = Loot::default();
+        let lying_about = observation(1.0, vec![sighting("sword", "sword", Vitality::Inanimate, 0.3), sighting("alice", "human", Vitality::Alive, 0.1)]);
+
+        let (score, command) = consider(&mut loot, &lying_about).unwrap();
+        assert_eq!(score, 0.4);
+        assert_eq!(collect_target(command).as_deref(), Some("sword"));
+
+        // a pick-up the character didn't set about says nothing of the sword
+        assert!(consider(&mut loot, &lying_about).is_some());
+        loot.acted_on();
+
+        // the sword is still there a turn later, so it can't be picked up
+        assert!(consider(&mut loot, &lying_about).is_none());
+
+        let far = observation(1.0, vec![sighting("sword", "sword", Vitality::Inanimate, -2.0)]);
+        let (_, command) = consider(&mut Loot::default(), &far).unwrap();
+        assert_eq!(movement(command), Some((LEFT, 1.75)));
+    }
+
+    #[test]
+    fn explore_keeps_its_heading_until_it_has_gone_far_enough() {
+        let mut explore = Explore::new(meters(1.0), meters(2.0));
+        let observation = observation(1.0, vec![]);
+
+        let mut heading = |acted_on: bool| {
+            let (score, command) = consider(&mut explore, &observation).unwrap();
+            assert_eq!(score, 0.2);
+            if acted_on {
+                explore.acted_on();
+            }
+            movement(command).unwrap().0
+        };
+
+        // turns the character spends doing something else take it nowhere
+        let first = heading(false);
+        assert_eq!([heading(false), heading(false), heading(false)], [first; 3]);
+
+        let back = if first == LEFT { RIGHT } else { LEFT };
+        let headings = [heading(true), heading(true), heading(true), heading(true), heading(true)];
+        assert_eq!(headings, [first, first, back, back, first]);
+    }
+}
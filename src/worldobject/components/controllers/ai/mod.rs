@@ -0,0 +1,132 @@
+// This module houses the AI: a controller that plays a character by itself
+// rather than asking a player.  It is built from behaviours, e.g. wandering
+// about or fleeing when hurt.  Each turn every behaviour weighs what the
+// character observes and may propose a command, scored by how pressing it
+// is; the AI gives whichever command scored highest (utility scoring).
+
+pub mod behaviours;
+pub mod personality;
+
+use async_trait::async_trait;
+use rand::SeedableRng;
+
+use crate::{quantities::distance::meters, world::rng::WorldRng};
+
+use super::{
+    Controller, DECLINE,
+    commands::{Command, circumspect_command::CircumspectCommand},
+    observation::Observation
+};
+
+use behaviours::{AttackWhenCornered, Explore, Flee, Follow, Hunt, Loot, Retaliate, Wander};
+use personality::Personality;
+
+// Behaviour is something an AI-controlled character might do.  Behaviours
+// are consulted every turn, whether or not they were acted on last time,
+// so they can keep track of what happened on earlier turns.
+pub trait Behaviour: Send + Sync {
+    // consider proposes a command, along with how pressing it is between
+    // 0 and 1, or None if the behaviour doesn't apply to the situation;
+    // anything it leaves to chance is drawn from the given generator
+    fn consider(&mut self, observation: &Observation, rng: &mut WorldRng) -> Option<(f64, Command)>;
+    // acted_on is called when the command the behaviour
+    // proposed this turn is the one the AI gives
+    fn acted_on(&mut self) {}
+}
+
+pub struct AiController {
+    behaviours: Vec<Box<dyn Behaviour>>,
+    // what the character perceived at the start of its turn
+    observation: Option<Observation>,
+}
+
+impl AiController {
+    pub fn new(behaviours: Vec<Box<dyn Behaviour>>) -> Self {
+        Self { behaviours, observation: None }
+    }
+
+    // rat returns the AI rats are born with: a rat keeps near other rats and
+    // wanders about, bites back at whatever hurts it, runs away once badly
+    // hurt, and fights for its life when it can't get away
+    pub fn rat() -> Self {
+        Self::new(vec![
+            Box::new(Wander::new(meters(1.0), 0.3)),
+            Box::new(Follow::new("rat", meters(2.0))),
+            Box::new(Retaliate::default()),
+            Box::new(Flee::new(0.5)),
+            Box::new(AttackWhenCornered::new(0.5)),
+        ])
+    }
+
+    // bot returns the AI that plays a human bot with the given personality
+    pub fn bot(personality: Personality) -> Self {
+        let mut behaviours: Vec<Box<dyn Behaviour>> = vec![
+            Box::new(Retaliate::default()),
+            Box::new(Flee::new(0.25)),
+            Box::new(AttackWhenCornered::new(0.25)),
+        ];
+
+        match personality {
+            Personality::Aggressive => {
+                behaviours.push(Box::new(Hunt));
+                behaviours.push(Box::new(Wander::new(meters(2.0), 0.5)));
+            },
+            Personality::Looter => {
+                behaviours.push(Box::new(Loot::default()));
+                behaviours.push(Box::new(Wander::new(meters(2.0), 0.5)));
+            },
+            Personality::Explorer => behaviours.push(Box::new(Explore::new(meters(3.0), meters(20.0)))),
+        }
+
+        Self::new(behaviours)
+    }
+}
+
+#[async_trait]
+impl Controller for AiController {
+    // with no behaviour to act on, or nothing observed yet, the character looks around
+    async fn prompt_turn(&mut self) -> Result<Command, Box<dyn std::error::Error>> {
+        let Some(observation) = &self.observation else {
+            return Ok(Command::new(CircumspectCommand));
+        };
+
+        // chance is settled by the seed the world handed out for this turn
+        let mut rng = WorldRng::seed_from_u64(observation.seed);
+
+        let mut chosen: Option<(usize, f64, Command)> = None;
+        for (index, behaviour) in self.behaviours.iter_mut().enumerate() {
+            let Some((score, command)) = behaviour.consider(observation, &mut rng) else {
+                continue;
+            };
+
+            // earlier behaviours win ties
+            if chosen.as_ref().is_none_or(|(_, best, _)| score > *best) {
+                chosen = Some((index, score, command));
+            }
+        }
+
+        let Some((index, _, command)) = chosen else {
+            return Ok(Command::new(CircumspectCommand));
+        };
+        self.behaviours[index].acted_on();
+        Ok(command)
+    }
+
+    // nobody reads the messages sent to the AI; it
+    // learns what it needs from its observations
+    async fn display_message(&mut self, _message: String) -> Result<(), Box<dyn std::error::Error>> {
+        Ok(())
+    }
+
+    // the AI has no sense of what anything is worth, so it turns down
+    // whatever it is offered; behaviours refer to objects by their labels,
+    // which are never ambiguous, so any other choice it is asked to make
+    // is arbitrary
+    async fn choose(&mut self, _prompt: String, options: Vec<String>) -> Result<usize, Box<dyn std::error::Error>> {
+        Ok(options.iter().position(|option| option == DECLINE).unwrap_or(0))
+    }
+
+    async fn observe(&mut self, observation: &Observation) {
+        self.observation = Some(observation.clone());
+    }
+}
@@ -0,0 +1,41 @@
+// This module houses the personalities of bots: computer-controlled humans
+// that fill out a lobby, or play unattended to test the game.  Whatever
+// their personality, bots look after themselves: they strike back when
+// hurt, run away when badly hurt, and fight when they can't get away.
+
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Personality {
+    // attacks anything that lives
+    Aggressive,
+    // picks up everything lying about
+    Looter,
+    // roams far and wide
+    Explorer,
+}
+
+impl Personality {
+    pub const ALL: [Personality; 3] = [Self::Aggressive, Self::Looter, Self::Explorer];
+
+    // bot_name names the bot with the given number after its personality, e.g. "Looter2"
+    pub fn bot_name(&self, number: usize) -> String {
+        let personality = self.to_string();
+        let mut letters = personality.chars();
+        let capitalized = letters.next()
+            .map(|first| first.to_uppercase().chain(letters).collect::<String>())
+            .unwrap_or_default();
+
+        format!("{}{}", capitalized, number)
+    }
+}
+
+impl fmt::Display for Personality {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Aggressive => write!(f, "aggressive"),
+            Self::Looter => write!(f, "looter"),
+            Self::Explorer => write!(f, "explorer"),
+        }
+    }
+}
@@ -2,12 +2,26 @@ use std::fmt;
 
 use serde::Serialize;
 use serde::Deserialize;
+use async_trait::async_trait;
 
-use crate::world::handle::WorldObjectHandle;
+use crate::{
+    world::{handle::{WorldObjectHandle, WorldObjectLabel}, view::WorldView},
+    worldobject::{
+        fns::update::Action,
+        components::body::{BodyPart, InvalidBodyPartError},
+        human::{Human, actions::attack_action::{self, AttackCommandToActionError}}
+    }
+};
+
+use super::{TypedCommand, grammar, super::Controller, resolve::{Referents, ResolveError, resolve}};
+
+// the word that introduces the body part a blow is aimed at, as in "hit bob in head"
+const AIM_PREPOSITION: &str = "in";
 
 #[derive(Serialize, Deserialize)]
 pub struct AttackCommand {
-    pub target_handle: WorldObjectHandle,
+    pub target: WorldObjectLabel,
+    pub aim: Option<BodyPart>,
 }
 
 #[derive(Debug)]
@@ -27,26 +41,65 @@ impl std::error::Error for AttackError {}
 
 #[derive(Debug)]
 pub enum AttackActionParseError {
-    NoObjectHandleProvided,
-    InvalidObjectHandle(String)
+    NoTargetProvided,
+    InvalidAim(InvalidBodyPartError),
 }
 
 impl fmt::Display for AttackActionParseError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Self::NoObjectHandleProvided => write!(f, "no object handle provided"),
-            Self::InvalidObjectHandle(handle_str) => write!(f, "invalid object handle \"{}\"", handle_str)
+            Self::NoTargetProvided => write!(f, "no target provided"),
+            Self::InvalidAim(err) => write!(f, "{}", err),
         }
     }
 }
 
-impl AttackCommand {
-    pub fn parse<'a, I: Iterator<Item = &'a str>>(words: &mut std::iter::Peekable<I>) -> Result<Self, AttackActionParseError> {
-        let target_handle = words.next().ok_or(AttackActionParseError::NoObjectHandleProvided)?;
-        let target_handle = WorldObjectHandle::try_from(target_handle)
-            .map_err(|_| AttackActionParseError::InvalidObjectHandle(target_handle.to_string()))?;
+impl std::error::Error for AttackActionParseError {}
 
-        Ok(AttackCommand { target_handle })
+// parse_target_and_aim reads the target of a blow, followed by an optional
+// body part introduced by a preposition, e.g. "hit bob in left arm"
+pub fn parse_target_and_aim<'a, I: Iterator<Item = &'a str>>(words: &mut std::iter::Peekable<I>) -> Result<(WorldObjectLabel, Option<BodyPart>), AttackActionParseError> {
+    words.next_if(|word| grammar::PREPOSITIONS.contains(word));
+    let target_words = words.by_ref()
+        .take_while(|word| *word != AIM_PREPOSITION)
+        .collect::<Vec<_>>();
+    if target_words.is_empty() {
+        return Err(AttackActionParseError::NoTargetProvided);
     }
+
+    let aim_words = words.collect::<Vec<_>>();
+    let aim = (!aim_words.is_empty())
+        .then(|| BodyPart::try_from(aim_words.as_slice()))
+        .transpose()
+        .map_err(AttackActionParseError::InvalidAim)?;
+
+    Ok((target_words.join(" "), aim))
 }
 
+#[async_trait]
+impl TypedCommand for AttackCommand {
+    type ParseError = AttackActionParseError;
+    type ActionError = AttackCommandToActionError;
+    type Actor = Human;
+
+    const KEYWORD: &'static str = "attack";
+    const SYNONYMS: &'static [&'static str] = &["hit", "punch", "strike"];
+    const USAGE: &'static str = "attack <target> [in <body part>]";
+    const DESCRIPTION: &'static str = "strike something within reach the usual way for whatever you are wielding, or with your fist if you are wielding nothing; the blow lands on the body part you name, or wherever it happens to";
+    const EXAMPLES: &'static [&'static str] = &["attack rat", "hit the dead rat", "punch bob in his left arm"];
+
+    fn parse<'a, I: Iterator<Item = &'a str>>(words: &mut std::iter::Peekable<I>) -> Result<Self, AttackActionParseError> {
+        let (target, aim) = parse_target_and_aim(words)?;
+
+        Ok(AttackCommand { target, aim })
+    }
+
+    async fn resolve(self, referents: &Referents, controller: &mut dyn Controller) -> Result<Self, ResolveError> {
+        let target = resolve(&self.target, &referents.objects, controller).await?;
+        Ok(AttackCommand { target, aim: self.aim })
+    }
+
+    fn into_action(self, me: &mut Human, world: &WorldView, my_handle: WorldObjectHandle) -> Result<Action, Self::ActionError> {
+        attack_action::from_command(self.target, self.aim, None, world, my_handle, me)
+    }
+}
@@ -0,0 +1,49 @@
+use serde::Serialize;
+use serde::Deserialize;
+use async_trait::async_trait;
+
+use crate::{
+    combat::DamageType,
+    world::{handle::{WorldObjectHandle, WorldObjectLabel}, view::WorldView},
+    worldobject::{
+        fns::update::Action,
+        components::body::BodyPart,
+        human::{Human, actions::attack_action::{self, AttackCommandToActionError}}
+    }
+};
+
+use super::{TypedCommand, super::Controller, attack_command::{AttackActionParseError, parse_target_and_aim}, resolve::{Referents, ResolveError, resolve}};
+
+#[derive(Serialize, Deserialize)]
+pub struct BashCommand {
+    pub target: WorldObjectLabel,
+    pub aim: Option<BodyPart>,
+}
+
+#[async_trait]
+impl TypedCommand for BashCommand {
+    type ParseError = AttackActionParseError;
+    type ActionError = AttackCommandToActionError;
+    type Actor = Human;
+
+    const KEYWORD: &'static str = "bash";
+    const SYNONYMS: &'static [&'static str] = &["smash", "club", "bludgeon"];
+    const USAGE: &'static str = "bash <target> [in <body part>]";
+    const DESCRIPTION: &'static str = "strike something within reach with the blunt of whatever you are wielding, or with your fist if you are wielding nothing";
+    const EXAMPLES: &'static [&'static str] = &["bash rat", "smash the rat", "bash bob in head"];
+
+    fn parse<'a, I: Iterator<Item = &'a str>>(words: &mut std::iter::Peekable<I>) -> Result<Self, AttackActionParseError> {
+        let (target, aim) = parse_target_and_aim(words)?;
+
+        Ok(BashCommand { target, aim })
+    }
+
+    async fn resolve(self, referents: &Referents, controller: &mut dyn Controller) -> Result<Self, ResolveError> {
+        let target = resolve(&self.target, &referents.objects, controller).await?;
+        Ok(BashCommand { target, aim: self.aim })
+    }
+
+    fn into_action(self, me: &mut Human, world: &WorldView, my_handle: WorldObjectHandle) -> Result<Action, Self::ActionError> {
+        attack_action::from_command(self.target, self.aim, Some(DamageType::Bash), world, my_handle, me)
+    }
+}
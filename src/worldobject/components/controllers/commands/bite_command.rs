@@ -0,0 +1,48 @@
+use serde::Serialize;
+use serde::Deserialize;
+use async_trait::async_trait;
+
+use crate::{
+    world::{handle::{WorldObjectHandle, WorldObjectLabel}, view::WorldView},
+    worldobject::{
+        fns::update::Action,
+        components::body::BodyPart,
+        human::{Human, actions::bite_action::{self, BiteCommandToActionError}}
+    }
+};
+
+use super::{TypedCommand, super::Controller, attack_command::{AttackActionParseError, parse_target_and_aim}, resolve::{Referents, ResolveError, resolve}};
+
+#[derive(Serialize, Deserialize)]
+pub struct BiteCommand {
+    pub target: WorldObjectLabel,
+    pub aim: Option<BodyPart>,
+}
+
+#[async_trait]
+impl TypedCommand for BiteCommand {
+    type ParseError = AttackActionParseError;
+    type ActionError = BiteCommandToActionError;
+    type Actor = Human;
+
+    const KEYWORD: &'static str = "bite";
+    const SYNONYMS: &'static [&'static str] = &["chomp", "nip"];
+    const USAGE: &'static str = "bite <target> [in <body part>]";
+    const DESCRIPTION: &'static str = "bite something right next to you; the more, longer and sharper your teeth, the harder you bite";
+    const EXAMPLES: &'static [&'static str] = &["bite rat", "chomp the rat", "bite bob in left hand"];
+
+    fn parse<'a, I: Iterator<Item = &'a str>>(words: &mut std::iter::Peekable<I>) -> Result<Self, AttackActionParseError> {
+        let (target, aim) = parse_target_and_aim(words)?;
+
+        Ok(BiteCommand { target, aim })
+    }
+
+    async fn resolve(self, referents: &Referents, controller: &mut dyn Controller) -> Result<Self, ResolveError> {
+        let target = resolve(&self.target, &referents.objects, controller).await?;
+        Ok(BiteCommand { target, aim: self.aim })
+    }
+
+    fn into_action(self, me: &mut Human, world: &WorldView, my_handle: WorldObjectHandle) -> Result<Action, Self::ActionError> {
+        bite_action::from_command(self.target, self.aim, world, my_handle, me)
+    }
+}
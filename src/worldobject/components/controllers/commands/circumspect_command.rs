@@ -0,0 +1,35 @@
+use serde::Serialize;
+use serde::Deserialize;
+
+use crate::{
+    world::{handle::WorldObjectHandle, view::WorldView},
+    worldobject::{
+        fns::update::Action,
+        human::{Human, actions::circumspect_action}
+    }
+};
+
+use super::TypedCommand;
+
+#[derive(Serialize, Deserialize)]
+pub struct CircumspectCommand;
+
+impl TypedCommand for CircumspectCommand {
+    type ParseError = std::convert::Infallible;
+    type ActionError = std::convert::Infallible;
+    type Actor = Human;
+
+    const KEYWORD: &'static str = "circumspect";
+    const SYNONYMS: &'static [&'static str] = &["look around", "look", "l"];
+    const USAGE: &'static str = "circumspect";
+    const DESCRIPTION: &'static str = "look around, listing everything you can see and where it is";
+    const EXAMPLES: &'static [&'static str] = &["circumspect", "look", "l"];
+
+    fn parse<'a, I: Iterator<Item = &'a str>>(_: &mut std::iter::Peekable<I>) -> Result<Self, Self::ParseError> {
+        Ok(CircumspectCommand)
+    }
+
+    fn into_action(self, _me: &mut Human, _world: &WorldView, my_handle: WorldObjectHandle) -> Result<Action, Self::ActionError> {
+        Ok(circumspect_action::action(my_handle))
+    }
+}
@@ -2,35 +2,61 @@ use std::fmt;
 
 use serde::Serialize;
 use serde::Deserialize;
+use async_trait::async_trait;
 
-use crate::world::handle::WorldObjectHandle;
+use crate::{
+    world::{handle::{WorldObjectHandle, WorldObjectLabel}, view::WorldView},
+    worldobject::{
+        fns::update::Action,
+        human::{Human, actions::collect_action::{self, CollectCommandToActionError}}
+    }
+};
+
+use super::{TypedCommand, super::Controller, resolve::{Referents, ResolveError, resolve}};
 
 #[derive(Serialize, Deserialize)]
 pub struct CollectCommand {
-    pub target_handle: WorldObjectHandle
+    pub target: WorldObjectLabel
 }
 
 #[derive(Debug)]
 pub enum CollectCommandParseError {
-    NoObjectHandleProvided,
-    InvalidObjectHandle(String),
+    NoTargetProvided,
 }
 
 impl fmt::Display for CollectCommandParseError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Self::NoObjectHandleProvided => write!(f, "no object handle provided"),
-            Self::InvalidObjectHandle(handle_str) => write!(f, "invalid object handle \"{}\"", handle_str)
+            Self::NoTargetProvided => write!(f, "no target provided"),
         }
     }
 }
 
-impl CollectCommand {
-    pub fn parse<'a, I: Iterator<Item = &'a str>>(words: &mut std::iter::Peekable<I>) -> Result<Self, CollectCommandParseError> {
-        let target_handle = words.next().ok_or(CollectCommandParseError::NoObjectHandleProvided)?;
-        let target_handle = WorldObjectHandle::try_from(target_handle)
-            .map_err(|_| CollectCommandParseError::InvalidObjectHandle(target_handle.to_string()))?;
-        Ok(CollectCommand { target_handle })
+impl std::error::Error for CollectCommandParseError {}
+
+#[async_trait]
+impl TypedCommand for CollectCommand {
+    type ParseError = CollectCommandParseError;
+    type ActionError = CollectCommandToActionError;
+    type Actor = Human;
+
+    const KEYWORD: &'static str = "collect";
+    const SYNONYMS: &'static [&'static str] = &["pick up", "take", "get", "grab"];
+    const USAGE: &'static str = "collect <target>";
+    const DESCRIPTION: &'static str = "pick up something within arm's reach and put it in your inventory";
+    const EXAMPLES: &'static [&'static str] = &["pick up the sword", "take wand"];
+
+    fn parse<'a, I: Iterator<Item = &'a str>>(words: &mut std::iter::Peekable<I>) -> Result<Self, CollectCommandParseError> {
+        let target = super::phrase(words).ok_or(CollectCommandParseError::NoTargetProvided)?;
+        Ok(CollectCommand { target })
+    }
+
+    async fn resolve(self, referents: &Referents, controller: &mut dyn Controller) -> Result<Self, ResolveError> {
+        let target = resolve(&self.target, &referents.objects, controller).await?;
+        Ok(CollectCommand { target })
     }
-}
 
+    fn into_action(self, me: &mut Human, world: &WorldView, my_handle: WorldObjectHandle) -> Result<Action, Self::ActionError> {
+        collect_action::from_command(self, world, my_handle, me)
+    }
+}
@@ -0,0 +1,65 @@
+use std::fmt;
+
+use serde::Serialize;
+use serde::Deserialize;
+use async_trait::async_trait;
+
+use crate::{
+    world::{handle::WorldObjectHandle, view::WorldView},
+    worldobject::{
+        fns::update::Action,
+        human::{Human, actions::drop_action::{self, DropCommandToActionError}}
+    }
+};
+
+use super::{TypedCommand, super::Controller, resolve::{Referents, ResolveError, resolve}};
+
+#[derive(Serialize, Deserialize)]
+pub struct DropCommand {
+    // the item to drop, e.g. "sword"; once resolved, this is the handle of an
+    // item in the inventory, or the name of an item being wielded
+    pub item: String
+}
+
+#[derive(Debug)]
+pub enum DropCommandParseError {
+    NoItemProvided,
+}
+
+impl fmt::Display for DropCommandParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DropCommandParseError::NoItemProvided => write!(f, "no item provided")
+        }
+    }
+}
+
+impl std::error::Error for DropCommandParseError {}
+
+#[async_trait]
+impl TypedCommand for DropCommand {
+    type ParseError = DropCommandParseError;
+    type ActionError = DropCommandToActionError;
+    type Actor = Human;
+
+    const KEYWORD: &'static str = "drop";
+    const SYNONYMS: &'static [&'static str] = &["put down", "discard"];
+    const USAGE: &'static str = "drop <item>";
+    const DESCRIPTION: &'static str = "put an item you are carrying or wielding down where you stand";
+    const EXAMPLES: &'static [&'static str] = &["drop sword", "put down the dead rat"];
+
+    fn parse<'a, I: Iterator<Item = &'a str>>(words: &mut std::iter::Peekable<I>) -> Result<Self, DropCommandParseError> {
+        let item = super::phrase(words).ok_or(DropCommandParseError::NoItemProvided)?;
+        Ok(DropCommand { item })
+    }
+
+    async fn resolve(self, referents: &Referents, controller: &mut dyn Controller) -> Result<Self, ResolveError> {
+        let candidates = [referents.inventory.as_slice(), referents.wielded.as_slice()].concat();
+        let item = resolve(&self.item, &candidates, controller).await?;
+        Ok(DropCommand { item })
+    }
+
+    fn into_action(self, me: &mut Human, _world: &WorldView, my_handle: WorldObjectHandle) -> Result<Action, Self::ActionError> {
+        drop_action::from_command(me, my_handle, self)
+    }
+}
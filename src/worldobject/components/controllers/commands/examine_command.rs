@@ -2,34 +2,61 @@ use std::fmt;
 
 use serde::Serialize;
 use serde::Deserialize;
+use async_trait::async_trait;
 
-use crate::world::handle::WorldObjectHandle;
+use crate::{
+    world::{handle::{WorldObjectHandle, WorldObjectLabel}, view::WorldView},
+    worldobject::{
+        fns::update::Action,
+        human::{Human, actions::examine_action::{self, ExamineCommandToActionError}}
+    }
+};
+
+use super::{TypedCommand, super::Controller, resolve::{Referents, ResolveError, resolve}};
 
 #[derive(Serialize, Deserialize)]
 pub struct ExamineCommand {
-    pub target_handle: WorldObjectHandle
+    pub target: WorldObjectLabel
 }
 
 #[derive(Debug)]
 pub enum ExamineCommandParseError {
-    NoObjectHandleProvided,
-    InvalidObjectHandle(String),
+    NoTargetProvided,
 }
 
 impl fmt::Display for ExamineCommandParseError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Self::NoObjectHandleProvided => write!(f, "no object handle provided"),
-            Self::InvalidObjectHandle(handle_str) => write!(f, "invalid object handle \"{}\"", handle_str)
+            Self::NoTargetProvided => write!(f, "no target provided"),
         }
     }
 }
 
-impl ExamineCommand {
-    pub fn parse<'a, I: Iterator<Item = &'a str>>(words: &mut std::iter::Peekable<I>) -> Result<Self, ExamineCommandParseError> {
-        let target_handle = words.next().ok_or(ExamineCommandParseError::NoObjectHandleProvided)?;
-        let target_handle = WorldObjectHandle::try_from(target_handle)
-            .map_err(|_| ExamineCommandParseError::InvalidObjectHandle(target_handle.to_string()))?;
-        Ok(ExamineCommand { target_handle })
+impl std::error::Error for ExamineCommandParseError {}
+
+#[async_trait]
+impl TypedCommand for ExamineCommand {
+    type ParseError = ExamineCommandParseError;
+    type ActionError = ExamineCommandToActionError;
+    type Actor = Human;
+
+    const KEYWORD: &'static str = "examine";
+    const SYNONYMS: &'static [&'static str] = &["look at", "inspect", "x"];
+    const USAGE: &'static str = "examine <target>";
+    const DESCRIPTION: &'static str = "take a closer look at something you can see";
+    const EXAMPLES: &'static [&'static str] = &["examine rat", "look at sword", "x wand"];
+
+    fn parse<'a, I: Iterator<Item = &'a str>>(words: &mut std::iter::Peekable<I>) -> Result<Self, ExamineCommandParseError> {
+        let target = super::phrase(words).ok_or(ExamineCommandParseError::NoTargetProvided)?;
+        Ok(ExamineCommand { target })
     }
-}
\ No newline at end of file
+
+    async fn resolve(self, referents: &Referents, controller: &mut dyn Controller) -> Result<Self, ResolveError> {
+        let target = resolve(&self.target, &referents.objects, controller).await?;
+        Ok(ExamineCommand { target })
+    }
+
+    fn into_action(self, _me: &mut Human, world: &WorldView, _my_handle: WorldObjectHandle) -> Result<Action, Self::ActionError> {
+        examine_action::from_command(self, world)
+    }
+}
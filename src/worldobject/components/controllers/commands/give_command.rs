@@ -0,0 +1,78 @@
+use std::fmt;
+
+use serde::Serialize;
+use serde::Deserialize;
+use async_trait::async_trait;
+
+use crate::{
+    world::{handle::{WorldObjectHandle, WorldObjectLabel}, view::WorldView},
+    worldobject::{
+        fns::update::Action,
+        human::{Human, actions::give_action::{self, GiveCommandToActionError}}
+    }
+};
+
+use super::{TypedCommand, super::Controller, grammar, resolve::{Referents, ResolveError, resolve}};
+
+#[derive(Serialize, Deserialize)]
+pub struct GiveCommand {
+    // the item to give away; once resolved, this is the item's handle
+    pub item: String,
+    pub recipient: WorldObjectLabel,
+}
+
+#[derive(Debug)]
+pub enum GiveCommandParseError {
+    NoItemProvided,
+    NoRecipientProvided,
+}
+
+impl fmt::Display for GiveCommandParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GiveCommandParseError::NoItemProvided => write!(f, "no item provided"),
+            GiveCommandParseError::NoRecipientProvided => write!(f, "no recipient provided"),
+        }
+    }
+}
+
+impl std::error::Error for GiveCommandParseError {}
+
+#[async_trait]
+impl TypedCommand for GiveCommand {
+    type ParseError = GiveCommandParseError;
+    type ActionError = GiveCommandToActionError;
+    type Actor = Human;
+
+    const KEYWORD: &'static str = "give";
+    const SYNONYMS: &'static [&'static str] = &["hand over", "hand", "pass"];
+    const USAGE: &'static str = "give <item> to <character>";
+    const DESCRIPTION: &'static str = "give an item from your inventory to someone within arm's reach";
+    const EXAMPLES: &'static [&'static str] = &["give sword to bob", "hand the wand to alice"];
+
+    // the item comes first, followed by the recipient
+    // introduced by a preposition, e.g. "give sword to bob"
+    fn parse<'a, I: Iterator<Item = &'a str>>(words: &mut std::iter::Peekable<I>) -> Result<Self, GiveCommandParseError> {
+        let item_words = std::iter::from_fn(|| words.next_if(|word| !grammar::PREPOSITIONS.contains(word)))
+            .collect::<Vec<_>>();
+        if item_words.is_empty() {
+            return Err(GiveCommandParseError::NoItemProvided);
+        }
+
+        let recipient = super::phrase(words).ok_or(GiveCommandParseError::NoRecipientProvided)?;
+        Ok(GiveCommand {
+            item: item_words.join(" "),
+            recipient
+        })
+    }
+
+    async fn resolve(self, referents: &Referents, controller: &mut dyn Controller) -> Result<Self, ResolveError> {
+        let item = resolve(&self.item, &referents.inventory, controller).await?;
+        let recipient = resolve(&self.recipient, &referents.objects, controller).await?;
+        Ok(GiveCommand { item, recipient })
+    }
+
+    fn into_action(self, me: &mut Human, world: &WorldView, my_handle: WorldObjectHandle) -> Result<Action, Self::ActionError> {
+        give_action::from_command(me, my_handle, self, world)
+    }
+}
@@ -0,0 +1,98 @@
+// This module houses the grammar commands are typed in.  Input is
+// split into lowercase words, articles are dropped, and whatever verb
+// the player used is replaced with the keyword of the command it stands
+// for, so that "Pick up  the sword" reads as "collect sword".  The verbs
+// come from the command registry.
+
+use super::registry;
+
+// words that never change the meaning of a command
+const ARTICLES: [&str; 3] = ["the", "a", "an"];
+
+// prepositions that may introduce the object of a command, as in "use wand on rat"
+pub const PREPOSITIONS: [&str; 5] = ["on", "at", "with", "to", "against"];
+
+// tokenize splits input into lowercase words, leaving out articles
+pub fn tokenize(input: &str) -> Vec<String> {
+    input.split_whitespace()
+        .map(str::to_lowercase)
+        .filter(|word| !ARTICLES.contains(&word.as_str()))
+        .collect()
+}
+
+// command_name returns the keyword of the command the verb at the start
+// of the words stands for, along with how many words the verb takes up
+fn command_name(words: &[String]) -> Option<(&'static str, usize)> {
+    let first = words.first()?;
+    let commands = registry::commands();
+
+    if let Some(command) = commands.iter().find(|command| command.keyword == first) {
+        return Some((command.keyword, 1));
+    }
+
+    // the longest synonym wins, so that "look at" is preferred over "look"
+    let synonym = commands.iter()
+        .flat_map(|command| command.synonyms.iter().map(|synonym| (command.keyword, synonym.split(' ').collect::<Vec<_>>())))
+        .filter(|(_, verb_words)| words.len() >= verb_words.len() && verb_words.iter().zip(words).all(|(verb_word, word)| verb_word == word))
+        .max_by_key(|(_, verb_words)| verb_words.len());
+
+    if let Some((keyword, verb_words)) = synonym {
+        return Some((keyword, verb_words.len()));
+    }
+
+    // any unambiguous beginning of a keyword is short for it, e.g. "circ"
+    let mut abbreviated = commands.iter().filter(|command| command.keyword.starts_with(first.as_str()));
+    match (abbreviated.next(), abbreviated.next()) {
+        (Some(command), None) => Some((command.keyword, 1)),
+        _ => None,
+    }
+}
+
+// normalize tokenizes input and replaces its verb with the name of its command
+pub fn normalize(input: &str) -> Vec<String> {
+    let mut words = tokenize(input);
+
+    if let Some((name, verb_length)) = command_name(&words) {
+        words.splice(0..verb_length, [String::from(name)]);
+    }
+
+    words
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::{normalize, tokenize};
+
+    #[test]
+    fn tokenize_lowercases_words_and_drops_articles() {
+        assert_eq!(tokenize("  Attack   THE Rat "), ["attack", "rat"]);
+        assert_eq!(tokenize("use a wand on an orc"), ["use", "wand", "on", "orc"]);
+    }
+
+    #[test]
+    fn normalize_replaces_synonyms_with_keywords() {
+        assert_eq!(normalize("Pick up  the sword"), ["collect", "sword"]);
+        assert_eq!(normalize("hit rat"), ["attack", "rat"]);
+        assert_eq!(normalize("x rat"), ["examine", "rat"]);
+    }
+
+    #[test]
+    fn normalize_prefers_the_longest_synonym() {
+        assert_eq!(normalize("look at rat"), ["examine", "rat"]);
+        assert_eq!(normalize("look around"), ["circumspect"]);
+        assert_eq!(normalize("look"), ["circumspect"]);
+    }
+
+    #[test]
+    fn normalize_expands_unambiguous_abbreviations_only() {
+        assert_eq!(normalize("circ"), ["circumspect"]);
+        // "in" could be short for "interact" or "inventory"
+        assert_eq!(normalize("in"), ["in"]);
+    }
+
+    #[test]
+    fn normalize_leaves_unknown_verbs_alone() {
+        assert_eq!(normalize("dance wildly"), ["dance", "wildly"]);
+    }
+}
@@ -0,0 +1,68 @@
+// This module houses the help players get by typing "help", or
+// "help <action>" for a particular action.  Everything it says comes
+// from the commands in the command registry, so that new commands are
+// documented as soon as they are registered.
+
+use super::{
+    grammar,
+    registry::{self, CommandInfo}
+};
+
+// the words players ask for help with
+const HELP_WORDS: [&str; 2] = ["help", "?"];
+
+// help_request returns the help the input asks for, if it asks for help at all
+pub fn help_request(input: &str) -> Option<String> {
+    let words = grammar::tokenize(input);
+    let (first, topic) = words.split_first()?;
+
+    if !HELP_WORDS.contains(&first.as_str()) {
+        return None;
+    }
+
+    Some(if topic.is_empty() {
+        overview()
+    } else {
+        help_on(&topic.join(" "))
+    })
+}
+
+// overview lists every action along with what it does
+fn overview() -> String {
+    let actions = registry::commands().iter()
+        .map(|command| format!(" - {}: {}", command.usage, command.description))
+        .collect::<Vec<_>>();
+
+    format!(
+        "the available actions are:\n{}\ntype \"help <action>\" to learn more about an action",
+        actions.join("\n")
+    )
+}
+
+// help_on describes the action the topic names; any synonym
+// or abbreviation of the action's keyword names it too
+fn help_on(topic: &str) -> String {
+    let keyword = grammar::normalize(topic).into_iter().next();
+
+    registry::commands().iter()
+        .find(|command| Some(command.keyword) == keyword.as_deref())
+        .map(describe)
+        .unwrap_or_else(|| format!("there is no action called \"{}\"; type \"help\" for a list of actions", topic))
+}
+
+fn describe(command: &CommandInfo) -> String {
+    let mut lines = vec![
+        format!("usage: {}", command.usage),
+        String::from(command.description),
+    ];
+
+    if !command.synonyms.is_empty() {
+        lines.push(format!("also: {}", command.synonyms.join(", ")));
+    }
+
+    if !command.examples.is_empty() {
+        lines.push(format!("examples:\n - {}", command.examples.join("\n - ")));
+    }
+
+    lines.join("\n")
+}
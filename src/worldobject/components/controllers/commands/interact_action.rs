@@ -2,36 +2,63 @@ use std::fmt;
 
 use serde::Serialize;
 use serde::Deserialize;
+use async_trait::async_trait;
 
-use crate::world::handle::WorldObjectHandle;
+use crate::{
+    world::{handle::{WorldObjectHandle, WorldObjectLabel}, view::WorldView},
+    worldobject::{
+        fns::update::Action,
+        human::{Human, actions::interact_action::{self, InteractCommandToActionError}}
+    }
+};
+
+use super::{TypedCommand, super::Controller, resolve::{Referents, ResolveError, resolve}};
 
 
 
 #[derive(Serialize, Deserialize)]
 pub struct InteractAction {
-    pub target_handle: WorldObjectHandle
+    pub target: WorldObjectLabel
 }
 
 #[derive(Debug)]
 pub enum InteractActionParseError {
-    NoObjectHandleProvided,
-    InvalidObjectHandle(String),
+    NoTargetProvided,
 }
 
 impl fmt::Display for InteractActionParseError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            InteractActionParseError::InvalidObjectHandle(handle) => write!(f, "invalid object handle: {}", handle),
-            InteractActionParseError::NoObjectHandleProvided => write!(f, "no object handle provided")
+            InteractActionParseError::NoTargetProvided => write!(f, "no target provided")
         }
     }
 }
 
-impl InteractAction {
-    pub fn parse<'a, I: Iterator<Item = &'a str>>(words: &mut std::iter::Peekable<I>) -> Result<Self, InteractActionParseError> {
-        let target_handle = words.next().ok_or(InteractActionParseError::NoObjectHandleProvided)?;
-        let target_handle = WorldObjectHandle::try_from(target_handle)
-            .map_err(|_| InteractActionParseError::InvalidObjectHandle(target_handle.to_string()))?;
-        Ok(InteractAction { target_handle })
+impl std::error::Error for InteractActionParseError {}
+
+#[async_trait]
+impl TypedCommand for InteractAction {
+    type ParseError = InteractActionParseError;
+    type ActionError = InteractCommandToActionError;
+    type Actor = Human;
+
+    const KEYWORD: &'static str = "interact";
+    const SYNONYMS: &'static [&'static str] = &["talk to", "talk", "touch"];
+    const USAGE: &'static str = "interact <target>";
+    const DESCRIPTION: &'static str = "interact with something within arm's reach, in a way that depends on what it is";
+    const EXAMPLES: &'static [&'static str] = &["interact with rat", "talk to Alice"];
+
+    fn parse<'a, I: Iterator<Item = &'a str>>(words: &mut std::iter::Peekable<I>) -> Result<Self, InteractActionParseError> {
+        let target = super::phrase(words).ok_or(InteractActionParseError::NoTargetProvided)?;
+        Ok(InteractAction { target })
     }
-}
\ No newline at end of file
+
+    async fn resolve(self, referents: &Referents, controller: &mut dyn Controller) -> Result<Self, ResolveError> {
+        let target = resolve(&self.target, &referents.objects, controller).await?;
+        Ok(InteractAction { target })
+    }
+
+    fn into_action(self, me: &mut Human, world: &WorldView, my_handle: WorldObjectHandle) -> Result<Action, Self::ActionError> {
+        interact_action::from_command(self, world, my_handle, me)
+    }
+}
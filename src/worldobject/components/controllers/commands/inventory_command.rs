@@ -0,0 +1,35 @@
+use serde::Serialize;
+use serde::Deserialize;
+
+use crate::{
+    world::{handle::WorldObjectHandle, view::WorldView},
+    worldobject::{
+        fns::update::Action,
+        human::{Human, actions::inventory_action}
+    }
+};
+
+use super::TypedCommand;
+
+#[derive(Serialize, Deserialize)]
+pub struct InventoryCommand;
+
+impl TypedCommand for InventoryCommand {
+    type ParseError = std::convert::Infallible;
+    type ActionError = std::convert::Infallible;
+    type Actor = Human;
+
+    const KEYWORD: &'static str = "inventory";
+    const SYNONYMS: &'static [&'static str] = &["inv", "i"];
+    const USAGE: &'static str = "inventory";
+    const DESCRIPTION: &'static str = "list what you are carrying and wielding";
+    const EXAMPLES: &'static [&'static str] = &["inventory", "i"];
+
+    fn parse<'a, I: Iterator<Item = &'a str>>(_: &mut std::iter::Peekable<I>) -> Result<Self, Self::ParseError> {
+        Ok(InventoryCommand)
+    }
+
+    fn into_action(self, me: &mut Human, _world: &WorldView, _my_handle: WorldObjectHandle) -> Result<Action, Self::ActionError> {
+        Ok(inventory_action::action(me))
+    }
+}
@@ -2,50 +2,227 @@ pub mod move_command;
 pub mod interact_action;
 pub mod collect_command;
 pub mod attack_command;
+pub mod slash_command;
+pub mod stab_command;
+pub mod bash_command;
+pub mod bite_command;
+pub mod push_command;
 pub mod examine_command;
 pub mod wield_command;
+pub mod unwield_command;
+pub mod drop_command;
+pub mod give_command;
+pub mod trade_command;
+pub mod circumspect_command;
+pub mod inventory_command;
 pub mod use_command;
+pub mod resolve;
+pub mod grammar;
+pub mod registry;
+pub mod help;
 
-use serde::{Serialize, Deserialize};
-
-#[derive(Serialize, Deserialize)]
-pub enum Command {
-    Move(move_command::MoveCommand),
-    Interact(interact_action::InteractAction),
-    Collect(collect_command::CollectCommand),
-    Attack(attack_command::AttackCommand),
-    Examine(examine_command::ExamineCommand),
-    Wield(wield_command::WieldCommand),
-    Circumspect,
-    Inventory,
-    Use(use_command::UseCommand),
+use async_trait::async_trait;
+use serde::{Serialize, Serializer, Deserialize, Deserializer, de::DeserializeOwned};
+use serde_json::json;
+
+use crate::{
+    world::{handle::WorldObjectHandle, view::WorldView},
+    worldobject::{
+        WorldObject,
+        fns::update::Action
+    }
+};
+
+use super::Controller;
+
+use resolve::{Referents, ResolveError};
+
+// TypedCommand is implemented by every command a player can give.  Each
+// command type is registered with the command registry under its keyword,
+// which is how the registry finds its parser and its action builder.
+#[async_trait]
+pub trait TypedCommand: Serialize + DeserializeOwned + Send + Sync + 'static {
+    type ParseError: std::error::Error + 'static;
+    type ActionError: std::error::Error + 'static;
+    // the kind of object that can carry out the command, e.g. Human
+    type Actor: WorldObject + 'static;
+
+    // the word players type to give this command, e.g. "attack"
+    const KEYWORD: &'static str;
+    // other verbs and abbreviations players may use instead, e.g. "hit";
+    // verbs may be several words long, e.g. "pick up"
+    const SYNONYMS: &'static [&'static str] = &[];
+    // the tag under which the command is sent over the network
+    const TAG: &'static str = Self::KEYWORD;
+
+    // how the command is written, e.g. "attack <target>"
+    const USAGE: &'static str;
+    // what the command does, for the help command
+    const DESCRIPTION: &'static str;
+    // a few ways the command might be given, e.g. "hit the rat"
+    const EXAMPLES: &'static [&'static str] = &[];
+
+    // parse reads the command from the words following its verb
+    fn parse<'a, I: Iterator<Item = &'a str>>(words: &mut std::iter::Peekable<I>) -> Result<Self, Self::ParseError>;
+
+    // resolve replaces every phrase in the command with the key of what it
+    // refers to (see the resolve module); commands without phrases are unchanged
+    async fn resolve(self, _referents: &Referents, _controller: &mut dyn Controller) -> Result<Self, ResolveError> {
+        Ok(self)
+    }
+
+    // into_action builds the action the actor takes when given this command
+    fn into_action(self, me: &mut Self::Actor, world: &WorldView, my_handle: WorldObjectHandle) -> Result<Action, Self::ActionError>;
+}
+
+// CarriesOut is implemented by objects that can carry out a command meant
+// for some other kind of actor, in their own way; rats, for instance, carry
+// out attacks by biting.  Each such pairing is registered with the command
+// registry, which is how a command finds its way to the object.
+pub trait CarriesOut<C: TypedCommand>: WorldObject + 'static {
+    type ActionError: std::error::Error + 'static;
+
+    // carry_out builds the action this object takes when given the command
+    fn carry_out(&mut self, command: C, world: &WorldView, my_handle: WorldObjectHandle) -> Result<Action, Self::ActionError>;
+}
+
+// WrongActorError is given when a command is given to an
+// object that isn't the kind of actor that can carry it out
+#[derive(Debug)]
+pub struct WrongActorError {
+    actor: String,
+    keyword: &'static str,
+}
+
+impl std::fmt::Display for WrongActorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} can't {}", self.actor, self.keyword)
+    }
+}
+
+impl std::error::Error for WrongActorError {}
+
+// AnyCommand is the object-safe counterpart of TypedCommand
+#[async_trait]
+pub trait AnyCommand: Send + Sync {
+    fn keyword(&self) -> &'static str;
+    fn tag(&self) -> &'static str;
+    fn to_json(&self) -> Result<serde_json::Value, serde_json::Error>;
+    fn as_any(&self) -> &dyn std::any::Any;
+    fn into_any(self: Box<Self>) -> Box<dyn std::any::Any>;
+    async fn resolve(self: Box<Self>, referents: &Referents, controller: &mut dyn Controller) -> Result<Command, ResolveError>;
+    fn into_action(self: Box<Self>, me: &mut dyn WorldObject, world: &WorldView, my_handle: WorldObjectHandle) -> Result<Action, Box<dyn std::error::Error>>;
+}
+
+#[async_trait]
+impl<T: TypedCommand> AnyCommand for T {
+    fn keyword(&self) -> &'static str {
+        T::KEYWORD
+    }
+
+    fn tag(&self) -> &'static str {
+        T::TAG
+    }
+
+    fn to_json(&self) -> Result<serde_json::Value, serde_json::Error> {
+        serde_json::to_value(self)
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn std::any::Any> {
+        self
+    }
+
+    async fn resolve(self: Box<Self>, referents: &Referents, controller: &mut dyn Controller) -> Result<Command, ResolveError> {
+        <T as TypedCommand>::resolve(*self, referents, controller).await
+            .map(Command::new)
+    }
+
+    fn into_action(self: Box<Self>, me: &mut dyn WorldObject, world: &WorldView, my_handle: WorldObjectHandle) -> Result<Action, Box<dyn std::error::Error>> {
+        let actor = me.definite_description();
+        let me = me.as_any_mut()
+            .downcast_mut::<T::Actor>()
+            .ok_or(WrongActorError { actor, keyword: T::KEYWORD })?;
+
+        <T as TypedCommand>::into_action(*self, me, world, my_handle)
+            .map_err(|err| Box::new(err).into())
+    }
+}
+
+// Command is a command of any registered type.  Commands are serialized
+// along with their tag so that they can be sent over the network.
+pub struct Command(Box<dyn AnyCommand>);
+
+impl Command {
+    pub fn new<T: TypedCommand>(command: T) -> Self {
+        Self(Box::new(command))
+    }
+
+    pub fn keyword(&self) -> &'static str {
+        self.0.keyword()
+    }
+
+    // downcast returns the command as a command of the given type,
+    // or gives it back if it is a command of some other type
+    pub fn downcast<T: TypedCommand>(self) -> Result<T, Self> {
+        if !self.0.as_any().is::<T>() {
+            return Err(self);
+        }
+
+        match self.0.into_any().downcast::<T>() {
+            Ok(command) => Ok(*command),
+            Err(_) => unreachable!("the command was just checked to be of this type"),
+        }
+    }
+
+    pub async fn resolve(self, referents: &Referents, controller: &mut dyn Controller) -> Result<Self, ResolveError> {
+        self.0.resolve(referents, controller).await
+    }
+
+    // into_action builds the action the given object takes when given
+    // the command, if it is the kind of actor that can carry it out or
+    // has been registered as carrying it out in its own way
+    pub fn into_action(self, me: &mut dyn WorldObject, world: &WorldView, my_handle: WorldObjectHandle) -> Result<Action, Box<dyn std::error::Error>> {
+        let command_type = (*self.0.as_any()).type_id();
+        let actor_type = (*me.as_any_mut()).type_id();
+
+        match registry::carrier(command_type, actor_type) {
+            Some(carry_out) => carry_out(self, me, world, my_handle),
+            None => self.0.into_action(me, world, my_handle),
+        }
+    }
+}
+
+impl Serialize for Command {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let data = self.0.to_json().map_err(serde::ser::Error::custom)?;
+        json!({ "type": self.0.tag(), "data": data }).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Command {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        registry::command_from_json(&value).map_err(serde::de::Error::custom)
+    }
 }
 
 #[derive(Debug)]
 pub enum HumanActionParseError {
     NoActionNameProvided,
     InvalidActionName(String),
-    MoveActionParseError(move_command::MoveActionParseError),
-    InteractActionParseError(interact_action::InteractActionParseError),
-    CollectActionParseError(collect_command::CollectCommandParseError),
-    AttackActionParseError(attack_command::AttackActionParseError),
-    ExamineActionParseError(examine_command::ExamineCommandParseError),
-    WieldActionParseError(wield_command::WieldCommandParseError),
-    UseActionParseError(use_command::UseCommandParseError),
+    CommandParseError { keyword: &'static str, usage: &'static str, err: Box<dyn std::error::Error> },
 }
 
 impl std::fmt::Display for HumanActionParseError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::NoActionNameProvided => write!(f, "no action name provided"),
-            Self::InvalidActionName(name) => write!(f, "invalid action name \"{}\"", name),
-            Self::MoveActionParseError(move_err) => write!(f, "failed to parse move action: {}", move_err),
-            Self::CollectActionParseError(collect_err) => write!(f, "failed to parse collect action: {}", collect_err),
-            Self::AttackActionParseError(attack_err) => write!(f, "failed to parse attack action: {}", attack_err),
-            Self::ExamineActionParseError(examine_err) => write!(f, "failed to parse examine action: {}", examine_err),
-            Self::InteractActionParseError(interact_err) => write!(f, "failed to parse interact action: {}", interact_err),
-            Self::WieldActionParseError(wield_err) => write!(f, "failed to parse wield action: {}", wield_err),
-            Self::UseActionParseError(use_err) => write!(f, "failed to parse use action: {}", use_err),
+            Self::InvalidActionName(name) => write!(f, "invalid action name \"{}\"; type \"help\" for a list of actions", name),
+            Self::CommandParseError { keyword, usage, err } => write!(f, "failed to parse {} action: {}; usage: {}", keyword, err, usage),
         }
     }
 }
@@ -54,45 +231,26 @@ impl std::error::Error for HumanActionParseError {}
 
 impl Command {
     fn parse<'a, I: Iterator<Item = &'a str>>(words: &mut std::iter::Peekable<I>) -> Result<Self, HumanActionParseError> {
-        let action_name = words.next();
-
-        match action_name {
-            Some(borrowed_str) => match borrowed_str {
-                "move" => move_command::MoveCommand::parse(words)
-                    .map(Command::Move)
-                    .map_err(HumanActionParseError::MoveActionParseError),
-                "interact" => interact_action::InteractAction::parse(words)
-                    .map(Command::Interact)
-                    .map_err(HumanActionParseError::InteractActionParseError),
-                "collect" => collect_command::CollectCommand::parse(words)
-                    .map(Command::Collect)
-                    .map_err(HumanActionParseError::CollectActionParseError),
-                "attack" => attack_command::AttackCommand::parse(words)
-                    .map(Command::Attack)
-                    .map_err(HumanActionParseError::AttackActionParseError),
-                "examine" => examine_command::ExamineCommand::parse(words)
-                    .map(Command::Examine)
-                    .map_err(HumanActionParseError::ExamineActionParseError),
-                "wield" => wield_command::WieldCommand::parse(words)
-                    .map(Command::Wield)
-                    .map_err(HumanActionParseError::WieldActionParseError),
-                "circumspect" => Ok(Command::Circumspect),
-                "inventory" => Ok(Command::Inventory),
-                "use" => use_command::UseCommand::parse(words)
-                    .map(Command::Use)
-                    .map_err(HumanActionParseError::UseActionParseError),
-                other => Err(HumanActionParseError::InvalidActionName(other.to_string())),
-            },
-            None => Err(HumanActionParseError::NoActionNameProvided),
-        }
+        let action_name = words.next()
+            .ok_or(HumanActionParseError::NoActionNameProvided)?;
+
+        registry::parse_command(action_name, &words.collect::<Vec<_>>())
     }
 }
 
+// phrase consumes the rest of the words as a phrase, e.g. "dead rat",
+// leaving out any preposition introducing it, as in "interact with rat"
+fn phrase<'a, I: Iterator<Item = &'a str>>(words: &mut std::iter::Peekable<I>) -> Option<String> {
+    words.next_if(|word| grammar::PREPOSITIONS.contains(word));
+    let phrase = words.collect::<Vec<_>>().join(" ");
+    (!phrase.is_empty()).then_some(phrase)
+}
+
 impl TryFrom<&str> for Command {
     type Error = HumanActionParseError;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        let words = value.trim().split(" ");
-        Self::parse(&mut words.peekable())
+        let words = grammar::normalize(value);
+        Self::parse(&mut words.iter().map(String::as_str).peekable())
     }
-}
\ No newline at end of file
+}
@@ -7,6 +7,16 @@ use serde::Deserialize;
 
 use std::fmt;
 
+use crate::{
+    world::{handle::WorldObjectHandle, view::WorldView, motion::MoveError},
+    worldobject::{
+        fns::update::Action,
+        human::{Human, actions::move_action}
+    }
+};
+
+use super::TypedCommand;
+
 #[derive(Debug)]
 pub enum MoveActionParseError {
     NoDirectionProvided,
@@ -26,23 +36,85 @@ impl fmt::Display for MoveActionParseError {
     }
 }
 
+impl std::error::Error for MoveActionParseError {}
+
 #[derive(Serialize, Deserialize)]
 pub struct MoveCommand {
     pub direction: direction::DirectionHorizontalOrVertical,
     pub distance: quantities::Quantity<distance::Distance>
 }
 
-impl MoveCommand {
-    pub fn parse<'a, I: Iterator<Item = &'a str>>(words: &mut std::iter::Peekable<I>) -> Result<Self, MoveActionParseError> {
-        let direction = words.next().ok_or(MoveActionParseError::NoDirectionProvided)?;
+impl TypedCommand for MoveCommand {
+    type ParseError = MoveActionParseError;
+    type ActionError = MoveError;
+    type Actor = Human;
+
+    const KEYWORD: &'static str = "move";
+    const SYNONYMS: &'static [&'static str] = &["go", "walk", "step"];
+    const USAGE: &'static str = "move <direction> <distance>";
+    const DESCRIPTION: &'static str = "walk left or right, or climb up or down, the given distance; distances without a unit are in meters";
+    const EXAMPLES: &'static [&'static str] = &["move right 3m", "go left 2 meters", "move up 50 cm"];
+
+    // the direction and the distance may come in either order,
+    // e.g. "move right 3m" or "move 3 meters right"
+    fn parse<'a, I: Iterator<Item = &'a str>>(words: &mut std::iter::Peekable<I>) -> Result<Self, MoveActionParseError> {
+        let mut words = words.collect::<Vec<_>>();
+
+        let direction_index = words.iter()
+            .position(|word| direction::DirectionHorizontalOrVertical::try_from(*word).is_ok());
+        let direction = match (direction_index, words.first()) {
+            (Some(index), _) => words.remove(index),
+            (None, Some(word)) => return Err(MoveActionParseError::InvalidDirection(word.to_string())),
+            (None, None) => return Err(MoveActionParseError::NoDirectionProvided),
+        };
         let direction = direction::DirectionHorizontalOrVertical::try_from(direction)
             .map_err(|_| MoveActionParseError::InvalidDirection(direction.to_string()))?;
 
-        let distance_meters_str = words.next().ok_or(MoveActionParseError::NoDistanceProvided)?;
+        if words.is_empty() {
+            return Err(MoveActionParseError::NoDistanceProvided);
+        }
+
+        let distance_str = words.join(" ");
+        let distance = quantities::Quantity::<distance::Distance>::try_from(distance_str.as_str())
+            .map_err(|_| MoveActionParseError::InvalidDistance(distance_str))?;
 
-        let distance_meters = distance_meters_str.parse::<f64>()
-            .map_err(|_| MoveActionParseError::InvalidDistance(distance_meters_str.to_string()))?;
+        Ok(MoveCommand { direction, distance })
+    }
 
-        Ok(MoveCommand { direction, distance: distance::meters(distance_meters) })
+    fn into_action(self, me: &mut Human, _world: &WorldView, my_handle: WorldObjectHandle) -> Result<Action, Self::ActionError> {
+        move_action::from_command(self, my_handle, me)
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::quantities::distance::meters;
+
+    use super::{MoveActionParseError, MoveCommand, TypedCommand};
+
+    fn parse(input: &str) -> Result<MoveCommand, MoveActionParseError> {
+        MoveCommand::parse(&mut input.split_whitespace().peekable())
+    }
+
+    #[test]
+    fn direction_and_distance_may_come_in_either_order() {
+        for input in ["right 3m", "3 meters right"] {
+            let command = parse(input).unwrap();
+            assert_eq!(command.direction.to_string(), "right");
+            assert_eq!(command.distance, meters(3.0));
+        }
+    }
+
+    #[test]
+    fn rejects_distances_that_are_not_positive() {
+        assert!(matches!(parse("right -100m"), Err(MoveActionParseError::InvalidDistance(_))));
+        assert!(matches!(parse("left 0m"), Err(MoveActionParseError::InvalidDistance(_))));
+    }
+
+    #[test]
+    fn rejects_missing_directions_and_distances() {
+        assert!(matches!(parse(""), Err(MoveActionParseError::NoDirectionProvided)));
+        assert!(matches!(parse("sideways 3m"), Err(MoveActionParseError::InvalidDirection(_))));
+        assert!(matches!(parse("up"), Err(MoveActionParseError::NoDistanceProvided)));
+    }
+}
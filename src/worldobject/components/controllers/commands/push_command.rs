@@ -0,0 +1,118 @@
+use std::fmt;
+
+use serde::Serialize;
+use serde::Deserialize;
+use async_trait::async_trait;
+
+use crate::{
+    world::{handle::{WorldObjectHandle, WorldObjectLabel}, view::WorldView},
+    worldobject::{
+        fns::update::Action,
+        human::{Human, actions::push_action::{self, PushCommandToActionError}}
+    },
+    quantities::direction::DirectionHorizontalOrVertical
+};
+
+use super::{TypedCommand, super::Controller, resolve::{Referents, ResolveError, resolve}};
+
+// the word that may introduce the direction of a push, as in "push rat to left"
+const DIRECTION_PREPOSITION: &str = "to";
+
+#[derive(Serialize, Deserialize)]
+pub struct PushCommand {
+    pub target: WorldObjectLabel,
+    pub direction: DirectionHorizontalOrVertical,
+}
+
+#[derive(Debug)]
+pub enum PushActionParseError {
+    NoTargetProvided,
+    NoDirectionProvided,
+    InvalidDirection(String),
+}
+
+impl fmt::Display for PushActionParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoTargetProvided => write!(f, "no target provided"),
+            Self::NoDirectionProvided => write!(f, "no direction provided"),
+            Self::InvalidDirection(dir_str) => write!(f, "invalid direction \"{}\"; you can push things left, right, up or down", dir_str),
+        }
+    }
+}
+
+impl std::error::Error for PushActionParseError {}
+
+#[async_trait]
+impl TypedCommand for PushCommand {
+    type ParseError = PushActionParseError;
+    type ActionError = PushCommandToActionError;
+    type Actor = Human;
+
+    const KEYWORD: &'static str = "push";
+    const SYNONYMS: &'static [&'static str] = &["shove"];
+    const USAGE: &'static str = "push <target> <direction>";
+    const DESCRIPTION: &'static str = "push something within arm's reach left, right, up or down; the lighter it is, the further it goes";
+    const EXAMPLES: &'static [&'static str] = &["push rat right", "shove bob to the left", "push rat up"];
+
+    // the direction comes last, e.g. "push rat left" or "push the rat to the left"
+    fn parse<'a, I: Iterator<Item = &'a str>>(words: &mut std::iter::Peekable<I>) -> Result<Self, PushActionParseError> {
+        let mut words = words.collect::<Vec<_>>();
+
+        let direction = words.pop()
+            .ok_or(PushActionParseError::NoDirectionProvided)?;
+        if words.is_empty() {
+            return Err(PushActionParseError::NoTargetProvided);
+        }
+        let direction = DirectionHorizontalOrVertical::try_from(direction)
+            .map_err(|_| PushActionParseError::InvalidDirection(direction.to_string()))?;
+
+        if words.last() == Some(&DIRECTION_PREPOSITION) {
+            words.pop();
+        }
+        if words.is_empty() {
+            return Err(PushActionParseError::NoTargetProvided);
+        }
+
+        Ok(PushCommand { target: words.join(" "), direction })
+    }
+
+    async fn resolve(self, referents: &Referents, controller: &mut dyn Controller) -> Result<Self, ResolveError> {
+        let target = resolve(&self.target, &referents.objects, controller).await?;
+        Ok(PushCommand { target, direction: self.direction })
+    }
+
+    fn into_action(self, me: &mut Human, world: &WorldView, my_handle: WorldObjectHandle) -> Result<Action, Self::ActionError> {
+        push_action::from_command(self.target, self.direction, world, my_handle, me)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::quantities::direction::{DirectionHorizontal, DirectionHorizontalOrVertical, DirectionVertical};
+
+    use super::{PushActionParseError, PushCommand, TypedCommand};
+
+    fn parse(input: &str) -> Result<PushCommand, PushActionParseError> {
+        PushCommand::parse(&mut input.split_whitespace().peekable())
+    }
+
+    #[test]
+    fn things_may_be_pushed_any_way() {
+        let command = parse("the rat to left").unwrap();
+        assert_eq!(command.target, "the rat");
+        assert_eq!(command.direction, DirectionHorizontalOrVertical::Horizontal(DirectionHorizontal::Left));
+
+        let command = parse("rat up").unwrap();
+        assert_eq!(command.target, "rat");
+        assert_eq!(command.direction, DirectionHorizontalOrVertical::Vertical(DirectionVertical::Up));
+    }
+
+    #[test]
+    fn rejects_missing_targets_and_directions() {
+        assert!(matches!(parse(""), Err(PushActionParseError::NoDirectionProvided)));
+        assert!(matches!(parse("down"), Err(PushActionParseError::NoTargetProvided)));
+        assert!(matches!(parse("to down"), Err(PushActionParseError::NoTargetProvided)));
+        assert!(matches!(parse("rat sideways"), Err(PushActionParseError::InvalidDirection(_))));
+    }
+}
@@ -0,0 +1,357 @@
+// This module houses the command registry.
+//
+// Every command players can give is registered here under its keyword,
+// along with its synonyms, its parser and the tag it is sent over the
+// network with.  Adding a verb means writing a TypedCommand and
+// registering it; neither the parser nor the network protocol needs to
+// know about it otherwise.
+//
+// Every command that ships with the game is registered in
+// CommandRegistry::builtin.  Other commands are registered on a registry
+// of their own, usually the builtin one, which is then installed in its
+// place; this has to happen before any command is first looked up.
+
+use std::{
+    any::TypeId,
+    collections::BTreeMap,
+    sync::OnceLock
+};
+
+use crate::{
+    world::{handle::WorldObjectHandle, view::WorldView},
+    worldobject::{
+        WorldObject,
+        fns::update::Action,
+        rat::Rat
+    }
+};
+
+use super::{
+    Command,
+    TypedCommand,
+    CarriesOut,
+    HumanActionParseError,
+    attack_command::AttackCommand,
+    bash_command::BashCommand,
+    bite_command::BiteCommand,
+    circumspect_command::CircumspectCommand,
+    collect_command::CollectCommand,
+    drop_command::DropCommand,
+    examine_command::ExamineCommand,
+    give_command::GiveCommand,
+    interact_action::InteractAction,
+    inventory_command::InventoryCommand,
+    move_command::MoveCommand,
+    push_command::PushCommand,
+    slash_command::SlashCommand,
+    stab_command::StabCommand,
+    trade_command::TradeCommand,
+    unwield_command::UnwieldCommand,
+    use_command::UseCommand,
+    wield_command::WieldCommand
+};
+
+type CommandParser = fn(&[&str]) -> Result<Command, Box<dyn std::error::Error>>;
+type CommandConstructor = fn(&serde_json::Value) -> Result<Command, serde_json::Error>;
+type CommandCarrier = fn(Command, &mut dyn WorldObject, &WorldView, WorldObjectHandle) -> Result<Action, Box<dyn std::error::Error>>;
+
+// CommandInfo describes a command to players
+#[derive(Clone, Copy)]
+pub struct CommandInfo {
+    pub keyword: &'static str,
+    pub synonyms: &'static [&'static str],
+    pub usage: &'static str,
+    pub description: &'static str,
+    pub examples: &'static [&'static str],
+}
+
+struct CommandEntry {
+    info: CommandInfo,
+    parse: CommandParser,
+}
+
+pub struct CommandRegistry {
+    // commands by keyword; kept sorted so that commands are always listed in the same order
+    commands: BTreeMap<&'static str, CommandEntry>,
+    // command constructors by network tag
+    tags: BTreeMap<&'static str, CommandConstructor>,
+    // how objects carry out commands meant for other kinds of actor,
+    // by the types of the command and of the object
+    carriers: BTreeMap<(TypeId, TypeId), CommandCarrier>,
+}
+
+static REGISTRY: OnceLock<CommandRegistry> = OnceLock::new();
+
+// registry gives the registry in use, which is the builtin
+// registry unless another was installed before it was needed
+fn registry() -> &'static CommandRegistry {
+    REGISTRY.get_or_init(CommandRegistry::builtin)
+}
+
+#[derive(Debug)]
+pub enum CommandRegistryError {
+    AlreadyInUse,
+    MissingTag,
+    MissingData(String),
+    UnknownTag(String),
+    FailedToDeserialize(String, serde_json::Error),
+}
+
+impl std::fmt::Display for CommandRegistryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AlreadyInUse => write!(f, "a command registry is already in use"),
+            Self::MissingTag => write!(f, "command tag not found"),
+            Self::MissingData(tag) => write!(f, "data not found for command \"{}\"", tag),
+            Self::UnknownTag(tag) => write!(f, "no command registered under tag \"{}\"", tag),
+            Self::FailedToDeserialize(tag, err) => write!(f, "failed to deserialize command \"{}\": {}", tag, err),
+        }
+    }
+}
+
+impl std::error::Error for CommandRegistryError {}
+
+fn parse_typed_command<T: TypedCommand>(words: &[&str]) -> Result<Command, Box<dyn std::error::Error>> {
+    T::parse(&mut words.iter().copied().peekable())
+        .map(Command::new)
+        .map_err(|err| Box::new(err).into())
+}
+
+fn construct_command<T: TypedCommand>(value: &serde_json::Value) -> Result<Command, serde_json::Error> {
+    T::deserialize(value).map(Command::new)
+}
+
+fn carry_out_command<A: CarriesOut<C>, C: TypedCommand>(command: Command, me: &mut dyn WorldObject, world: &WorldView, my_handle: WorldObjectHandle) -> Result<Action, Box<dyn std::error::Error>> {
+    let (Ok(command), Some(me)) = (command.downcast::<C>(), me.as_any_mut().downcast_mut::<A>()) else {
+        unreachable!("carriers are looked up by the types of the command and the object")
+    };
+
+    me.carry_out(command, world, my_handle)
+        .map_err(|err| Box::new(err).into())
+}
+
+impl CommandRegistry {
+    fn new() -> Self {
+        Self {
+            commands: BTreeMap::new(),
+            tags: BTreeMap::new(),
+            carriers: BTreeMap::new(),
+        }
+    }
+
+    // builtin creates a registry containing every command that ships with the game
+    pub fn builtin() -> Self {
+        let mut registry = Self::new();
+
+        registry.register::<MoveCommand>();
+        registry.register::<InteractAction>();
+        registry.register::<CollectCommand>();
+        registry.register::<AttackCommand>();
+        registry.register::<SlashCommand>();
+        registry.register::<StabCommand>();
+        registry.register::<BashCommand>();
+        registry.register::<BiteCommand>();
+        registry.register::<PushCommand>();
+        registry.register::<ExamineCommand>();
+        registry.register::<WieldCommand>();
+        registry.register::<UnwieldCommand>();
+        registry.register::<DropCommand>();
+        registry.register::<GiveCommand>();
+        registry.register::<TradeCommand>();
+        registry.register::<CircumspectCommand>();
+        registry.register::<InventoryCommand>();
+        registry.register::<UseCommand>();
+
+        // rats get about and bite, whatever they're told
+        registry.register_carrier::<Rat, MoveCommand>();
+        registry.register_carrier::<Rat, AttackCommand>();
+        registry.register_carrier::<Rat, BiteCommand>();
+        registry.register_carrier::<Rat, CircumspectCommand>();
+
+        registry
+    }
+
+    // install makes this the registry in use; it fails once any
+    // command has been looked up, since the registry is then fixed
+    pub fn install(self) -> Result<(), CommandRegistryError> {
+        REGISTRY.set(self).map_err(|_| CommandRegistryError::AlreadyInUse)
+    }
+
+    // register adds a command, replacing any registered under the same keyword or tag
+    pub fn register<T: TypedCommand>(&mut self) {
+        let info = CommandInfo {
+            keyword: T::KEYWORD,
+            synonyms: T::SYNONYMS,
+            usage: T::USAGE,
+            description: T::DESCRIPTION,
+            examples: T::EXAMPLES,
+        };
+        self.commands.insert(T::KEYWORD, CommandEntry { info, parse: parse_typed_command::<T> });
+        self.tags.insert(T::TAG, construct_command::<T>);
+    }
+
+    // register_carrier lets objects of type A carry out commands of type C in their own way
+    pub fn register_carrier<A: CarriesOut<C>, C: TypedCommand>(&mut self) {
+        self.carriers.insert((TypeId::of::<C>(), TypeId::of::<A>()), carry_out_command::<A, C>);
+    }
+
+    fn commands(&self) -> Vec<CommandInfo> {
+        self.commands.values()
+            .map(|entry| entry.info)
+            .collect()
+    }
+
+    fn parse_command(&self, keyword: &str, words: &[&str]) -> Result<Command, HumanActionParseError> {
+        let entry = self.commands.get(keyword)
+            .ok_or(HumanActionParseError::InvalidActionName(keyword.to_string()))?;
+
+        (entry.parse)(words).map_err(|err| HumanActionParseError::CommandParseError {
+            keyword: entry.info.keyword,
+            usage: entry.info.usage,
+            err
+        })
+    }
+
+    fn command_from_json(&self, value: &serde_json::Value) -> Result<Command, CommandRegistryError> {
+        let tag = value.get("type").and_then(|tag| tag.as_str()).ok_or(CommandRegistryError::MissingTag)?;
+        let data = value.get("data").ok_or(CommandRegistryError::MissingData(String::from(tag)))?;
+
+        let constructor = self.tags.get(tag)
+            .ok_or(CommandRegistryError::UnknownTag(String::from(tag)))?;
+
+        constructor(data).map_err(|err| CommandRegistryError::FailedToDeserialize(String::from(tag), err))
+    }
+}
+
+// commands describes every registered command, ordered by keyword
+pub fn commands() -> Vec<CommandInfo> {
+    registry().commands()
+}
+
+// parse_command parses the words following a keyword into the command it names
+pub fn parse_command(keyword: &str, words: &[&str]) -> Result<Command, HumanActionParseError> {
+    registry().parse_command(keyword, words)
+}
+
+// command_from_json reconstructs a command from its serialized form
+pub fn command_from_json(value: &serde_json::Value) -> Result<Command, CommandRegistryError> {
+    registry().command_from_json(value)
+}
+
+// carrier finds how objects of the given type carry out commands of
+// the given type, if they have been registered as carrying them out
+pub(super) fn carrier(command_type: TypeId, actor_type: TypeId) -> Option<CommandCarrier> {
+    registry().carriers.get(&(command_type, actor_type)).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{any::TypeId, convert::Infallible};
+
+    use async_trait::async_trait;
+    use serde::{Serialize, Deserialize};
+    use serde_json::json;
+
+    use crate::{
+        world::{handle::WorldObjectHandle, view::WorldView},
+        worldobject::{fns::update::Action, human::Human, rat::Rat}
+    };
+
+    use super::{
+        Command,
+        CommandRegistry,
+        CommandRegistryError,
+        HumanActionParseError,
+        TypedCommand,
+        AttackCommand,
+        carrier,
+        command_from_json,
+        commands,
+        parse_command
+    };
+
+    #[derive(Serialize, Deserialize)]
+    struct DanceCommand;
+
+    #[async_trait]
+    impl TypedCommand for DanceCommand {
+        type ParseError = Infallible;
+        type ActionError = Infallible;
+        type Actor = Human;
+
+        const KEYWORD: &'static str = "dance";
+        const USAGE: &'static str = "dance";
+        const DESCRIPTION: &'static str = "dance a little jig";
+
+        fn parse<'a, I: Iterator<Item = &'a str>>(_words: &mut std::iter::Peekable<I>) -> Result<Self, Self::ParseError> {
+            Ok(Self)
+        }
+
+        fn into_action(self, _me: &mut Human, _world: &WorldView, _my_handle: WorldObjectHandle) -> Result<Action, Self::ActionError> {
+            Ok(Action::no_op())
+        }
+    }
+
+    #[test]
+    fn commands_are_listed_once_each_by_keyword() {
+        let keywords = commands().iter().map(|command| command.keyword).collect::<Vec<_>>();
+
+        let mut sorted = keywords.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(keywords, sorted);
+        assert!(keywords.contains(&"move") && keywords.contains(&"trade"));
+    }
+
+    #[test]
+    fn parses_the_words_following_a_keyword() {
+        let command = parse_command("attack", &["rat"]).unwrap();
+        assert_eq!(command.keyword(), "attack");
+
+        assert!(matches!(parse_command("dance", &[]), Err(HumanActionParseError::InvalidActionName(_))));
+        assert!(matches!(parse_command("move", &["sideways"]), Err(HumanActionParseError::CommandParseError { keyword: "move", .. })));
+    }
+
+    #[test]
+    fn commands_survive_a_round_trip_through_json() {
+        let command = Command::try_from("move left 2m").unwrap();
+        let value = serde_json::to_value(&command).unwrap();
+        assert_eq!(value["type"], "move");
+
+        let restored = command_from_json(&value).unwrap();
+        assert_eq!(restored.keyword(), "move");
+        assert_eq!(serde_json::to_value(&restored).unwrap(), value);
+    }
+
+    #[test]
+    fn rejects_json_that_names_no_registered_command() {
+        assert!(matches!(command_from_json(&json!({ "data": {} })), Err(CommandRegistryError::MissingTag)));
+        assert!(matches!(command_from_json(&json!({ "type": "move" })), Err(CommandRegistryError::MissingData(_))));
+        assert!(matches!(command_from_json(&json!({ "type": "dance", "data": {} })), Err(CommandRegistryError::UnknownTag(_))));
+        assert!(matches!(command_from_json(&json!({ "type": "move", "data": {} })), Err(CommandRegistryError::FailedToDeserialize(..))));
+    }
+
+    #[test]
+    fn registers_commands_besides_the_builtin_ones() {
+        let mut registry = CommandRegistry::builtin();
+        registry.register::<DanceCommand>();
+
+        assert!(registry.commands().iter().any(|command| command.keyword == "dance"));
+        assert_eq!(registry.parse_command("dance", &[]).unwrap().keyword(), "dance");
+
+        let value = serde_json::to_value(Command::new(DanceCommand)).unwrap();
+        assert_eq!(registry.command_from_json(&value).unwrap().keyword(), "dance");
+    }
+
+    #[test]
+    fn cannot_install_a_registry_once_commands_have_been_looked_up() {
+        commands();
+        assert!(matches!(CommandRegistry::builtin().install(), Err(CommandRegistryError::AlreadyInUse)));
+    }
+
+    #[test]
+    fn rats_carry_out_attacks_in_their_own_way() {
+        assert!(carrier(TypeId::of::<AttackCommand>(), TypeId::of::<Rat>()).is_some());
+        assert!(carrier(TypeId::of::<AttackCommand>(), TypeId::of::<Human>()).is_none());
+    }
+}
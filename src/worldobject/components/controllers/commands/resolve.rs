@@ -0,0 +1,144 @@
+// This module resolves the phrases players use to refer to things,
+// such as "the dead rat", "sword" or "my wand", into the objects and
+// items they mean.  Phrases are matched against the names and the
+// descriptions of everything in scope; when a phrase could refer to
+// more than one thing, the player is asked to choose between them.
+//
+// Commands are resolved before they are turned into actions: each
+// phrase is replaced with the key of what it refers to, i.e. the label
+// of a world object or the handle of an inventory item, so that actions
+// can look up their targets directly.
+
+use std::fmt;
+
+use crate::world::{perception::Perception, view::WorldView};
+
+use super::super::Controller;
+
+// words that don't help pick out what a phrase refers to
+const FILLER_WORDS: [&str; 4] = ["the", "a", "an", "my"];
+
+// Referent is something a phrase can refer to
+#[derive(Clone)]
+pub struct Referent {
+    // what the referent is looked up by once resolved
+    pub key: String,
+    // the names and descriptions the referent goes by
+    pub names: Vec<String>,
+    // how the referent is described when the player must choose
+    pub description: String,
+}
+
+// Referents holds everything a character's commands can refer to
+pub struct Referents {
+    // the objects around the character
+    pub objects: Vec<Referent>,
+    // the items in the character's inventory
+    pub inventory: Vec<Referent>,
+    // the items the character is wielding
+    pub wielded: Vec<Referent>,
+}
+
+impl Referents {
+    // visible_objects returns the referents of a character that carries
+    // nothing: just the objects it can see with the given perception
+    pub fn visible_objects(world: &WorldView, perception: &Perception) -> Self {
+        let my_coord = world.actor_coord();
+
+        let objects = world.visible_objects(perception).into_iter()
+            .filter_map(|(handle, coord, object)| {
+                let label = world.label_of(handle).ok()?.clone();
+                Some(Referent {
+                    description: format!("{} ({}), {}", object.indefinite_description(), label, coord.describe_relative_to(&my_coord)),
+                    names: vec![label.clone(), object.name(), object.definite_description(), object.indefinite_description()],
+                    key: label,
+                })
+            })
+            .collect();
+
+        Self { objects, inventory: Vec::new(), wielded: Vec::new() }
+    }
+}
+
+#[derive(Debug)]
+pub enum ResolveError {
+    NothingMatches(String),
+    FailedToChoose(Box<dyn std::error::Error>),
+}
+
+impl fmt::Display for ResolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NothingMatches(phrase) => write!(f, "there is nothing you could mean by \"{}\"", phrase),
+            Self::FailedToChoose(err) => write!(f, "failed to choose: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for ResolveError {}
+
+fn significant_words(text: &str) -> Vec<String> {
+    text.split_whitespace()
+        .map(str::to_lowercase)
+        .filter(|word| !FILLER_WORDS.contains(&word.as_str()))
+        .collect()
+}
+
+impl Referent {
+    // is_named_by returns whether the words are exactly one of the referent's names
+    fn is_named_by(&self, words: &[String]) -> bool {
+        self.names.iter().any(|name| significant_words(name) == words)
+    }
+
+    // is_described_by returns whether every word appears in one of the referent's names
+    fn is_described_by(&self, words: &[String]) -> bool {
+        self.names.iter().any(|name| {
+            let name_words = significant_words(name);
+            words.iter().all(|word| name_words.contains(word))
+        })
+    }
+}
+
+// resolve returns the key of the candidate the phrase refers to.  A phrase
+// that is a key refers to that candidate; otherwise candidates named by the
+// phrase are preferred over those it merely describes.
+pub async fn resolve(phrase: &str, candidates: &[Referent], controller: &mut dyn Controller) -> Result<String, ResolveError> {
+    if let Some(candidate) = candidates.iter().find(|candidate| candidate.key.eq_ignore_ascii_case(phrase.trim())) {
+        return Ok(candidate.key.clone());
+    }
+
+    let words = significant_words(phrase);
+    if words.is_empty() {
+        return Err(ResolveError::NothingMatches(String::from(phrase)));
+    }
+
+    let named = candidates.iter()
+        .filter(|candidate| candidate.is_named_by(&words))
+        .collect::<Vec<_>>();
+
+    let matches = if named.is_empty() {
+        candidates.iter()
+            .filter(|candidate| candidate.is_described_by(&words))
+            .collect::<Vec<_>>()
+    } else {
+        named
+    };
+
+    match matches.as_slice() {
+        [] => Err(ResolveError::NothingMatches(String::from(phrase))),
+        [only] => Ok(only.key.clone()),
+        _ => {
+            let prompt = format!("Which do you mean by \"{}\"?", phrase);
+            let options = matches.iter()
+                .map(|candidate| candidate.description.clone())
+                .collect();
+
+            let choice = controller.choose(prompt, options).await
+                .map_err(ResolveError::FailedToChoose)?;
+
+            matches.get(choice)
+                .map(|candidate| candidate.key.clone())
+                .ok_or(ResolveError::FailedToChoose(format!("invalid choice {}", choice).into()))
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use serde::Serialize;
+use serde::Deserialize;
+use async_trait::async_trait;
+
+use crate::{
+    combat::DamageType,
+    world::{handle::{WorldObjectHandle, WorldObjectLabel}, view::WorldView},
+    worldobject::{
+        fns::update::Action,
+        components::body::BodyPart,
+        human::{Human, actions::attack_action::{self, AttackCommandToActionError}}
+    }
+};
+
+use super::{TypedCommand, super::Controller, attack_command::{AttackActionParseError, parse_target_and_aim}, resolve::{Referents, ResolveError, resolve}};
+
+#[derive(Serialize, Deserialize)]
+pub struct SlashCommand {
+    pub target: WorldObjectLabel,
+    pub aim: Option<BodyPart>,
+}
+
+#[async_trait]
+impl TypedCommand for SlashCommand {
+    type ParseError = AttackActionParseError;
+    type ActionError = AttackCommandToActionError;
+    type Actor = Human;
+
+    const KEYWORD: &'static str = "slash";
+    const SYNONYMS: &'static [&'static str] = &["cut", "hack", "slice"];
+    const USAGE: &'static str = "slash <target> [in <body part>]";
+    const DESCRIPTION: &'static str = "strike something within reach with the edge of the weapon you are wielding";
+    const EXAMPLES: &'static [&'static str] = &["slash rat", "cut the dead rat", "slash bob in right arm"];
+
+    fn parse<'a, I: Iterator<Item = &'a str>>(words: &mut std::iter::Peekable<I>) -> Result<Self, AttackActionParseError> {
+        let (target, aim) = parse_target_and_aim(words)?;
+
+        Ok(SlashCommand { target, aim })
+    }
+
+    async fn resolve(self, referents: &Referents, controller: &mut dyn Controller) -> Result<Self, ResolveError> {
+        let target = resolve(&self.target, &referents.objects, controller).await?;
+        Ok(SlashCommand { target, aim: self.aim })
+    }
+
+    fn into_action(self, me: &mut Human, world: &WorldView, my_handle: WorldObjectHandle) -> Result<Action, Self::ActionError> {
+        attack_action::from_command(self.target, self.aim, Some(DamageType::Slash), world, my_handle, me)
+    }
+}
@@ -0,0 +1,78 @@
+use std::fmt;
+
+use serde::Serialize;
+use serde::Deserialize;
+use async_trait::async_trait;
+
+use crate::{
+    world::{handle::{WorldObjectHandle, WorldObjectLabel}, view::WorldView},
+    worldobject::{
+        fns::update::Action,
+        human::{Human, actions::trade_action::{self, TradeCommandToActionError}}
+    }
+};
+
+use super::{TypedCommand, super::Controller, grammar, resolve::{Referents, ResolveError, resolve}};
+
+#[derive(Serialize, Deserialize)]
+pub struct TradeCommand {
+    // the item offered in trade; once resolved, this is the item's handle
+    pub item: String,
+    pub partner: WorldObjectLabel,
+}
+
+#[derive(Debug)]
+pub enum TradeCommandParseError {
+    NoItemProvided,
+    NoPartnerProvided,
+}
+
+impl fmt::Display for TradeCommandParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TradeCommandParseError::NoItemProvided => write!(f, "no item provided"),
+            TradeCommandParseError::NoPartnerProvided => write!(f, "no one to trade with provided"),
+        }
+    }
+}
+
+impl std::error::Error for TradeCommandParseError {}
+
+#[async_trait]
+impl TypedCommand for TradeCommand {
+    type ParseError = TradeCommandParseError;
+    type ActionError = TradeCommandToActionError;
+    type Actor = Human;
+
+    const KEYWORD: &'static str = "trade";
+    const SYNONYMS: &'static [&'static str] = &["offer", "barter", "swap"];
+    const USAGE: &'static str = "trade <item> with <character>";
+    const DESCRIPTION: &'static str = "offer an item from your inventory to someone within arm's reach; they choose an item to give in return, and the items change hands once you both agree";
+    const EXAMPLES: &'static [&'static str] = &["trade sword with bob", "offer my wand to alice"];
+
+    // the item comes first, followed by the partner
+    // introduced by a preposition, e.g. "trade sword with bob"
+    fn parse<'a, I: Iterator<Item = &'a str>>(words: &mut std::iter::Peekable<I>) -> Result<Self, TradeCommandParseError> {
+        let item_words = std::iter::from_fn(|| words.next_if(|word| !grammar::PREPOSITIONS.contains(word)))
+            .collect::<Vec<_>>();
+        if item_words.is_empty() {
+            return Err(TradeCommandParseError::NoItemProvided);
+        }
+
+        let partner = super::phrase(words).ok_or(TradeCommandParseError::NoPartnerProvided)?;
+        Ok(TradeCommand {
+            item: item_words.join(" "),
+            partner
+        })
+    }
+
+    async fn resolve(self, referents: &Referents, controller: &mut dyn Controller) -> Result<Self, ResolveError> {
+        let item = resolve(&self.item, &referents.inventory, controller).await?;
+        let partner = resolve(&self.partner, &referents.objects, controller).await?;
+        Ok(TradeCommand { item, partner })
+    }
+
+    fn into_action(self, me: &mut Human, world: &WorldView, my_handle: WorldObjectHandle) -> Result<Action, Self::ActionError> {
+        trade_action::from_command(me, my_handle, self, world)
+    }
+}
@@ -0,0 +1,59 @@
+use serde::Serialize;
+use serde::Deserialize;
+use async_trait::async_trait;
+
+use crate::{
+    world::{handle::WorldObjectHandle, view::WorldView},
+    worldobject::{
+        fns::update::Action,
+        human::{Human, actions::unwield_action::{self, UnwieldCommandToActionError}}
+    }
+};
+
+use super::{TypedCommand, super::Controller, wield_command::HandChoice, resolve::{Referents, ResolveError, resolve}};
+
+#[derive(Serialize, Deserialize)]
+pub struct UnwieldCommand {
+    // the item to put away, e.g. "sword"; once resolved, this is the item's name
+    pub item: Option<String>,
+    // the hand to empty, e.g. "off hand"
+    pub hand: Option<HandChoice>,
+}
+
+#[async_trait]
+impl TypedCommand for UnwieldCommand {
+    type ParseError = std::convert::Infallible;
+    type ActionError = UnwieldCommandToActionError;
+    type Actor = Human;
+
+    const KEYWORD: &'static str = "unwield";
+    const SYNONYMS: &'static [&'static str] = &["put away", "sheathe", "stow"];
+    const USAGE: &'static str = "unwield [<item> | <hand>]";
+    const DESCRIPTION: &'static str = "put an item you are wielding back into your inventory; without an item, empties your dominant hand if it holds anything, otherwise your off hand";
+    const EXAMPLES: &'static [&'static str] = &["unwield", "put away sword", "unwield off hand"];
+
+    // the words name either a hand, e.g. "left hand", or an item, e.g. "sword"
+    fn parse<'a, I: Iterator<Item = &'a str>>(words: &mut std::iter::Peekable<I>) -> Result<Self, Self::ParseError> {
+        let words = words.collect::<Vec<_>>();
+        if words.is_empty() {
+            return Ok(UnwieldCommand { item: None, hand: None });
+        }
+
+        Ok(match HandChoice::try_from(words.as_slice()) {
+            Ok(hand) => UnwieldCommand { item: None, hand: Some(hand) },
+            Err(_) => UnwieldCommand { item: Some(words.join(" ")), hand: None },
+        })
+    }
+
+    async fn resolve(self, referents: &Referents, controller: &mut dyn Controller) -> Result<Self, ResolveError> {
+        let item = match self.item {
+            Some(item) => Some(resolve(&item, &referents.wielded, controller).await?),
+            None => None,
+        };
+        Ok(UnwieldCommand { item, hand: self.hand })
+    }
+
+    fn into_action(self, me: &mut Human, _world: &WorldView, _my_handle: WorldObjectHandle) -> Result<Action, Self::ActionError> {
+        unwield_action::from_command(me, self)
+    }
+}
@@ -2,44 +2,81 @@ use std::fmt;
 
 use serde::Serialize;
 use serde::Deserialize;
+use async_trait::async_trait;
 
-use crate::world::handle::WorldObjectHandle;
+use crate::{
+    world::{handle::{WorldObjectHandle, WorldObjectLabel}, view::WorldView},
+    worldobject::{
+        fns::update::Action,
+        human::{Human, actions::use_action::{self, UseCommandToActionError}}
+    }
+};
+
+use super::{TypedCommand, super::Controller, grammar, resolve::{Referents, ResolveError, resolve}};
 
 
 
 #[derive(Serialize, Deserialize)]
 pub struct UseCommand {
     pub item_name: String,
-    pub target_handle: Option<WorldObjectHandle>
+    pub target: Option<WorldObjectLabel>
 }
 
 #[derive(Debug)]
 pub enum UseCommandParseError {
     NoItemNameProvided,
     InvalidItemName(String),
-    InvalidTargetHandle(Box<dyn std::error::Error>),
 }
 
 impl fmt::Display for UseCommandParseError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             UseCommandParseError::InvalidItemName(name) => write!(f, "you are not wielding an item named \"{}\"", name),
-            UseCommandParseError::NoItemNameProvided => write!(f, "no item name provided"),
-            UseCommandParseError::InvalidTargetHandle(err) => write!(f, "invalid target handle: {}", err)
+            UseCommandParseError::NoItemNameProvided => write!(f, "no item name provided")
         }
     }
-}   
-
-impl UseCommand {
-    pub fn parse<'a, I: Iterator<Item = &'a str>>(words: &mut std::iter::Peekable<I>) -> Result<Self, UseCommandParseError> {
-        let item_name = words.next().ok_or(UseCommandParseError::NoItemNameProvided)?;
-        let target_handle = words.next()
-            .map(|handle| WorldObjectHandle::try_from(handle))
-            .transpose()
-            .map_err(|err| UseCommandParseError::InvalidTargetHandle(Box::new(err)))?;
+}
+
+impl std::error::Error for UseCommandParseError {}   
+
+#[async_trait]
+impl TypedCommand for UseCommand {
+    type ParseError = UseCommandParseError;
+    type ActionError = UseCommandToActionError;
+    type Actor = Human;
+
+    const KEYWORD: &'static str = "use";
+    const SYNONYMS: &'static [&'static str] = &["apply", "zap"];
+    const USAGE: &'static str = "use <item> [on <target>]";
+    const DESCRIPTION: &'static str = "use an item you are wielding, on a target within the item's range if it needs one";
+    const EXAMPLES: &'static [&'static str] = &["use wand on rat"];
+
+    // the item comes first, followed by an optional target
+    // introduced by a preposition, e.g. "use wand on rat"
+    fn parse<'a, I: Iterator<Item = &'a str>>(words: &mut std::iter::Peekable<I>) -> Result<Self, UseCommandParseError> {
+        let item_words = std::iter::from_fn(|| words.next_if(|word| !grammar::PREPOSITIONS.contains(word)))
+            .collect::<Vec<_>>();
+        if item_words.is_empty() {
+            return Err(UseCommandParseError::NoItemNameProvided);
+        }
+
+        let target = super::phrase(words);
         Ok(UseCommand {
-            item_name: String::from(item_name),
-            target_handle
+            item_name: item_words.join(" "),
+            target
         })
     }
-}
\ No newline at end of file
+
+    async fn resolve(self, referents: &Referents, controller: &mut dyn Controller) -> Result<Self, ResolveError> {
+        let item_name = resolve(&self.item_name, &referents.wielded, controller).await?;
+        let target = match self.target {
+            Some(target) => Some(resolve(&target, &referents.objects, controller).await?),
+            None => None,
+        };
+        Ok(UseCommand { item_name, target })
+    }
+
+    fn into_action(self, me: &mut Human, world: &WorldView, my_handle: WorldObjectHandle) -> Result<Action, Self::ActionError> {
+        use_action::from_command(me, my_handle, self, world)
+    }
+}
@@ -2,37 +2,140 @@ use std::fmt;
 
 use serde::Serialize;
 use serde::Deserialize;
+use async_trait::async_trait;
 
-use crate::worldobject::components::inventory::item::InventoryItemHandle;
+use crate::{
+    quantities::direction::DirectionHorizontal,
+    world::{handle::WorldObjectHandle, view::WorldView},
+    worldobject::{
+        fns::update::Action,
+        human::{Human, actions::wield_action::{self, WieldCommandToActionError}}
+    }
+};
+
+use super::{TypedCommand, super::Controller, resolve::{Referents, ResolveError, resolve}};
+
+
+
+// prepositions that introduce the hand to wield an item in, as in "wield sword in left hand"
+const HAND_PREPOSITIONS: [&str; 2] = ["in", "with"];
+
+// words that may accompany the name of a hand without changing which hand it is
+const HAND_FILLER_WORDS: [&str; 3] = ["my", "hand", "arm"];
+
+// HandChoice is a hand as a player names it: by its side, or by
+// whether it is the hand the character favours
+#[derive(Clone, Copy, Serialize, Deserialize)]
+pub enum HandChoice {
+    Dominant,
+    Off,
+    Side(DirectionHorizontal),
+}
+
+impl HandChoice {
+    // side returns which hand is meant, given which hand the character favours
+    pub fn side(self, dominant_arm: DirectionHorizontal) -> DirectionHorizontal {
+        match self {
+            Self::Dominant => dominant_arm,
+            Self::Off => dominant_arm.opposite(),
+            Self::Side(side) => side,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct InvalidHandError(String);
+
+impl fmt::Display for InvalidHandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "there is no hand called \"{}\"; try \"left\", \"right\", \"main\" or \"off\"", self.0)
+    }
+}
+
+impl std::error::Error for InvalidHandError {}
 
+impl TryFrom<&[&str]> for HandChoice {
+    type Error = InvalidHandError;
 
+    fn try_from(words: &[&str]) -> Result<Self, Self::Error> {
+        let name = words.iter()
+            .filter(|word| !HAND_FILLER_WORDS.contains(word))
+            .copied()
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        match name.as_str() {
+            "main" | "dominant" | "good" => Ok(Self::Dominant),
+            "off" | "off-hand" | "offhand" | "other" | "weak" => Ok(Self::Off),
+            side => DirectionHorizontal::try_from(side)
+                .map(Self::Side)
+                .map_err(|_| InvalidHandError(words.join(" "))),
+        }
+    }
+}
 
 #[derive(Serialize, Deserialize)]
 pub struct WieldCommand {
-    pub item_handle: InventoryItemHandle
+    // the item to wield, e.g. "sword"; once resolved, this is the item's handle
+    pub item: String,
+    // the hand to wield the item in; the dominant hand if none is given
+    pub hand: Option<HandChoice>,
 }
 
 #[derive(Debug)]
 pub enum WieldCommandParseError {
-    NoItemHandleProvided,
-    InvalidItemHandle(String),
+    NoItemProvided,
+    InvalidHand(InvalidHandError),
 }
 
 impl fmt::Display for WieldCommandParseError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            WieldCommandParseError::InvalidItemHandle(handle) => write!(f, "invalid item handle: {}", handle),
-            WieldCommandParseError::NoItemHandleProvided => write!(f, "no item handle provided")
+            WieldCommandParseError::NoItemProvided => write!(f, "no item provided"),
+            WieldCommandParseError::InvalidHand(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for WieldCommandParseError {}   
+
+#[async_trait]
+impl TypedCommand for WieldCommand {
+    type ParseError = WieldCommandParseError;
+    type ActionError = WieldCommandToActionError;
+    type Actor = Human;
+
+    const KEYWORD: &'static str = "wield";
+    const SYNONYMS: &'static [&'static str] = &["equip", "hold"];
+    const USAGE: &'static str = "wield <item> [in <hand>]";
+    const DESCRIPTION: &'static str = "take an item from your inventory into your dominant hand, or into the hand you name; whatever that hand held is put into your inventory";
+    const EXAMPLES: &'static [&'static str] = &["wield sword", "equip my wand", "hold wand in off hand", "wield sword in left hand"];
+
+    // the item comes first, followed by an optional hand
+    // introduced by a preposition, e.g. "wield sword in left hand"
+    fn parse<'a, I: Iterator<Item = &'a str>>(words: &mut std::iter::Peekable<I>) -> Result<Self, WieldCommandParseError> {
+        let item_words = words.by_ref()
+            .take_while(|word| !HAND_PREPOSITIONS.contains(word))
+            .collect::<Vec<_>>();
+        if item_words.is_empty() {
+            return Err(WieldCommandParseError::NoItemProvided);
         }
+
+        let hand_words = words.collect::<Vec<_>>();
+        let hand = (!hand_words.is_empty())
+            .then(|| HandChoice::try_from(hand_words.as_slice()))
+            .transpose()
+            .map_err(WieldCommandParseError::InvalidHand)?;
+
+        Ok(WieldCommand { item: item_words.join(" "), hand })
+    }
+
+    async fn resolve(self, referents: &Referents, controller: &mut dyn Controller) -> Result<Self, ResolveError> {
+        let item = resolve(&self.item, &referents.inventory, controller).await?;
+        Ok(WieldCommand { item, hand: self.hand })
     }
-}   
 
-impl WieldCommand {
-    pub fn parse<'a, I: Iterator<Item = &'a str>>(words: &mut std::iter::Peekable<I>) -> Result<Self, WieldCommandParseError> {
-        let item_handle = words.next().ok_or(WieldCommandParseError::NoItemHandleProvided)
-            .and_then(|handle| InventoryItemHandle::try_from(handle)
-                .map_err(|err| WieldCommandParseError::InvalidItemHandle(err))
-            )?;
-        Ok(WieldCommand { item_handle })
+    fn into_action(self, me: &mut Human, _world: &WorldView, _my_handle: WorldObjectHandle) -> Result<Action, Self::ActionError> {
+        wield_action::from_command(me, self)
     }
-}
\ No newline at end of file
+}
@@ -4,12 +4,26 @@ use async_trait::async_trait;
 
 pub mod terminal;
 pub mod net;
+pub mod scripted;
+pub mod ai;
 pub mod commands;
+pub mod observation;
+
+// DECLINE is the option offered alongside the others whenever
+// a player may turn something down, such as an offer of trade
+pub const DECLINE: &str = "decline";
 
 #[async_trait]
 pub trait Controller: Send + Sync {
     async fn prompt_turn(&mut self) -> Result<commands::Command, Box<dyn std::error::Error>>;
     async fn display_message(&mut self, message: String) -> Result<(), Box<dyn std::error::Error>>;
+    // choose asks the player to pick one of several options,
+    // returning the index of the option they picked
+    async fn choose(&mut self, prompt: String, options: Vec<String>) -> Result<usize, Box<dyn std::error::Error>>;
+    // observe tells the controller what its character perceives just before
+    // it is prompted for a turn; players see the world through messages
+    // instead, so only controllers that decide for themselves need it
+    async fn observe(&mut self, _observation: &observation::Observation) {}
 }
 
 #[async_trait]
@@ -22,4 +36,12 @@ where D::Target: Controller {
     async fn display_message(&mut self, message: String) -> Result<(), Box<dyn std::error::Error>> {
         self.deref_mut().display_message(message).await
     }
+
+    async fn choose(&mut self, prompt: String, options: Vec<String>) -> Result<usize, Box<dyn std::error::Error>> {
+        self.deref_mut().choose(prompt, options).await
+    }
+
+    async fn observe(&mut self, observation: &observation::Observation) {
+        self.deref_mut().observe(observation).await
+    }
 }
\ No newline at end of file
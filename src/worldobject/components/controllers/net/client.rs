@@ -96,6 +96,17 @@ impl NetworkHumanControllerClient {
             NetworkHumanControllerMessage::DisplayMessage(message) => {
                 subcontroller.display_message(message).await
             }
+            NetworkHumanControllerMessage::Choose(prompt, options) => {
+                let choice = subcontroller.choose(prompt, options)
+                    .await?;
+                let json = serde_json::to_vec(&choice)?;
+
+                tcp_stream.write_all(&json).await.map_err(|e| Box::new(std::io::Error::new(std::io::ErrorKind::InvalidData, e.to_string())))?;
+
+                tcp_stream.flush().await?;
+
+                Ok(())
+            }
         }
     }
 }
\ No newline at end of file
@@ -76,4 +76,24 @@ impl Controller for NetworkController {
         self.tcp_stream.flush().await.map_err(|e| Box::new(std::io::Error::new(std::io::ErrorKind::InvalidData, e.to_string())))?;
         Ok(())
     }
+
+    // choose sends the options via the TCP stream
+    // and waits for the client to send back the index of its choice
+    async fn choose(&mut self, prompt: String, options: Vec<String>) -> Result<usize, Box<dyn std::error::Error>> {
+        let option_count = options.len();
+        let json_content = serde_json::to_vec(&NetworkHumanControllerMessage::Choose(prompt, options)).map_err(|e| Box::new(std::io::Error::new(std::io::ErrorKind::InvalidData, e.to_string())))?;
+        self.tcp_stream.write_all(&json_content).await.map_err(|e| Box::new(std::io::Error::new(std::io::ErrorKind::InvalidData, e.to_string())))?;
+        self.tcp_stream.flush().await.map_err(|e| Box::new(std::io::Error::new(std::io::ErrorKind::InvalidData, e.to_string())))?;
+
+        let mut json_stream = serde_json::Deserializer::from_reader(SyncIoBridge::new(&mut self.tcp_stream)).into_iter::<usize>();
+        let choice = tokio::task::block_in_place(|| {
+            json_stream.next().ok_or(Box::new(std::io::Error::new(std::io::ErrorKind::InvalidData, "Invalid JSON")))
+        })??;
+
+        if choice >= option_count {
+            return Err(Box::new(std::io::Error::new(std::io::ErrorKind::InvalidData, format!("invalid choice {}", choice))));
+        }
+
+        Ok(choice)
+    }
 }
\ No newline at end of file
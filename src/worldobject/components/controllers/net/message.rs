@@ -4,4 +4,6 @@ use serde::{Serialize, Deserialize};
 pub enum NetworkHumanControllerMessage {
     PromptTurn,
     DisplayMessage(String),
+    // the client replies to Choose with the index of the chosen option
+    Choose(String, Vec<String>),
 }
\ No newline at end of file
@@ -0,0 +1,92 @@
+// This module houses observations: what a character perceives at the
+// start of its turn.  Controllers that play a character themselves, such
+// as the AI, decide what to do from these; players learn about the world
+// through messages instead, and have no need of them.
+
+use crate::{
+    quantities::{Quantity, distance::{Distance, meters}},
+    world::{
+        coord::WorldCoord,
+        handle::WorldObjectLabel,
+        perception::Perception,
+        view::WorldView
+    },
+    worldobject::components::health::Vitality
+};
+
+// Sighting is an object the observer can see
+#[derive(Clone)]
+pub struct Sighting {
+    // the label commands use to refer to the object
+    pub label: WorldObjectLabel,
+    // what kind of object it is, i.e. its type tag, e.g. "rat"
+    pub kind: &'static str,
+    pub vitality: Vitality,
+    pub position: WorldCoord,
+}
+
+#[derive(Clone)]
+pub struct Observation {
+    // what kind of object the observer is, i.e. its type tag
+    pub kind: &'static str,
+    pub position: WorldCoord,
+    // the fraction of its health the observer has left, between 0 and 1
+    pub health: f64,
+    // how far away the observer can strike
+    pub reach: Quantity<Distance>,
+    // how far away the observer can pick things up
+    pub grasp: Quantity<Distance>,
+    // how far the observer can get in a single move
+    pub stride: Quantity<Distance>,
+    // everything the observer can see, ordered by handle
+    pub sightings: Vec<Sighting>,
+    // the seed for whatever the observer leaves to chance this turn
+    pub seed: u64,
+}
+
+impl Sighting {
+    // a living object of a kind other than the observer's is a threat to it
+    pub fn is_threat_to(&self, kind: &str) -> bool {
+        self.vitality == Vitality::Alive && self.kind != kind
+    }
+}
+
+impl Observation {
+    // new records what an observer with the given perception sees from
+    // where it stands; objects without a label can't be referred to,
+    // and so are left out
+    pub fn new(kind: &'static str, health: f64, reach: Quantity<Distance>, grasp: Quantity<Distance>, stride: Quantity<Distance>, perception: &Perception, world: &WorldView) -> Self {
+        let sightings = world.visible_objects(perception).into_iter()
+            .filter_map(|(handle, position, object)| Some(Sighting {
+                label: world.label_of(handle).ok()?.clone(),
+                kind: object.type_tag(),
+                vitality: object.vitality(),
+                position,
+            }))
+            .collect();
+
+        Self { kind, position: world.actor_coord(), health, reach, grasp, stride, sightings, seed: world.seed() }
+    }
+
+    pub fn distance_to(&self, sighting: &Sighting) -> Quantity<Distance> {
+        self.position.distance_to(&sighting.position)
+    }
+
+    pub fn within_reach(&self, sighting: &Sighting) -> bool {
+        self.distance_to(sighting) <= self.reach
+    }
+
+    // threats returns every living thing in sight that isn't of the observer's kind
+    pub fn threats(&self) -> impl Iterator<Item = &Sighting> {
+        self.sightings.iter().filter(|sighting| sighting.is_threat_to(self.kind))
+    }
+
+    // nearest returns whichever of the sightings is closest to the observer
+    pub fn nearest<'a>(&self, sightings: impl Iterator<Item = &'a Sighting>) -> Option<&'a Sighting> {
+        sightings.min_by(|a, b| {
+            let a = (self.distance_to(a) / meters(1.0)).cancel().0.0;
+            let b = (self.distance_to(b) / meters(1.0)).cancel().0.0;
+            a.total_cmp(&b)
+        })
+    }
+}
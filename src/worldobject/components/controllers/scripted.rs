@@ -0,0 +1,193 @@
+// This module houses the ScriptedController, which plays a character by
+// giving a predetermined list of commands, one per turn, and picking
+// predetermined options whenever it has to choose.  It records
+// every message its character receives in a transcript.  It lets the
+// game be played without anyone at the keyboard, e.g. in tests, or
+// when replaying a journal.
+
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex}
+};
+
+use async_trait::async_trait;
+
+use super::{
+    Controller,
+    commands::Command
+};
+
+#[derive(Debug)]
+pub struct ScriptFinishedError;
+
+impl std::fmt::Display for ScriptFinishedError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "the script has run out of commands")
+    }
+}
+
+impl std::error::Error for ScriptFinishedError {}
+
+// RejectedCommandError stands in for a command a player gave that was
+// rejected, so that a replay tells the character just what they were told
+#[derive(Debug)]
+pub struct RejectedCommandError(String);
+
+impl std::fmt::Display for RejectedCommandError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for RejectedCommandError {}
+
+// Transcript is the record of every message a scripted character received.
+// It is shared with whoever made the controller, so that it can still be
+// read once the controller has been handed to a character.
+#[derive(Clone, Default)]
+pub struct Transcript(Arc<Mutex<Vec<String>>>);
+
+impl Transcript {
+    fn record(&self, entry: String) {
+        if let Ok(mut entries) = self.0.lock() {
+            entries.push(entry);
+        }
+    }
+
+    // text returns the transcript so far, one message per line
+    #[cfg(test)]
+    pub fn text(&self) -> String {
+        self.0.lock()
+            .map(|entries| entries.iter().map(|entry| format!("{}\n", entry)).collect())
+            .unwrap_or_default()
+    }
+
+    // take returns every message received since the transcript was last
+    // taken, leaving it empty
+    pub fn take(&self) -> Vec<String> {
+        self.0.lock()
+            .map(|mut entries| std::mem::take(&mut *entries))
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Default)]
+struct ScriptEntries {
+    // the commands yet to be given, each of which may instead
+    // be the reason a command was rejected
+    commands: VecDeque<Result<Command, String>>,
+    // the options to pick, in order, whenever the character must choose;
+    // once these run out, the first option is picked
+    choices: VecDeque<usize>,
+}
+
+// Script is what a scripted character has yet to do.  Like the
+// transcript, it is shared, so that commands and choices can still be
+// added once the controller has been handed to a character.
+#[derive(Clone, Default)]
+pub struct Script(Arc<Mutex<ScriptEntries>>);
+
+impl Script {
+    pub fn push(&self, command: Command) {
+        if let Ok(mut entries) = self.0.lock() {
+            entries.commands.push_back(Ok(command));
+        }
+    }
+
+    // reject has the character fail to give a command on its
+    // turn, for the given reason, in place of the next command
+    pub fn reject(&self, reason: String) {
+        if let Ok(mut entries) = self.0.lock() {
+            entries.commands.push_back(Err(reason));
+        }
+    }
+
+    // choose has the character pick the given option
+    // the next time it must choose, after any already scripted
+    pub fn choose(&self, choice: usize) {
+        if let Ok(mut entries) = self.0.lock() {
+            entries.choices.push_back(choice);
+        }
+    }
+
+    // pending_choices returns how many scripted choices have yet to be made
+    pub fn pending_choices(&self) -> usize {
+        self.0.lock()
+            .map(|entries| entries.choices.len())
+            .unwrap_or_default()
+    }
+
+    fn pop(&self) -> Option<Result<Command, String>> {
+        self.0.lock().ok()?.commands.pop_front()
+    }
+
+    fn pop_choice(&self) -> Option<usize> {
+        self.0.lock().ok()?.choices.pop_front()
+    }
+}
+
+pub struct ScriptedController {
+    script: Script,
+    transcript: Transcript,
+}
+
+impl ScriptedController {
+    pub fn new(commands: impl IntoIterator<Item = Command>, choices: impl IntoIterator<Item = usize>) -> Self {
+        let entries = ScriptEntries {
+            commands: commands.into_iter().map(Ok).collect(),
+            choices: choices.into_iter().collect(),
+        };
+
+        Self {
+            script: Script(Arc::new(Mutex::new(entries))),
+            transcript: Transcript::default(),
+        }
+    }
+
+    // from_lines reads the script as commands typed by a player, one per line
+    #[cfg(test)]
+    pub fn from_lines<'a>(lines: impl IntoIterator<Item = &'a str>, choices: impl IntoIterator<Item = usize>) -> Result<Self, super::commands::HumanActionParseError> {
+        let commands = lines.into_iter()
+            .map(Command::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self::new(commands, choices))
+    }
+
+    pub fn transcript(&self) -> Transcript {
+        self.transcript.clone()
+    }
+
+    pub fn script(&self) -> Script {
+        self.script.clone()
+    }
+}
+
+#[async_trait]
+impl Controller for ScriptedController {
+    // once the script has run out, the character does nothing
+    async fn prompt_turn(&mut self) -> Result<Command, Box<dyn std::error::Error>> {
+        match self.script.pop() {
+            Some(Ok(command)) => Ok(command),
+            Some(Err(reason)) => Err(Box::new(RejectedCommandError(reason))),
+            None => Err(Box::new(ScriptFinishedError)),
+        }
+    }
+
+    async fn display_message(&mut self, message: String) -> Result<(), Box<dyn std::error::Error>> {
+        self.transcript.record(message);
+        Ok(())
+    }
+
+    async fn choose(&mut self, prompt: String, options: Vec<String>) -> Result<usize, Box<dyn std::error::Error>> {
+        let choice = self.script.pop_choice().unwrap_or(0);
+
+        let options = options.iter()
+            .enumerate()
+            .map(|(number, option)| format!("  {}. {}", number + 1, option))
+            .collect::<Vec<_>>();
+        self.transcript.record(format!("{}\n{}\n(chose {})", prompt, options.join("\n"), choice + 1));
+
+        Ok(choice)
+    }
+}
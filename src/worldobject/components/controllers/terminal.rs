@@ -1,6 +1,6 @@
 use async_trait::async_trait;
 
-use crate::worldobject::components::controllers::commands::Command;
+use crate::worldobject::components::controllers::commands::{Command, help};
 
 use super::Controller;
 
@@ -9,16 +9,40 @@ pub struct TerminalHumanController {}
 #[async_trait]
 impl Controller for TerminalHumanController {
     async fn prompt_turn(&mut self) -> Result<Command, Box<dyn std::error::Error>> {
-        println!("Enter your action:");
-        let mut input = String::new();
-        std::io::stdin().read_line(&mut input)?;
-        
-        let action = Command::try_from(input.as_str())?;
-        Ok(action)
+        // asking for help doesn't use up the turn
+        loop {
+            println!("Enter your action (or \"help\"):");
+            let mut input = String::new();
+            std::io::stdin().read_line(&mut input)?;
+
+            match help::help_request(&input) {
+                Some(help) => println!("{}", help),
+                None => return Ok(Command::try_from(input.as_str())?),
+            }
+        }
     }
 
     async fn display_message(&mut self, message: String) -> Result<(), Box<dyn std::error::Error>> {
         println!("{}", message);
         Ok(())
     }
+
+    async fn choose(&mut self, prompt: String, options: Vec<String>) -> Result<usize, Box<dyn std::error::Error>> {
+        println!("{}", prompt);
+        for (number, option) in options.iter().enumerate() {
+            println!("  {}. {}", number + 1, option);
+        }
+
+        // keep asking until the player picks one of the listed options
+        loop {
+            println!("Enter the number of your choice:");
+            let mut input = String::new();
+            std::io::stdin().read_line(&mut input)?;
+
+            match input.trim().parse::<usize>() {
+                Ok(number) if (1..=options.len()).contains(&number) => return Ok(number - 1),
+                _ => println!("Please enter a number between 1 and {}.", options.len()),
+            }
+        }
+    }
 }
\ No newline at end of file
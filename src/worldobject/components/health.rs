@@ -0,0 +1,58 @@
+use serde::Serialize;
+
+// Health is how much punishment something can take before it stops working,
+// measured in hit points.  Below half its hit points a thing is injured, and
+// with none left it is disabled: a broken limb, or a dead creature.
+#[derive(Clone, Serialize)]
+pub struct Health {
+    pub hit_points: f64,
+    pub max_hit_points: f64,
+}
+
+impl Health {
+    // new returns unhurt health with the given number of hit points
+    pub fn new(max_hit_points: f64) -> Health {
+        Health { hit_points: max_hit_points, max_hit_points }
+    }
+
+    // damage takes away hit points, down to none at all
+    pub fn damage(&mut self, hit_points: f64) {
+        self.hit_points = (self.hit_points - hit_points).max(0.0);
+    }
+
+    // the fraction of its hit points that remain, between 0 and 1
+    pub fn fraction(&self) -> f64 {
+        if self.max_hit_points > 0.0 {
+            self.hit_points / self.max_hit_points
+        } else {
+            0.0
+        }
+    }
+
+    pub fn is_injured(&self) -> bool {
+        self.fraction() < 0.5
+    }
+
+    pub fn is_disabled(&self) -> bool {
+        self.hit_points <= 0.0
+    }
+}
+
+impl TryFrom<&serde_json::Value> for Health {
+    type Error = String;
+
+    fn try_from(value: &serde_json::Value) -> Result<Self, Self::Error> {
+        let hit_points = value.get("hit_points").and_then(|v| v.as_f64()).ok_or("hit_points not found")?;
+        let max_hit_points = value.get("max_hit_points").and_then(|v| v.as_f64()).ok_or("max_hit_points not found")?;
+        Ok(Health { hit_points, max_hit_points })
+    }
+}
+
+// Vitality is whether an object is alive; death is final,
+// and objects that were never alive are inanimate
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Vitality {
+    Inanimate,
+    Alive,
+    Dead,
+}
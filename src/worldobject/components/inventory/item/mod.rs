@@ -8,12 +8,16 @@ use serde::Deserialize;
 use uuid::Uuid;
 
 use crate::{
+    combat::{Blow, BlowOutcome, DamageType},
+    materials::Material,
     world::{
-        World,
         handle::WorldObjectHandle,
+        rng::WorldRng,
+        view::WorldView,
+        perception::Perception,
     },
     worldobject::{
-        components::controllers::Controller,
+        components::{controllers::Controller, health::Vitality},
         WorldObject,
         fns::update::Action,
         Error as WorldObjectError
@@ -21,13 +25,15 @@ use crate::{
     quantities::{
         Quantity,
         mass::Mass,
-        force::Force
+        force::Force,
+        distance::Distance,
+        speed::Speed
     }
 };
 
 use super::Inventory;
 
-#[derive(Debug, Eq, PartialEq, Hash, Clone, Serialize, Deserialize)]
+#[derive(Debug, Eq, PartialEq, Hash, PartialOrd, Ord, Clone, Serialize, Deserialize)]
 pub struct InventoryItemHandle(uuid::Uuid);
 
 impl TryFrom<&str> for InventoryItemHandle {
@@ -45,8 +51,17 @@ impl std::fmt::Display for InventoryItemHandle {
 }
 
 impl InventoryItemHandle {
-    pub fn new() -> InventoryItemHandle {
-        InventoryItemHandle(uuid::Uuid::new_v4())
+    // successor returns the handle that comes after this one
+    fn successor(&self) -> InventoryItemHandle {
+        InventoryItemHandle(Uuid::from_u128(self.0.as_u128().wrapping_add(1)))
+    }
+
+    // next returns the handle after the greatest of the given handles,
+    // so that handles are handed out in order rather than at random
+    pub fn next<'a>(handles: impl Iterator<Item = &'a InventoryItemHandle>) -> InventoryItemHandle {
+        handles.max()
+            .map(InventoryItemHandle::successor)
+            .unwrap_or(InventoryItemHandle(Uuid::from_u128(1)))
     }
 }
 
@@ -57,8 +72,27 @@ impl From<InventoryItemHandle> for String {
 }
 
 pub trait InventoryItem: WorldObject {
-    fn dummy(&self) -> Box<dyn InventoryItem>;
-    fn use_item(&mut self, world: &World, user_handle: WorldObjectHandle, target_handle: Option<WorldObjectHandle>) -> Result<Action, Box<dyn std::error::Error>>;
+    // how far the item extends its wielder's reach
+    fn reach(&self) -> Quantity<Distance>;
+    // how far away a target the item can be used on
+    fn range(&self) -> Quantity<Distance>;
+    fn use_item(&mut self, world: &WorldView, user_handle: WorldObjectHandle, target_handle: Option<WorldObjectHandle>) -> Result<Action, Box<dyn std::error::Error>>;
+
+    // the ways the item can strike when wielded as a weapon, the usual way
+    // first; anything can at least be swung to bash with
+    fn damage_types(&self) -> &'static [DamageType] {
+        &[DamageType::Bash]
+    }
+
+    // how keen the item's edges and points still are, from 1 when
+    // they are new down to a floor below which they dull no further
+    fn sharpness(&self) -> f64 {
+        1.0
+    }
+
+    // dull wears down the item's edges and points after they strike
+    // something of the given hardness; items without any ignore it
+    fn dull(&mut self, _target_hardness: f64) {}
 }
 
 #[async_trait]
@@ -67,7 +101,7 @@ impl WorldObject for Box<dyn InventoryItem> {
         (**self).name()
     }
 
-    async fn update(&mut self, my_handle: WorldObjectHandle, world: &crate::world::World) -> Result<Action, WorldObjectError> {
+    async fn update(&mut self, my_handle: WorldObjectHandle, world: &crate::world::view::WorldView) -> Result<Action, WorldObjectError> {
         (**self).update(my_handle, world).await
     }
 
@@ -75,8 +109,12 @@ impl WorldObject for Box<dyn InventoryItem> {
         (**self).examine()
     }
 
-    fn dummy(&self) -> Box<dyn WorldObject> {
-        WorldObject::dummy(&**self)
+    fn type_tag(&self) -> &'static str {
+        (**self).type_tag()
+    }
+
+    fn to_json(&self) -> Result<serde_json::Value, WorldObjectError> {
+        (**self).to_json()
     }
 
     fn definite_description(&self) -> String {
@@ -107,14 +145,34 @@ impl WorldObject for Box<dyn InventoryItem> {
         (**self).inventory_mut()
     }
 
-    async fn apply_force(&mut self, force: &Quantity<Force>) -> Result<String, Box<dyn std::error::Error>> {
-        (**self).apply_force(force).await
+    async fn apply_force(&mut self, force: &Quantity<Force>, rng: &mut WorldRng) -> Result<BlowOutcome, Box<dyn std::error::Error>> {
+        (**self).apply_force(force, rng).await
+    }
+
+    async fn receive_blow(&mut self, blow: &Blow, rng: &mut WorldRng) -> Result<BlowOutcome, Box<dyn std::error::Error>> {
+        (**self).receive_blow(blow, rng).await
     }
 
     fn mass(&self) -> Quantity<Mass> {
         (**self).mass()
     }
 
+    fn perception(&self) -> Perception {
+        (**self).perception()
+    }
+
+    fn initiative(&self) -> Option<Quantity<Speed>> {
+        (**self).initiative()
+    }
+
+    fn vitality(&self) -> Vitality {
+        (**self).vitality()
+    }
+
+    fn material(&self) -> Option<&'static Material> {
+        (**self).material()
+    }
+
     async fn send_message(&mut self, message: String) -> Result<(), Box<dyn std::error::Error>> {
         (**self).send_message(message).await
     }
@@ -134,15 +192,35 @@ impl WorldObject for Box<dyn InventoryItem> {
     fn set_controller(&mut self, controller: Box<dyn Controller>) -> Result<(), (Box<dyn Controller>, Box<dyn std::error::Error>)> {
         (**self).set_controller(controller)
     }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        (**self).as_any_mut()
+    }
 }
 
 #[async_trait]
 impl InventoryItem for Box<dyn InventoryItem> {
-    fn dummy(&self) -> Box<dyn InventoryItem> {
-        InventoryItem::dummy(&(**self))
+    fn reach(&self) -> Quantity<Distance> {
+        (**self).reach()
+    }
+
+    fn range(&self) -> Quantity<Distance> {
+        (**self).range()
     }
 
-    fn use_item(&mut self, world: &World, user_handle: WorldObjectHandle, target_handle: Option<WorldObjectHandle>) -> Result<Action, Box<dyn std::error::Error>> {
+    fn use_item(&mut self, world: &WorldView, user_handle: WorldObjectHandle, target_handle: Option<WorldObjectHandle>) -> Result<Action, Box<dyn std::error::Error>> {
         (**self).use_item(world, user_handle, target_handle)
     }
+
+    fn damage_types(&self) -> &'static [DamageType] {
+        (**self).damage_types()
+    }
+
+    fn sharpness(&self) -> f64 {
+        (**self).sharpness()
+    }
+
+    fn dull(&mut self, target_hardness: f64) {
+        (**self).dull(target_hardness)
+    }
 }
\ No newline at end of file
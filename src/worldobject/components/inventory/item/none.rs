@@ -1,9 +1,13 @@
 use async_trait::async_trait;
+use serde::Serialize;
 
 use crate::{
+    combat::BlowOutcome,
     world::{
         handle::WorldObjectHandle,
-        World
+        rng::WorldRng,
+        view::WorldView,
+        perception::Perception
     },
     worldobject::{
         components::controllers::Controller,
@@ -16,7 +20,9 @@ use crate::{
     quantities::{
         Quantity,
         mass::Mass,
-        force::Force
+        force::Force,
+        distance::{Distance, meters},
+        speed::Speed
     }
 };
 
@@ -25,12 +31,13 @@ use super::{
     super::Inventory
 };
 
+#[derive(Serialize)]
 pub struct NoInventoryItem(pub none::NoWorldObject);
 
 #[async_trait]
 impl TypedWorldObject for NoInventoryItem {
-    type Dummy = Self;
     type CollectInventoryItem = Self;
+    const TYPE_TAG: &'static str = "none";
 
     fn name(&self) -> String {
         WorldObject::name(&self.0)
@@ -52,11 +59,7 @@ impl TypedWorldObject for NoInventoryItem {
         WorldObject::pronoun(&self.0)
     }
 
-    fn dummy(&self) -> Self::Dummy {
-        Self(TypedWorldObject::dummy(&self.0))
-    }
-
-    async fn update(&mut self, my_handle: WorldObjectHandle, world: &World) -> Result<Action, WorldObjectError> {
+    async fn update(&mut self, my_handle: WorldObjectHandle, world: &WorldView) -> Result<Action, WorldObjectError> {
         WorldObject::update(&mut self.0, my_handle, world).await
     }
 
@@ -76,8 +79,16 @@ impl TypedWorldObject for NoInventoryItem {
         WorldObject::mass(&self.0)
     }
 
-    async fn apply_force(&mut self, force: &Quantity<Force>) -> Result<String, WorldObjectError> {
-        WorldObject::apply_force(&mut self.0, force).await
+    fn perception(&self) -> Perception {
+        Perception::none()
+    }
+
+    fn initiative(&self) -> Option<Quantity<Speed>> {
+        None
+    }
+
+    async fn apply_force(&mut self, force: &Quantity<Force>, rng: &mut WorldRng) -> Result<BlowOutcome, WorldObjectError> {
+        WorldObject::apply_force(&mut self.0, force, rng).await
     }
     
     
@@ -118,11 +129,15 @@ impl std::fmt::Display for NoInventoryItemUseError {
 }
 
 impl InventoryItem for NoInventoryItem {
-    fn dummy(&self) -> Box<dyn InventoryItem> {
-        Box::new(NoInventoryItem(TypedWorldObject::dummy(&self.0)))
+    fn reach(&self) -> Quantity<Distance> {
+        meters(0.0)
+    }
+
+    fn range(&self) -> Quantity<Distance> {
+        meters(0.0)
     }
 
-    fn use_item(&mut self, _: &World, _: WorldObjectHandle, _: Option<WorldObjectHandle>) -> Result<Action, Box<dyn std::error::Error>> {
+    fn use_item(&mut self, _: &WorldView, _: WorldObjectHandle, _: Option<WorldObjectHandle>) -> Result<Action, Box<dyn std::error::Error>> {
         return Err(Box::new(NoInventoryItemUseError));
     }
 }
\ No newline at end of file
@@ -1,22 +1,25 @@
 use async_trait::async_trait;
+use serde::Serialize;
 
 use crate::{
+    combat::{BlowOutcome, DamageType},
     materials::Material,
     quantities::{
         Quantity,
-        mass::{
-            Mass,
-            grams
-        },
+        mass::Mass,
         force::Force,
         distance::{
             Distance,
-            meters
-        }
+            meters,
+            centimeters
+        },
+        speed::Speed
     },
     world::{
-        World,
-        handle::WorldObjectHandle
+        handle::WorldObjectHandle,
+        rng::WorldRng,
+        view::WorldView,
+        perception::Perception
     },
     worldobject::{
         Error as WorldObjectError,
@@ -35,15 +38,49 @@ use crate::{
 use std::fmt;
 use std::error;
 
+// the cross-section of a blade, in centimeters;
+// a blade is as long as the sword's reach
+const BLADE_WIDTH_CENTIMETERS: f64 = 4.0;
+const BLADE_THICKNESS_CENTIMETERS: f64 = 0.5;
+
+// however worn, an edge never gets duller than this
+const MIN_SHARPNESS: f64 = 0.2;
+
+#[derive(Serialize)]
 pub struct Sword {
     mass: Quantity<Mass>,
     reach: Quantity<Distance>,
-    material: Material
+    material: &'static Material,
+    sharpness: f64,
 }
 
 impl Sword {
-    pub fn new(reach: Quantity<Distance>, material: Material) -> Sword {
-        Sword { mass: grams(1500.0), reach, material }
+    // new forges a sword with a fresh edge, which
+    // weighs as much as its blade's worth of material
+    pub fn new(reach: Quantity<Distance>, material: &'static Material) -> Sword {
+        let blade = reach * (centimeters(BLADE_WIDTH_CENTIMETERS) * centimeters(BLADE_THICKNESS_CENTIMETERS));
+        Sword { mass: material.mass_of(&blade), reach, material, sharpness: 1.0 }
+    }
+
+    // describe_edge says how worn the sword's edge is
+    fn describe_edge(&self) -> &'static str {
+        match self.sharpness {
+            sharpness if sharpness > 0.9 => "keen",
+            sharpness if sharpness > 0.5 => "worn",
+            _ => "dull",
+        }
+    }
+}
+
+impl TryFrom<&serde_json::Value> for Sword {
+    type Error = String;
+
+    fn try_from(value: &serde_json::Value) -> Result<Self, Self::Error> {
+        let mass = Quantity::<Mass>::try_from(value.get("mass").ok_or("mass not found")?.clone()).map_err(|err| format!("failed to parse mass: {}", err))?;
+        let reach = Quantity::<Distance>::try_from(value.get("reach").ok_or("reach not found")?.clone()).map_err(|err| format!("failed to parse reach: {}", err))?;
+        let material = Material::named(value.get("material").and_then(|v| v.as_str()).ok_or("material not found")?).map_err(|err| format!("failed to parse material: {}", err))?;
+        let sharpness = value.get("sharpness").and_then(|v| v.as_f64()).ok_or("sharpness not found")?;
+        Ok(Sword { mass, reach, material, sharpness })
     }
 }
 
@@ -71,23 +108,19 @@ impl error::Error for SwordControllerError {}
 
 #[async_trait]
 impl TypedWorldObject for Sword {
-    type Dummy = Self;
     type CollectInventoryItem = Self;
+    const TYPE_TAG: &'static str = "sword";
 
     fn name(&self) -> String {
         String::from("sword")
     }
 
-    async fn update(&mut self, _: WorldObjectHandle, _: &World) -> Result<Action, WorldObjectError> {
+    async fn update(&mut self, _: WorldObjectHandle, _: &WorldView) -> Result<Action, WorldObjectError> {
         Ok(Action::no_op())
     }
 
-    fn dummy(&self) -> Self {
-        Sword { mass: self.mass.clone(), reach: self.reach.clone(), material: self.material.clone() }
-    }
-
     fn examine(&self) -> String {
-        format!("a sword forged from {} with a reach of {} meters", self.material, (&self.reach / &meters(1.0)).cancel())
+        format!("a sword made of {} with a reach of {} meters and a {} edge", self.material.describe(), (self.reach / meters(1.0)).cancel(), self.describe_edge())
     }
 
     async fn collect(self: Box<Self>) -> Result<Self, (WorldObjectError, Box<Self>)> {
@@ -110,8 +143,20 @@ impl TypedWorldObject for Sword {
         self.mass.clone()
     }
 
-    async fn apply_force(&mut self, _: &Quantity<Force>) -> Result<String, WorldObjectError> {
-        Ok(String::from("the sword bends with the force, but recovers its shape"))
+    fn perception(&self) -> Perception {
+        Perception::none()
+    }
+
+    fn initiative(&self) -> Option<Quantity<Speed>> {
+        None
+    }
+
+    fn material(&self) -> Option<&'static Material> {
+        Some(self.material)
+    }
+
+    async fn apply_force(&mut self, _: &Quantity<Force>, _: &mut WorldRng) -> Result<BlowOutcome, WorldObjectError> {
+        Ok(BlowOutcome::from(String::from("the sword bends with the force, but recovers its shape")))
     }
 
     async fn send_message(&mut self, _: String) -> Result<(), WorldObjectError> {
@@ -148,13 +193,29 @@ impl TypedWorldObject for Sword {
 }
 
 impl InventoryItem for Sword {
-    fn dummy(&self) -> Box<dyn InventoryItem> {
-        Box::new(<Sword as TypedWorldObject>::dummy(self))
+    fn reach(&self) -> Quantity<Distance> {
+        self.reach
+    }
+
+    fn range(&self) -> Quantity<Distance> {
+        self.reach
     }
 
-    fn use_item(&mut self, _: &World, _: WorldObjectHandle, _: Option<WorldObjectHandle>) -> Result<Action, Box<dyn std::error::Error>> {
+    fn use_item(&mut self, _: &WorldView, _: WorldObjectHandle, _: Option<WorldObjectHandle>) -> Result<Action, Box<dyn std::error::Error>> {
         return Err(Box::new(SwordUseError));
     }
+
+    fn damage_types(&self) -> &'static [DamageType] {
+        &[DamageType::Slash, DamageType::Stab, DamageType::Bash]
+    }
+
+    fn sharpness(&self) -> f64 {
+        self.sharpness
+    }
+
+    fn dull(&mut self, target_hardness: f64) {
+        self.sharpness = (self.sharpness - self.material.wear(target_hardness)).max(MIN_SHARPNESS);
+    }
 }
 
 #[derive(Debug)]
@@ -164,6 +225,6 @@ impl std::error::Error for SwordUseError {}
 
 impl std::fmt::Display for SwordUseError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "you can't think of anything particularly interesting to do with this sword")
+        write!(f, "swords are for fighting with; try attacking, slashing or stabbing instead")
     }
 }
@@ -1,7 +1,9 @@
 use async_trait::async_trait;
+use serde::Serialize;
 
+use crate::combat::BlowOutcome;
 use crate::lang::{VerbPhrase, TransitiveVerbPhrase, TransitiveVerb, PrepositionalVerbPhrase, PrepositionalPhrase, verbs::ToCast};
-use crate::world::{World, handle::WorldObjectHandle};
+use crate::world::{handle::WorldObjectHandle, view::WorldView, perception::Perception, rng::WorldRng};
 use crate::worldobject::{
     TypedWorldObject,
     Error as WorldObjectError,
@@ -17,11 +19,26 @@ use crate::worldobject::{
 use crate::quantities::{
     Quantity,
     mass::{Mass, grams},
-    force::Force
+    force::Force,
+    distance::{Distance, meters},
+    duration::seconds,
+    speed::Speed
 };
 
+#[derive(Serialize)]
 pub struct Wand;
 
+// how far away, in meters, a target the wand's spell can reach
+const WAND_RANGE_METERS: f64 = 5.0;
+
+impl TryFrom<&serde_json::Value> for Wand {
+    type Error = String;
+
+    fn try_from(_: &serde_json::Value) -> Result<Self, Self::Error> {
+        Ok(Wand)
+    }
+}
+
 #[derive(Debug)]
 pub struct WandInventoryError;
 
@@ -46,8 +63,8 @@ impl std::error::Error for WandControllerError {}
 
 #[async_trait]
 impl TypedWorldObject for Wand {
-    type Dummy = Self;
     type CollectInventoryItem = Self;
+    const TYPE_TAG: &'static str = "wand";
 
     fn name(&self) -> String {
         String::from("wand")
@@ -69,10 +86,6 @@ impl TypedWorldObject for Wand {
         String::from("it")
     }
 
-    fn dummy(&self) -> Self {
-        Wand {}
-    }
-
     fn inventory(&self) -> Result<&Inventory, WorldObjectError> {
         Err(Box::new(WandInventoryError))
     }
@@ -85,8 +98,16 @@ impl TypedWorldObject for Wand {
         grams(100.0)
     }
 
-    async fn apply_force(&mut self, _: &Quantity<Force>) -> Result<String, WorldObjectError> {
-        Ok(String::from("the wand resists the force with incredible strength"))
+    fn perception(&self) -> Perception {
+        Perception::none()
+    }
+
+    fn initiative(&self) -> Option<Quantity<Speed>> {
+        None
+    }
+
+    async fn apply_force(&mut self, _: &Quantity<Force>, _: &mut WorldRng) -> Result<BlowOutcome, WorldObjectError> {
+        Ok(BlowOutcome::from(String::from("the wand resists the force with incredible strength")))
     }
 
     async fn send_message(&mut self, _: String) -> Result<(), WorldObjectError> {
@@ -97,7 +118,7 @@ impl TypedWorldObject for Wand {
         Ok(String::from("you can't think of anything particularly interesting to do with this."))
     }
 
-    async fn update(&mut self, target_handle: WorldObjectHandle, world: &World) -> Result<Action, WorldObjectError> {
+    async fn update(&mut self, _: WorldObjectHandle, _: &WorldView) -> Result<Action, WorldObjectError> {
         Ok(Action::no_op())
     }
 
@@ -146,52 +167,58 @@ impl std::fmt::Display for WandUseError {
 impl std::error::Error for WandUseError {}
 
 impl InventoryItem for Wand {
-    fn dummy(&self) -> Box<dyn InventoryItem> {
-        Box::new(<Wand as TypedWorldObject>::dummy(self))
+    fn reach(&self) -> Quantity<Distance> {
+        meters(0.0)
     }
 
-    fn use_item(&mut self, world: &World, user_handle: WorldObjectHandle, target_handle: Option<WorldObjectHandle>) -> Result<Action, Box<dyn std::error::Error>> {
+    fn range(&self) -> Quantity<Distance> {
+        meters(WAND_RANGE_METERS)
+    }
+
+    fn use_item(&mut self, world: &WorldView, user_handle: WorldObjectHandle, target_handle: Option<WorldObjectHandle>) -> Result<Action, Box<dyn std::error::Error>> {
         let target_description = target_handle.as_ref().ok_or(WandUseError::NoTargetProvided)
-            .and_then(|handle| world.get_object(&handle)
+            .and_then(|handle| world.get_object(handle)
                 .map_err(|err| WandUseError::FailedToGetTarget(Box::new(err))))
             .map(|object| object.definite_description())?;
 
 
         Ok(Action{
             exec: Box::new(move |world| {
-                let user_handle = user_handle.clone();
-                let target_handle = target_handle.clone();
                 Box::pin(async move {
-                    let target_handle = target_handle
-                        .ok_or::<Box<dyn std::error::Error>>(Box::new(WandUseError::NoTargetProvided).into())?;
+                    let target_handle = target_handle.ok_or(WandUseError::NoTargetProvided)?;
+
+                    // the target is checked for a controller before the user's
+                    // is taken, so that a spell cast on, say, a sword leaves
+                    // the user in control of themselves
+                    world.get_object(&target_handle)
+                        .map_err(|err| WandUseError::FailedToGetTarget(Box::new(err)))?
+                        .controller()
+                        .map_err(WandUseError::FailedToTakeController)?;
 
                     let user_controller = world.get_object_mut(&user_handle)
-                        .map_err(|err| -> Box<dyn std::error::Error> { 
-                            Box::new(WandUseError::FailedToGetUser(Box::new(err))).into()
-                        })?.take_controller()?;
+                        .map_err(|err| WandUseError::FailedToGetUser(Box::new(err)))?
+                        .take_controller()
+                        .map_err(WandUseError::FailedToTakeController)?;
 
                     let target_controller = world.get_object_mut(&target_handle)
-                        .map_err(|err| -> Box<dyn std::error::Error> {
-                            Box::new(WandUseError::FailedToGetTarget(Box::new(err)))
-                        })?.take_controller()?;
+                        .map_err(|err| WandUseError::FailedToGetTarget(Box::new(err)))?
+                        .take_controller()
+                        .map_err(WandUseError::FailedToTakeController)?;
 
                     world.get_object_mut(&target_handle)
-                        .map_err(|err| -> Box<dyn std::error::Error> {
-                            Box::new(WandUseError::FailedToGetTarget(Box::new(err))).into()
-                        })?.set_controller(user_controller).map_err(
-                            |(_, err)| err
-                        )?;
-                    
+                        .map_err(|err| WandUseError::FailedToGetTarget(Box::new(err)))?
+                        .set_controller(user_controller)
+                        .map_err(|(_, err)| WandUseError::FailedToSetController(err))?;
+
                     world.get_object_mut(&user_handle)
-                        .map_err(|err| -> Box<dyn std::error::Error> {
-                            Box::new(WandUseError::FailedToGetUser(Box::new(err))).into()
-                        })?.set_controller(target_controller).map_err(
-                            |(_, err)| err
-                        )?;
+                        .map_err(|err| WandUseError::FailedToGetUser(Box::new(err)))?
+                        .set_controller(target_controller)
+                        .map_err(|(_, err)| WandUseError::FailedToSetController(err))?;
 
                     Ok(None)
                 })
             }),
+            cost: seconds(1.0),
             verb_phrase: VerbPhrase::Prepositional(
                 PrepositionalVerbPhrase {
                     main_verb_phrase: Box::new(
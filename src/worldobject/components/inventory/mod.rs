@@ -1,41 +1,85 @@
 pub mod item;
 
-use std::collections::HashMap;
+use std::collections::BTreeMap;
+
+use serde::ser::{Error as _, SerializeMap};
 
 use item::{
     InventoryItem,
     InventoryItemHandle
 };
 
+use crate::worldobject::registry;
 
-pub struct Inventory(pub HashMap<item::InventoryItemHandle, Box<dyn InventoryItem>>);
+// Inventories are serialized as a map from item handle to item,
+// where each item is tagged with its concrete type so that it
+// can be reconstructed via the registry.  Items are kept in the
+// order of their handles, which are handed out in sequence, so an
+// inventory always lists its items in the order they were put in.
+pub struct Inventory(pub BTreeMap<item::InventoryItemHandle, Box<dyn InventoryItem>>);
 
 impl<'de> serde::Deserialize<'de> for Inventory {
-    fn deserialize<D: serde::Deserializer<'de>>(_: D) -> Result<Self, D::Error> {
-        let inventory = Inventory(HashMap::new());
-        Ok(inventory)
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = <serde_json::Value as serde::Deserialize>::deserialize(deserializer)?;
+        Inventory::try_from(&value).map_err(serde::de::Error::custom)
     }
 }
 
 impl serde::Serialize for Inventory {
     fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        serializer.serialize_none()
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (handle, item) in &self.0 {
+            let item_json = registry::inventory_item_to_json(item.as_ref())
+                .map_err(S::Error::custom)?;
+            map.serialize_entry(&handle.to_string(), &item_json)?;
+        }
+        map.end()
+    }
+}
+
+impl TryFrom<&serde_json::Value> for Inventory {
+    type Error = String;
+
+    fn try_from(value: &serde_json::Value) -> Result<Self, Self::Error> {
+        // older clients serialized inventories as null
+        if value.is_null() {
+            return Ok(Inventory::new());
+        }
+
+        let items = value.as_object().ok_or("inventory is not a map")?;
+
+        items.iter().map(|(handle, item)| {
+            let handle = InventoryItemHandle::try_from(handle.as_str())
+                .map_err(|err| format!("failed to parse item handle \"{}\": {}", handle, err))?;
+            let item = registry::inventory_item_from_json(item)
+                .map_err(|err| format!("failed to parse item \"{}\": {}", handle, err))?;
+            Ok((handle, item))
+        }).collect::<Result<BTreeMap<_, _>, String>>().map(Inventory)
     }
 }
 
 impl Inventory {
     pub fn new() -> Inventory {
-        Inventory(HashMap::new())
+        Inventory(BTreeMap::new())
     }
 
     pub fn give<Item: InventoryItem + 'static>(&mut self, item: Item) -> InventoryItemHandle {
-        let handle = InventoryItemHandle::new();
+        let handle = InventoryItemHandle::next(self.0.keys());
 
         self.0.insert(handle.clone(), Box::new(item));
 
         handle
     }
 
+    // stow puts an item that is already boxed, e.g. one taken out of a hand, into the inventory
+    pub fn stow(&mut self, item: Box<dyn InventoryItem>) -> InventoryItemHandle {
+        let handle = InventoryItemHandle::next(self.0.keys());
+
+        self.0.insert(handle.clone(), item);
+
+        handle
+    }
+
     pub fn get(&self, handle: &InventoryItemHandle) -> Option<&Box<dyn InventoryItem>> {
         self.0.get(handle)
     }
@@ -47,10 +91,42 @@ impl Inventory {
     pub fn take(&mut self, handle: &InventoryItemHandle) -> Option<Box<dyn InventoryItem>> {
         self.0.remove(handle)
     }
+}
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::{
+        tests::fixtures::sword,
+        worldobject::components::inventory::item::wand::Wand
+    };
+
+    use super::Inventory;
+
+    #[test]
+    fn items_keep_their_handles_through_a_round_trip() {
+        let mut inventory = Inventory::new();
+        inventory.give(sword("steel"));
+        let wand = inventory.give(Wand);
+        inventory.take(&wand);
+        inventory.give(Wand);
+
+        let value = serde_json::to_value(&inventory).unwrap();
+        let loaded = Inventory::try_from(&value).unwrap();
 
-    pub fn dummy(&self) -> Self {
-        Self(self.0.iter().map(
-            |(handle, item)| (handle.clone(), item.dummy())
-        ).collect())
+        assert_eq!(loaded.0.keys().collect::<Vec<_>>(), inventory.0.keys().collect::<Vec<_>>());
+        assert_eq!(serde_json::to_value(&loaded).unwrap(), value);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn a_null_inventory_is_empty() {
+        assert!(Inventory::try_from(&json!(null)).unwrap().0.is_empty());
+    }
+
+    #[test]
+    fn rejects_malformed_inventories() {
+        assert!(Inventory::try_from(&json!([])).is_err());
+        assert!(Inventory::try_from(&json!({ "not a handle": { "type": "wand", "data": null } })).is_err());
+        assert!(Inventory::try_from(&json!({ "00000000-0000-0000-0000-000000000001": { "type": "dragon", "data": {} } })).is_err());
+    }
+}
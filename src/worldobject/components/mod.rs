@@ -1,4 +1,5 @@
 pub mod inventory;
 pub mod controllers;
 pub mod gender;
-pub mod body;
\ No newline at end of file
+pub mod body;
+pub mod health;
\ No newline at end of file
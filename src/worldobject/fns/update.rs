@@ -5,7 +5,11 @@ use crate::{
     lang::TransitiveVerbPhrase,
     lang::TransitiveVerb,
     lang::verbs::ToDo,
-    world::{World}
+    world::{World},
+    quantities::{
+        Quantity,
+        duration::{Duration, seconds}
+    }
 };
 
 use super::Error;
@@ -14,7 +18,10 @@ pub struct Action {
     pub exec: Box<dyn FnOnce(&mut World) -> BoxFuture<
         Result<Option<String>, Error>
     >>,
-    pub verb_phrase: VerbPhrase
+    pub verb_phrase: VerbPhrase,
+    // how much game time the action takes; the actor
+    // won't act again until this much time has passed
+    pub cost: Quantity<Duration>
 }
 
 impl Action {
@@ -30,7 +37,20 @@ impl Action {
                     verb: TransitiveVerb::new(ToDo),
                     direct_object: String::from("nothing"),
                 }
-            )
+            ),
+            cost: Self::idle_cost()
         }
     }
+
+    // how long an actor waits when it does nothing, or when
+    // the action it chose could not be carried out
+    pub fn idle_cost() -> Quantity<Duration> {
+        seconds(1.0)
+    }
+
+    // the least time any action takes, however quick it is
+    // made out to be, so that the clock always moves on
+    pub fn min_cost() -> Quantity<Duration> {
+        seconds(0.1)
+    }
 }
\ No newline at end of file
@@ -1,23 +1,33 @@
 use crate::{
-    lang::{VerbPhrase, TransitiveVerbPhrase, TransitiveVerb, verbs::ToAttack},
+    combat::{self, DamageType},
+    lang::{VerbPhrase, TransitiveVerbPhrase, TransitiveVerb, verbs::{ToSlash, ToStab, ToBash, ToPunch, ToBite}},
     worldobject::{
         fns::update::Action,
         human::Human,
-        components::controllers::commands::attack_command::AttackCommand
+        components::body::torso::arm::Arm,
+        components::body::BodyPart
     },
-    world::{World, WorldObjectGetError},
-    quantities::direction::{DirectionHorizontal}
+    world::{World, WorldObjectGetError, handle::{WorldObjectHandle, WorldObjectLabel}, view::WorldView, range::RangeError},
+    quantities::duration::seconds
 };
 
 #[derive(Debug)]
 pub enum AttackCommandToActionError {
     FailedToGetTargetObject(WorldObjectGetError),
+    TargetOutOfRange(RangeError),
+    NothingToStrikeWith(DamageType),
+    CannotStrikeWith(DamageType, String),
+    NoArmToStrikeWith,
 }
 
 impl std::fmt::Display for AttackCommandToActionError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::FailedToGetTargetObject(err) => write!(f, "failed to get target object: {}", err),
+            Self::TargetOutOfRange(err) => write!(f, "target out of reach: {}", err),
+            Self::NothingToStrikeWith(damage_type) => write!(f, "you are not wielding anything to {} with", damage_type),
+            Self::CannotStrikeWith(damage_type, weapon) => write!(f, "you can't {} with {}", damage_type, weapon),
+            Self::NoArmToStrikeWith => write!(f, "you have no arm fit to strike with"),
         }
     }
 }
@@ -39,38 +49,80 @@ impl std::fmt::Display for AttackError {
 
 impl std::error::Error for AttackError {}
 
-pub fn from_command(cmd: AttackCommand, world: &World, me: Human) -> Result<Action, AttackCommandToActionError> {
-    let target_description = world.get_object(&cmd.target_handle)
-        .map(|object| object.definite_description())
+// the verb for striking a blow of the given type, with or without a weapon
+fn striking_verb(damage_type: DamageType, armed: bool) -> TransitiveVerb {
+    match (damage_type, armed) {
+        (DamageType::Slash, _) => TransitiveVerb::new(ToSlash),
+        (DamageType::Stab, _) => TransitiveVerb::new(ToStab),
+        (DamageType::Bash, true) => TransitiveVerb::new(ToBash),
+        (DamageType::Bash, false) => TransitiveVerb::new(ToPunch),
+        (DamageType::Bite, _) => TransitiveVerb::new(ToBite),
+    }
+}
+
+// from_command builds an attack on the target; the blow is struck the given
+// way, or the usual way for whatever the attacking arm wields, or with a
+// bare fist if it wields nothing, and lands on the given body part, if any
+pub fn from_command(target: WorldObjectLabel, aim: Option<BodyPart>, damage_type: Option<DamageType>, world: &WorldView, my_handle: WorldObjectHandle, me: &mut Human) -> Result<Action, AttackCommandToActionError> {
+    let target_handle = world.find_object(&target)
+        .map_err(AttackCommandToActionError::FailedToGetTargetObject)?;
+
+    let (target_description, target_hardness) = world.get_object(&target_handle)
+        .map(|object| (object.definite_description(), combat::hardness(object)))
         .map_err(|err| AttackCommandToActionError::FailedToGetTargetObject(err))?;
 
+    let side = me.attacking_side()
+        .ok_or(AttackCommandToActionError::NoArmToStrikeWith)?;
+    let arm = me.arm(side)
+        .ok_or(AttackCommandToActionError::NoArmToStrikeWith)?;
+
+    world.check_range(&my_handle, &target_handle, &arm.armed_reach())
+        .map_err(AttackCommandToActionError::TargetOutOfRange)?;
+
+    let target_description = match aim {
+        Some(part) => format!("{} in the {}", target_description, part),
+        None => target_description,
+    };
+    let (blow, direct_object) = match arm.wielded_item() {
+        Some(weapon) => {
+            let damage_type = damage_type
+                .or(weapon.damage_types().first().copied())
+                .unwrap_or(DamageType::Bash);
+            if !weapon.damage_types().contains(&damage_type) {
+                return Err(AttackCommandToActionError::CannotStrikeWith(damage_type, weapon.indefinite_description()));
+            }
+
+            (
+                combat::swing(&arm.punch_force, &arm.reach, weapon, damage_type, aim, target_hardness),
+                format!("{} with {}", target_description, weapon.indefinite_description())
+            )
+        },
+        None => match damage_type {
+            None | Some(DamageType::Bash) => (combat::punch(&arm.punch_force, aim), target_description),
+            Some(damage_type) => return Err(AttackCommandToActionError::NothingToStrikeWith(damage_type)),
+        },
+    };
+    let verb = striking_verb(blow.damage_type, arm.wielded_item().is_some());
+
+    // every blow struck with an edge or point wears it down a little
+    if blow.damage_type.dulls() {
+        if let Some(weapon) = me.arm_mut(side).and_then(Arm::wielded_item_mut) {
+            weapon.dull(target_hardness);
+        }
+    }
+
     Ok(Action{
         exec: Box::new(
             move |world: &mut World| {
-                Box::pin(async move {
-                    let arm = match me.dominant_arm {
-                        DirectionHorizontal::Left => &me.body.torso.left_arm,
-                        DirectionHorizontal::Right => &me.body.torso.right_arm,
-                    };
-                
-                    let punch_force = arm.punch_force.clone();
-                    
-                    let object = world.get_object_mut(&cmd.target_handle)
-                        .map_err(|err| Box::new(err))?;
-                
-                    let msg = object.apply_force(&punch_force).await
-                        .unwrap_or_else(|err| format!("failed to apply force: {}", err));
-                
-                    Ok(Some(msg))
-                })
+                Box::pin(combat::strike(world, my_handle, target_handle, blow))
             }
         ),
+        cost: seconds(1.0),
         verb_phrase: VerbPhrase::Transitive(
             TransitiveVerbPhrase {
-                verb: TransitiveVerb::new(ToAttack),
-                direct_object: target_description
+                verb,
+                direct_object
             }
         )
     })
 }
-
@@ -0,0 +1,73 @@
+use crate::{
+    combat,
+    lang::{VerbPhrase, TransitiveVerbPhrase, TransitiveVerb, verbs::ToBite},
+    worldobject::{
+        fns::update::Action,
+        human::Human,
+        components::body::BodyPart
+    },
+    world::{World, WorldObjectGetError, handle::{WorldObjectHandle, WorldObjectLabel}, view::WorldView, range::RangeError},
+    quantities::{duration::seconds, distance::meters}
+};
+
+// how far, in meters, a human can lean in to bite something
+const BITE_REACH_METERS: f64 = 0.5;
+
+#[derive(Debug)]
+pub enum BiteCommandToActionError {
+    FailedToGetTargetObject(WorldObjectGetError),
+    TargetOutOfRange(RangeError),
+    NoTeeth,
+}
+
+impl std::fmt::Display for BiteCommandToActionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::FailedToGetTargetObject(err) => write!(f, "failed to get target object: {}", err),
+            Self::TargetOutOfRange(err) => write!(f, "target out of reach: {}", err),
+            Self::NoTeeth => write!(f, "you have no teeth to bite with"),
+        }
+    }
+}
+
+impl std::error::Error for BiteCommandToActionError {}
+
+// from_command builds a bite at the target, which lands
+// on the given body part, if any
+pub fn from_command(target: WorldObjectLabel, aim: Option<BodyPart>, world: &WorldView, my_handle: WorldObjectHandle, me: &Human) -> Result<Action, BiteCommandToActionError> {
+    let teeth = &me.body.head.mouth.teeth;
+    if teeth.is_empty() {
+        return Err(BiteCommandToActionError::NoTeeth);
+    }
+
+    let target_handle = world.find_object(&target)
+        .map_err(BiteCommandToActionError::FailedToGetTargetObject)?;
+
+    let target_description = world.get_object(&target_handle)
+        .map(|object| object.definite_description())
+        .map_err(BiteCommandToActionError::FailedToGetTargetObject)?;
+
+    world.check_range(&my_handle, &target_handle, &meters(BITE_REACH_METERS))
+        .map_err(BiteCommandToActionError::TargetOutOfRange)?;
+
+    let direct_object = match aim {
+        Some(part) => format!("{} in the {}", target_description, part),
+        None => target_description,
+    };
+    let blow = combat::bite(teeth, aim);
+
+    Ok(Action{
+        exec: Box::new(
+            move |world: &mut World| {
+                Box::pin(combat::strike(world, my_handle, target_handle, blow))
+            }
+        ),
+        cost: seconds(1.0),
+        verb_phrase: VerbPhrase::Transitive(
+            TransitiveVerbPhrase {
+                verb: TransitiveVerb::new(ToBite),
+                direct_object
+            }
+        )
+    })
+}
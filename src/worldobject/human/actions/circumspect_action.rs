@@ -1,16 +1,25 @@
 use crate::{
     lang::{VerbPhrase, IntransitiveVerb, verbs::ToCircumspect},
-    world::World,
-    worldobject::fns::update::Action
+    world::{World, handle::WorldObjectHandle},
+    worldobject::fns::update::Action,
+    quantities::duration::seconds
 };
 
-pub fn action() -> Action {
+pub fn action(my_handle: WorldObjectHandle) -> Action {
     Action{
         exec: Box::new(
             move |world: &mut World| {
                 Box::pin(async move {
-                    let handles_and_descriptions = world.objects.iter()
-                        .map(|(handle, object)| (handle, format!("{}: {}", handle, object.1.indefinite_description())))
+                    // only objects within sight are listed, along with where they are
+                    let my_coord = world.locate_object(&my_handle)
+                        .map_err(Box::new)?;
+
+                    // objects are listed by label, which is how players refer to them
+                    let handles_and_descriptions = world.visible_objects(&my_handle).into_iter()
+                        .map(|(handle, coord, object)| {
+                            let label = world.label_of(handle).map(String::as_str).unwrap_or("?");
+                            (handle, format!("{}: {}, {}", label, object.indefinite_description(), coord.describe_relative_to(&my_coord)))
+                        })
                         .collect::<Vec<_>>();
 
                     Ok(Some(if handles_and_descriptions.is_empty() {
@@ -21,6 +30,7 @@ pub fn action() -> Action {
                 })
             }
         ),
+        cost: seconds(3.0),
         verb_phrase: VerbPhrase::Intransitive(
             IntransitiveVerb::new(ToCircumspect)
         )
@@ -1,40 +1,50 @@
 use crate::{
     lang::{VerbPhrase, TransitiveVerbPhrase, TransitiveVerb, verbs::ToCollect},
-    world::{handle::WorldObjectHandle, World, WorldObjectGetError},
+    world::{handle::WorldObjectHandle, view::WorldView, World, WorldObjectGetError, range::RangeError},
     worldobject::{
         fns::update::Action,
+        human::Human,
         components::controllers::commands::collect_command::CollectCommand
-    }
+    },
+    quantities::duration::seconds
 };
 
 #[derive(Debug)]
 pub enum CollectCommandToActionError {
     FailedToGetTargetObject(WorldObjectGetError),
+    TargetOutOfRange(RangeError),
 }
 
 impl std::fmt::Display for CollectCommandToActionError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::FailedToGetTargetObject(err) => write!(f, "failed to get target object: {}", err),
+            Self::TargetOutOfRange(err) => write!(f, "target out of reach: {}", err),
         }
     }
 }
 
 impl std::error::Error for CollectCommandToActionError {}
 
-pub fn from_command(cmd: CollectCommand, world: &World, my_handle: WorldObjectHandle) -> Result<Action, CollectCommandToActionError> {
+pub fn from_command(cmd: CollectCommand, world: &WorldView, my_handle: WorldObjectHandle, me: &Human) -> Result<Action, CollectCommandToActionError> {
+    let target_handle = world.find_object(&cmd.target)
+        .map_err(CollectCommandToActionError::FailedToGetTargetObject)?;
+
+    world.check_range(&my_handle, &target_handle, &me.grasp_reach())
+        .map_err(CollectCommandToActionError::TargetOutOfRange)?;
+
     Ok(Action{
         exec: {
-            let target_handle = cmd.target_handle.clone();
             Box::new(
                 move |world: &mut World| {
                     Box::pin(async move {
                         let location = world.locate_object(&target_handle)?;
+                        let label = world.label_of(&target_handle)?.clone();
                         let object = world.take_object(&target_handle)?;
                     
                         let inventory_item = object.collect().await
                             .or_else(|(err, og_object)| {
-                                world.add_object(target_handle, og_object, location);
+                                world.restore_object(target_handle, label, og_object, location);
                                 Err(err)
                         })?;
                     
@@ -46,10 +56,11 @@ pub fn from_command(cmd: CollectCommand, world: &World, my_handle: WorldObjectHa
                 }
             )
         },
+        cost: seconds(2.0),
         verb_phrase: VerbPhrase::Transitive(
             TransitiveVerbPhrase {
                 verb: TransitiveVerb::new(ToCollect),
-                direct_object: world.get_object(&cmd.target_handle)
+                direct_object: world.get_object(&target_handle)
                     .map(|object| object.definite_description())
                     .map_err(|err| CollectCommandToActionError::FailedToGetTargetObject(err))?
             }
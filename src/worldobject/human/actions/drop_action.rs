@@ -0,0 +1,115 @@
+use futures::future::BoxFuture;
+
+use crate::{
+    lang::{TransitiveVerb, TransitiveVerbPhrase, VerbPhrase, verbs::ToDrop},
+    world::{World, handle::WorldObjectHandle},
+    worldobject::{
+        Error as WorldObjectError, WorldObject,
+        components::{
+            controllers::commands::drop_command::DropCommand,
+            inventory::item::InventoryItemHandle
+        },
+        fns::update::Action, human::Human
+    },
+    quantities::duration::seconds,
+};
+
+#[derive(Debug)]
+pub enum DropCommandToActionError {
+    NoSuchItem(String),
+}
+
+impl std::fmt::Display for DropCommandToActionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NoSuchItem(item) => write!(f, "you are neither carrying nor wielding item: {}", item),
+        }
+    }
+}
+
+impl std::error::Error for DropCommandToActionError {}
+
+pub fn from_command(me: &mut Human, my_handle: WorldObjectHandle, cmd: DropCommand) -> Result<Action, DropCommandToActionError> {
+    // the item is either in the inventory, keyed by its handle, or in a hand, keyed by its name
+    let item = match InventoryItemHandle::try_from(cmd.item.as_str()) {
+        Ok(item_handle) => me.inventory.take(&item_handle),
+        Err(_) => me.wielding_side(&cmd.item).and_then(|side| me.arm_mut(side)).and_then(|arm| arm.unwield()),
+    }.ok_or(DropCommandToActionError::NoSuchItem(cmd.item))?;
+    let item_description = item.indefinite_description();
+
+    Ok(Action{
+        exec: Box::new(
+            move |world: &mut World| -> BoxFuture<Result<Option<String>, WorldObjectError>> {
+                Box::pin(async move {
+                    let location = world.locate_object(&my_handle)?;
+                    let object: Box<dyn WorldObject> = item;
+                    world.add_object(object, location);
+                    Ok(None)
+                })
+            }
+        ),
+        cost: seconds(1.0),
+        verb_phrase: VerbPhrase::Transitive(
+            TransitiveVerbPhrase {
+                verb: TransitiveVerb::new(ToDrop),
+                direct_object: item_description
+            }
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        logging::noop::NoopLogger,
+        quantities::{direction::DirectionHorizontal, distance::meters},
+        tests::fixtures::{alice, sword},
+        world::{World, coord::WorldCoord, handle::WorldObjectHandle},
+        worldobject::{
+            components::{controllers::commands::drop_command::DropCommand, inventory::item::wand::Wand},
+            human::Human
+        }
+    };
+
+    use super::{DropCommandToActionError, from_command};
+
+    // drop has the human in the world drop the item
+    async fn drop(world: &mut World, handle: WorldObjectHandle, item: String) -> Result<(), DropCommandToActionError> {
+        // the human is taken out of the world while deciding, as on its turn
+        let (coord, mut object) = world.objects.remove(&handle).unwrap();
+        let action = from_command(object.as_any_mut().downcast_mut::<Human>().unwrap(), handle, DropCommand { item });
+        world.objects.insert(handle, (coord, object));
+
+        action?.call(world).await.unwrap();
+        Ok(())
+    }
+
+    // where the object with the given label lies, if anywhere
+    fn location_of(world: &World, label: &str) -> Option<WorldCoord> {
+        world.find_object(label).and_then(|handle| world.locate_object(&handle)).ok()
+    }
+
+    #[tokio::test]
+    async fn drops_a_carried_item_where_the_human_stands() {
+        let mut world = World::with_seed(NoopLogger::new(), 1);
+        let mut human = alice();
+        let sword = human.inventory.give(sword("steel"));
+        let handle = world.add_object(Box::new(human), WorldCoord::new(meters(2.0), meters(0.0)));
+
+        drop(&mut world, handle, sword.to_string()).await.unwrap();
+        assert_eq!(location_of(&world, "sword").unwrap().x, meters(2.0));
+        assert!(world.get_object(&handle).unwrap().inventory().unwrap().0.is_empty());
+    }
+
+    #[tokio::test]
+    async fn drops_a_wielded_item_by_name() {
+        let mut world = World::with_seed(NoopLogger::new(), 1);
+        let mut human = alice();
+        assert!(human.arm_mut(DirectionHorizontal::Left).unwrap().wield(Box::new(Wand)).is_ok());
+        let handle = world.add_object(Box::new(human), WorldCoord::new(meters(1.0), meters(0.0)));
+
+        drop(&mut world, handle, String::from("wand")).await.unwrap();
+        assert_eq!(location_of(&world, "wand").unwrap().x, meters(1.0));
+        assert!(matches!(drop(&mut world, handle, String::from("wand")).await, Err(DropCommandToActionError::NoSuchItem(_))));
+    }
+}
@@ -2,12 +2,13 @@ use futures::future::BoxFuture;
 
 use crate::{
     lang::{VerbPhrase, TransitiveVerbPhrase, TransitiveVerb, verbs::ToExamine},
-    world::{World, WorldObjectGetError},
+    world::{World, WorldObjectGetError, view::WorldView},
     worldobject::{
         Error as WorldObjectError,
         components::controllers::commands::examine_command::ExamineCommand,
         fns::update::Action
-    }
+    },
+    quantities::duration::seconds
 };
 
 #[derive(Debug)]
@@ -25,8 +26,11 @@ impl std::fmt::Display for ExamineCommandToActionError {
 
 impl std::error::Error for ExamineCommandToActionError {}
 
-pub fn from_command(cmd: ExamineCommand, world: &World) -> Result<Action, ExamineCommandToActionError> {
-    let target_description = world.get_object(&cmd.target_handle)
+pub fn from_command(cmd: ExamineCommand, world: &WorldView) -> Result<Action, ExamineCommandToActionError> {
+    let target_handle = world.find_object(&cmd.target)
+        .map_err(ExamineCommandToActionError::FailedToGetTargetObject)?;
+
+    let target_description = world.get_object(&target_handle)
         .map(|object| object.definite_description())
         .map_err(|err| ExamineCommandToActionError::FailedToGetTargetObject(err))?;
 
@@ -34,12 +38,13 @@ pub fn from_command(cmd: ExamineCommand, world: &World) -> Result<Action, Examin
         exec: Box::new(
             move |world: &mut World| -> BoxFuture<Result<Option<String>, WorldObjectError>> {
                 Box::pin(async move {
-                    let object = world.get_object(&cmd.target_handle)?;
+                    let object = world.get_object(&target_handle)?;
                     let msg = format!("you see {}", object.examine());
                     Ok(Some(msg))
                 })
             }
         ),
+        cost: seconds(2.0),
         verb_phrase: VerbPhrase::Transitive(
             TransitiveVerbPhrase {
                 verb: TransitiveVerb::new(ToExamine),
@@ -0,0 +1,85 @@
+use futures::future::BoxFuture;
+
+use crate::{
+    lang::{TransitiveVerb, TransitiveVerbPhrase, VerbPhrase, verbs::ToGive},
+    world::{World, WorldObjectGetError, handle::WorldObjectHandle, view::WorldView, range::RangeError},
+    worldobject::{
+        Error as WorldObjectError,
+        components::{
+            controllers::commands::give_command::GiveCommand,
+            inventory::item::InventoryItemHandle
+        },
+        fns::update::Action, human::Human
+    },
+    quantities::duration::seconds,
+};
+
+#[derive(Debug)]
+pub enum GiveCommandToActionError {
+    NoSuchItem(String),
+    FailedToGetRecipient(WorldObjectGetError),
+    RecipientOutOfRange(RangeError),
+    CannotGiveToSelf,
+}
+
+impl std::fmt::Display for GiveCommandToActionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NoSuchItem(item) => write!(f, "inventory does not contain item: {}", item),
+            Self::FailedToGetRecipient(err) => write!(f, "failed to get recipient: {}", err),
+            Self::RecipientOutOfRange(err) => write!(f, "recipient out of reach: {}", err),
+            Self::CannotGiveToSelf => write!(f, "you already have it"),
+        }
+    }
+}
+
+impl std::error::Error for GiveCommandToActionError {}
+
+pub fn from_command(me: &Human, my_handle: WorldObjectHandle, cmd: GiveCommand, world: &WorldView) -> Result<Action, GiveCommandToActionError> {
+    let item_handle = InventoryItemHandle::try_from(cmd.item.as_str()).ok()
+        .filter(|item_handle| me.inventory.get(item_handle).is_some())
+        .ok_or(GiveCommandToActionError::NoSuchItem(cmd.item))?;
+    let item_description = me.inventory.get(&item_handle)
+        .map(|item| item.indefinite_description())
+        .unwrap_or_default();
+
+    let recipient_handle = world.find_object(&cmd.recipient)
+        .map_err(GiveCommandToActionError::FailedToGetRecipient)?;
+    if recipient_handle == my_handle {
+        return Err(GiveCommandToActionError::CannotGiveToSelf);
+    }
+
+    world.check_range(&my_handle, &recipient_handle, &me.grasp_reach())
+        .map_err(GiveCommandToActionError::RecipientOutOfRange)?;
+
+    let recipient_description = world.get_object(&recipient_handle)
+        .map(|recipient| recipient.definite_description())
+        .map_err(GiveCommandToActionError::FailedToGetRecipient)?;
+
+    let given_description = item_description.clone();
+
+    Ok(Action{
+        exec: Box::new(
+            move |world: &mut World| -> BoxFuture<Result<Option<String>, WorldObjectError>> {
+                Box::pin(async move {
+                    world.exchange_items(&my_handle, &item_handle, &recipient_handle, None)
+                        .map_err(Box::new)?;
+                    world.address(recipient_handle, VerbPhrase::Transitive(
+                        TransitiveVerbPhrase {
+                            verb: TransitiveVerb::new(ToGive),
+                            direct_object: format!("you {}", given_description)
+                        }
+                    ));
+                    Ok(None)
+                })
+            }
+        ),
+        cost: seconds(2.0),
+        verb_phrase: VerbPhrase::Transitive(
+            TransitiveVerbPhrase {
+                verb: TransitiveVerb::new(ToGive),
+                direct_object: format!("{} to {}", item_description, recipient_description)
+            }
+        )
+    })
+}
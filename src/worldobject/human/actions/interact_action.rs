@@ -1,34 +1,43 @@
 use futures::future::BoxFuture;
 
 use crate::{
-    world::{World, WorldObjectGetError},
+    world::{World, WorldObjectGetError, handle::WorldObjectHandle, view::WorldView, range::RangeError},
     worldobject::{
         Error as WorldObjectError,
+        human::Human,
         components::controllers::commands::interact_action::InteractAction,
         fns::update::Action
     },
-    lang::{VerbPhrase, PrepositionalVerbPhrase, PrepositionalPhrase, IntransitiveVerb, verbs::ToInteract}
+    lang::{VerbPhrase, PrepositionalVerbPhrase, PrepositionalPhrase, IntransitiveVerb, verbs::ToInteract},
+    quantities::duration::seconds
 };
 
 #[derive(Debug)]
 pub enum InteractCommandToActionError {
     FailedToGetTargetObject(WorldObjectGetError),
+    TargetOutOfRange(RangeError),
 }
 
 impl std::fmt::Display for InteractCommandToActionError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::FailedToGetTargetObject(err) => write!(f, "failed to get target object: {}", err),
+            Self::TargetOutOfRange(err) => write!(f, "target out of reach: {}", err),
         }
     }
 }
 
 impl std::error::Error for InteractCommandToActionError {}
 
-pub fn from_command(cmd: InteractAction, world: &World) -> Result<Action, InteractCommandToActionError> {
+pub fn from_command(cmd: InteractAction, world: &WorldView, my_handle: WorldObjectHandle, me: &Human) -> Result<Action, InteractCommandToActionError> {
+    let target_handle = world.find_object(&cmd.target)
+        .map_err(InteractCommandToActionError::FailedToGetTargetObject)?;
+
+    world.check_range(&my_handle, &target_handle, &me.grasp_reach())
+        .map_err(InteractCommandToActionError::TargetOutOfRange)?;
+
     Ok(Action{
         exec: {
-            let target_handle = cmd.target_handle.clone();
             Box::new(
                 move |world: &mut World| -> BoxFuture<Result<Option<String>, WorldObjectError>> {
                     Box::pin(async move {
@@ -42,6 +51,7 @@ pub fn from_command(cmd: InteractAction, world: &World) -> Result<Action, Intera
                 }
             )
         },
+        cost: seconds(2.0),
         verb_phrase: VerbPhrase::Prepositional(
             PrepositionalVerbPhrase {
                 main_verb_phrase: Box::new(
@@ -51,7 +61,7 @@ pub fn from_command(cmd: InteractAction, world: &World) -> Result<Action, Intera
                 ),
                 prepositional_phrase: PrepositionalPhrase {
                     preposition: String::from("with"),
-                    object: world.get_object(&cmd.target_handle)
+                    object: world.get_object(&target_handle)
                         .map(|object| object.definite_description())
                         .map_err(|err| InteractCommandToActionError::FailedToGetTargetObject(err))?
                 }
@@ -2,42 +2,52 @@ use crate::{
     lang::{VerbPhrase, TransitiveVerb, TransitiveVerbPhrase, verbs::ToCheck},
     world::World,
     worldobject::{
-        TypedWorldObject,
         fns::update::Action,
         human::Human
-    }
+    },
+    quantities::{direction::DirectionHorizontal, duration::seconds}
 };
 
-pub fn action(me: Human) -> Action {
+// describe_hand says what the hand on the given side is wielding, if it is still there
+fn describe_hand(me: &Human, side: DirectionHorizontal) -> String {
+    match me.arm(side).map(|arm| (arm.hand.is_some(), arm.wielded_item())) {
+        Some((true, item)) => format!("in your {} hand, you are wielding {}", side, item
+            .map(|item| item.indefinite_description())
+            .unwrap_or(String::from("nothing"))
+        ),
+        Some((false, _)) | None => format!("you have no {} hand", side),
+    }
+}
+
+pub fn action(me: &Human) -> Action {
+    // checking the inventory doesn't change it, so the
+    // listing can be put together ahead of time
+    let listing = format!(
+        "{}\n{}\n{}",
+        {
+            let handles_and_descriptions = me.inventory.0.iter()
+                .map(|(handle, object)| (handle, object.indefinite_description()))
+                .collect::<Vec<_>>();
+
+            if handles_and_descriptions.is_empty() {
+                "you are carrying nothing".to_string()
+            } else {
+                format!("you are carrying:\n - {}", handles_and_descriptions.iter().map(|(_, description)| description.clone()).collect::<Vec<_>>().join("\n - "))
+            }
+        },
+        describe_hand(me, DirectionHorizontal::Left),
+        describe_hand(me, DirectionHorizontal::Right),
+    );
+
     Action{
         exec: Box::new(
             move |_: &mut World| {
                 Box::pin(async move {
-                    Ok(Some(format!(
-                        "{}\n{}\n{}",
-                        {
-                            let handles_and_descriptions = me.inventory()?.0.iter()
-                                .map(|(handle, object)| (handle, format!("{}: {}", handle, object.indefinite_description())))
-                                .collect::<Vec<_>>();
-
-                            if handles_and_descriptions.is_empty() {
-                                format!("you are carrying nothing")
-                            } else {
-                                format!("you are carrying:\n - {}", handles_and_descriptions.iter().map(|(_, description)| description.clone()).collect::<Vec<_>>().join("\n - "))
-                            }
-                        },
-                        format!("in your left hand, you are wielding {}", me.body.torso.left_arm.wielded_item()
-                            .map(|item| item.indefinite_description())
-                            .unwrap_or(String::from("nothing"))
-                        ),
-                        format!("in your right hand, you are wielding {}", me.body.torso.right_arm.wielded_item()
-                            .map(|item| item.indefinite_description())
-                            .unwrap_or(String::from("nothing"))
-                        ),
-                    )))
+                    Ok(Some(listing))
                 })
             }
         ),
+        cost: seconds(2.0),
         verb_phrase: VerbPhrase::Transitive(
             TransitiveVerbPhrase {
                 verb: TransitiveVerb::new(ToCheck),
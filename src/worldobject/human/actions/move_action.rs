@@ -2,43 +2,28 @@ use futures::future::BoxFuture;
 
 use crate::{
     lang::{VerbPhrase, IntransitiveVerb, verbs::ToMove},
-    world::{World, handle::WorldObjectHandle},
+    world::{
+        World,
+        handle::WorldObjectHandle,
+        motion::{self, MoveError}
+    },
     worldobject::{
         Error as WorldObjectError,
         components::controllers::commands::move_command::MoveCommand,
         human::Human,
         fns::update::Action
     },
-    quantities::{
-        duration::seconds,
-        distance::meters
-    }
+    quantities::distance::meters
 };
 
-#[derive(Debug)]
-pub enum MoveError {
-    DistanceTooGreat,
-}
-
-impl std::fmt::Display for MoveError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Self::DistanceTooGreat => write!(f, "distance too great"),
-        }
-    }
-}
+pub fn from_command(cmd: MoveCommand, my_handle: WorldObjectHandle, me: &Human) -> Result<Action, MoveError> {
+    // the move takes as long as it takes to walk the distance
+    let cost = motion::move_cost(&cmd.distance, &me.body.legs.current_speed())?;
 
-impl std::error::Error for MoveError {}
-
-pub fn from_command(cmd: MoveCommand, my_handle: WorldObjectHandle, me: Human) -> Action {
-    Action{
+    Ok(Action{
         exec: Box::new(
             move |world: &mut World| -> BoxFuture<Result<Option<String>, WorldObjectError>> {
                 Box::pin(async move {
-                    if cmd.distance > (seconds(1.0) * me.body.legs.speed.commute()).associate_left().commute().cancel() {
-                        return Err(Box::new(MoveError::DistanceTooGreat).into());
-                    }
-
                     world.move_object(&my_handle, &cmd.direction, &cmd.distance)
                         .map_err(|err| Box::new(err))?;
 
@@ -48,8 +33,9 @@ pub fn from_command(cmd: MoveCommand, my_handle: WorldObjectHandle, me: Human) -
                 })
             }
         ),
+        cost,
         verb_phrase: VerbPhrase::Intransitive(
             IntransitiveVerb::new(ToMove)
         )
-    }
+    })
 }
\ No newline at end of file
@@ -0,0 +1,89 @@
+use crate::{
+    lang::{VerbPhrase, PrepositionalVerbPhrase, PrepositionalPhrase, TransitiveVerb, verbs::ToPush},
+    worldobject::{
+        fns::update::Action,
+        human::Human
+    },
+    world::{World, WorldObjectGetError, handle::{WorldObjectHandle, WorldObjectLabel}, view::WorldView, range::RangeError},
+    quantities::{duration::seconds, direction::DirectionHorizontalOrVertical, force::newtons, distance::meters}
+};
+
+// how long, in seconds, a push lasts; the push's
+// force acts on the target for all of that time
+const PUSH_SECONDS: f64 = 0.5;
+
+#[derive(Debug)]
+pub enum PushCommandToActionError {
+    FailedToGetTargetObject(WorldObjectGetError),
+    TargetOutOfRange(RangeError),
+    NoArmToPushWith,
+}
+
+impl std::fmt::Display for PushCommandToActionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::FailedToGetTargetObject(err) => write!(f, "failed to get target object: {}", err),
+            Self::TargetOutOfRange(err) => write!(f, "target out of reach: {}", err),
+            Self::NoArmToPushWith => write!(f, "you have no arm fit to push with"),
+        }
+    }
+}
+
+impl std::error::Error for PushCommandToActionError {}
+
+// from_command builds a push of the target in the given direction, with
+// all the strength of the human's working arms; how far the target goes
+// depends on how heavy it is
+pub fn from_command(target: WorldObjectLabel, direction: DirectionHorizontalOrVertical, world: &WorldView, my_handle: WorldObjectHandle, me: &Human) -> Result<Action, PushCommandToActionError> {
+    let target_handle = world.find_object(&target)
+        .map_err(PushCommandToActionError::FailedToGetTargetObject)?;
+
+    let target_description = world.get_object(&target_handle)
+        .map(|object| object.definite_description())
+        .map_err(PushCommandToActionError::FailedToGetTargetObject)?;
+
+    world.check_range(&my_handle, &target_handle, &me.grasp_reach())
+        .map_err(PushCommandToActionError::TargetOutOfRange)?;
+
+    let force = me.push_force();
+    if (&force / &newtons(1.0)).cancel().0.0 <= 0.0 {
+        return Err(PushCommandToActionError::NoArmToPushWith);
+    }
+
+    let budge_description = target_description.clone();
+
+    Ok(Action{
+        exec: Box::new(
+            move |world: &mut World| {
+                Box::pin(async move {
+                    let distance = world.shove(&target_handle, direction, &force, &seconds(PUSH_SECONDS))
+                        .map_err(Box::new)?;
+
+                    if distance <= meters(0.0) {
+                        return Ok(Some(format!("{} doesn't budge", budge_description)));
+                    }
+
+                    Ok(None)
+                })
+            }
+        ),
+        cost: seconds(PUSH_SECONDS),
+        verb_phrase: match direction {
+            DirectionHorizontalOrVertical::Horizontal(horizontal) => VerbPhrase::Prepositional(
+                PrepositionalVerbPhrase {
+                    main_verb_phrase: Box::new(VerbPhrase::Transitive(
+                        TransitiveVerb::new(ToPush).with_direct_object(target_description)
+                    )),
+                    prepositional_phrase: PrepositionalPhrase {
+                        preposition: String::from("to the"),
+                        object: horizontal.to_string(),
+                    }
+                }
+            ),
+            // things are pushed up or down without a preposition
+            DirectionHorizontalOrVertical::Vertical(vertical) => VerbPhrase::Transitive(
+                TransitiveVerb::new(ToPush).with_direct_object(format!("{} {}", target_description, vertical))
+            ),
+        }
+    })
+}
@@ -0,0 +1,132 @@
+use futures::future::BoxFuture;
+
+use crate::{
+    lang::{TransitiveVerb, TransitiveVerbPhrase, VerbPhrase, verbs::{ToOffer, ToGive, ToDecline}},
+    world::{World, WorldObjectGetError, handle::WorldObjectHandle, view::WorldView, range::RangeError},
+    worldobject::{
+        Error as WorldObjectError, TypedWorldObject,
+        components::{
+            controllers::{DECLINE, commands::trade_command::TradeCommand},
+            health::Vitality,
+            inventory::item::InventoryItemHandle
+        },
+        fns::update::Action, human::Human
+    },
+    quantities::duration::seconds,
+};
+
+#[derive(Debug)]
+pub enum TradeCommandToActionError {
+    NoSuchItem(String),
+    FailedToGetPartner(WorldObjectGetError),
+    PartnerOutOfRange(RangeError),
+    CannotTradeWithSelf,
+    PartnerIsDead(String),
+}
+
+impl std::fmt::Display for TradeCommandToActionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NoSuchItem(item) => write!(f, "inventory does not contain item: {}", item),
+            Self::FailedToGetPartner(err) => write!(f, "failed to get trading partner: {}", err),
+            Self::PartnerOutOfRange(err) => write!(f, "trading partner out of reach: {}", err),
+            Self::CannotTradeWithSelf => write!(f, "you can't trade with yourself"),
+            Self::PartnerIsDead(partner) => write!(f, "{} is dead", partner),
+        }
+    }
+}
+
+impl std::error::Error for TradeCommandToActionError {}
+
+fn transitive(verb: TransitiveVerb, direct_object: String) -> VerbPhrase {
+    VerbPhrase::Transitive(TransitiveVerbPhrase { verb, direct_object })
+}
+
+// A trade takes place in three steps: the trader offers an item, the
+// partner answers with an item of their own or declines, and the trader
+// accepts or declines the answer.  Both parties answer through their
+// controllers while the action is carried out, and the items only change
+// hands, all at once, after both have agreed.
+pub fn from_command(me: &Human, my_handle: WorldObjectHandle, cmd: TradeCommand, world: &WorldView) -> Result<Action, TradeCommandToActionError> {
+    let item_handle = InventoryItemHandle::try_from(cmd.item.as_str()).ok()
+        .filter(|item_handle| me.inventory.get(item_handle).is_some())
+        .ok_or(TradeCommandToActionError::NoSuchItem(cmd.item))?;
+    let item_description = me.inventory.get(&item_handle)
+        .map(|item| item.indefinite_description())
+        .unwrap_or_default();
+
+    let partner_handle = world.find_object(&cmd.partner)
+        .map_err(TradeCommandToActionError::FailedToGetPartner)?;
+    if partner_handle == my_handle {
+        return Err(TradeCommandToActionError::CannotTradeWithSelf);
+    }
+
+    world.check_range(&my_handle, &partner_handle, &me.grasp_reach())
+        .map_err(TradeCommandToActionError::PartnerOutOfRange)?;
+
+    let (partner_description, partner_vitality) = world.get_object(&partner_handle)
+        .map(|partner| (partner.definite_description(), partner.vitality()))
+        .map_err(TradeCommandToActionError::FailedToGetPartner)?;
+    if partner_vitality == Vitality::Dead {
+        return Err(TradeCommandToActionError::PartnerIsDead(partner_description));
+    }
+
+    let my_description = me.definite_description();
+    let offer = format!("{} to {} in trade", item_description, partner_description);
+
+    Ok(Action{
+        exec: Box::new(
+            move |world: &mut World| -> BoxFuture<Result<Option<String>, WorldObjectError>> {
+                Box::pin(async move {
+                    world.address(partner_handle, transitive(TransitiveVerb::new(ToOffer), format!("you {} in trade", item_description)));
+
+                    // the partner answers with any item they carry, or declines
+                    let mut wares = world.get_object(&partner_handle)?.inventory()?.0.iter()
+                        .map(|(handle, item)| (handle.clone(), item.indefinite_description()))
+                        .collect::<Vec<_>>();
+                    wares.sort_by(|(_, a), (_, b)| a.cmp(b));
+
+                    let prompt = format!("{} offers you {} in trade; what will you give in return?", my_description, item_description);
+                    let options = wares.iter()
+                        .map(|(_, description)| description.clone())
+                        .chain(std::iter::once(String::from(DECLINE)))
+                        .collect();
+
+                    let choice = world.choose(partner_handle, prompt, options).await?;
+
+                    let Some((returned_handle, returned_description)) = wares.into_iter().nth(choice) else {
+                        world.narrate_to(
+                            partner_handle, transitive(TransitiveVerb::new(ToDecline), format!("{}'s offer", my_description)),
+                            my_handle, transitive(TransitiveVerb::new(ToDecline), String::from("your offer"))
+                        );
+                        return Ok(None);
+                    };
+
+                    let prompt = format!("{} offers you {} for {}; do you accept?", partner_description, returned_description, item_description);
+                    let options = vec![String::from("accept"), String::from(DECLINE)];
+
+                    let choice = world.choose(my_handle, prompt, options).await?;
+
+                    if choice != 0 {
+                        world.narrate_to(
+                            my_handle, transitive(TransitiveVerb::new(ToDecline), format!("{}'s offer", partner_description)),
+                            partner_handle, transitive(TransitiveVerb::new(ToDecline), String::from("your offer"))
+                        );
+                        return Ok(None);
+                    }
+
+                    world.exchange_items(&my_handle, &item_handle, &partner_handle, Some(&returned_handle))
+                        .map_err(Box::new)?;
+                    world.narrate_to(
+                        partner_handle, transitive(TransitiveVerb::new(ToGive), format!("{} to {} in return", returned_description, my_description)),
+                        my_handle, transitive(TransitiveVerb::new(ToGive), format!("you {} in return", returned_description))
+                    );
+
+                    Ok(None)
+                })
+            }
+        ),
+        cost: seconds(2.0),
+        verb_phrase: transitive(TransitiveVerb::new(ToOffer), offer)
+    })
+}
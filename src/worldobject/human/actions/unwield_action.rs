@@ -0,0 +1,123 @@
+use futures::future::BoxFuture;
+
+use crate::{
+    lang::{TransitiveVerb, TransitiveVerbPhrase, VerbPhrase, verbs::ToUnwield}, world::World, worldobject::{
+        Error as WorldObjectError, components::controllers::commands::unwield_command::UnwieldCommand,
+        fns::update::Action, human::Human
+    },
+    quantities::{direction::DirectionHorizontal, duration::seconds},
+};
+
+#[derive(Debug)]
+pub enum UnwieldCommandToActionError {
+    NotWielding(String),
+    NothingInHand(DirectionHorizontal),
+    NothingWielded,
+}
+
+impl std::fmt::Display for UnwieldCommandToActionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotWielding(name) => write!(f, "you are not wielding an item named \"{}\"", name),
+            Self::NothingInHand(side) => write!(f, "your {} hand is empty", side),
+            Self::NothingWielded => write!(f, "you are not wielding anything"),
+        }
+    }
+}
+
+impl std::error::Error for UnwieldCommandToActionError {}
+
+pub fn from_command(me: &mut Human, cmd: UnwieldCommand) -> Result<Action, UnwieldCommandToActionError> {
+    let side = match (cmd.item, cmd.hand) {
+        (Some(item), _) => me.wielding_side(&item)
+            .ok_or(UnwieldCommandToActionError::NotWielding(item))?,
+        (None, Some(hand)) => hand.side(me.dominant_arm),
+        (None, None) => [me.dominant_arm, me.dominant_arm.opposite()].into_iter()
+            .find(|side| me.arm(*side).and_then(|arm| arm.wielded_item()).is_some())
+            .ok_or(UnwieldCommandToActionError::NothingWielded)?,
+    };
+
+    let item = me.arm_mut(side).and_then(|arm| arm.unwield())
+        .ok_or(UnwieldCommandToActionError::NothingInHand(side))?;
+    let item_description = item.definite_description();
+
+    me.inventory.stow(item);
+
+    Ok(Action{
+        exec: Box::new(
+            move |_: &mut World| -> BoxFuture<Result<Option<String>, WorldObjectError>> {
+                Box::pin(async move {
+                    Ok(None)
+                })
+            }
+        ),
+        cost: seconds(1.0),
+        verb_phrase: VerbPhrase::Transitive(
+            TransitiveVerbPhrase {
+                verb: TransitiveVerb::new(ToUnwield),
+                direct_object: item_description
+            }
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        quantities::direction::DirectionHorizontal,
+        tests::fixtures::{alice, sword},
+        worldobject::{
+            components::{
+                controllers::commands::{unwield_command::UnwieldCommand, wield_command::HandChoice},
+                inventory::item::wand::Wand
+            },
+            human::Human
+        }
+    };
+
+    use super::{UnwieldCommandToActionError, from_command};
+
+    // a right-handed human with a sword in their right hand and a wand in their left
+    fn armed_human() -> Human {
+        let mut human = alice();
+        assert!(human.arm_mut(DirectionHorizontal::Right).unwrap().wield(Box::new(sword("steel"))).is_ok());
+        assert!(human.arm_mut(DirectionHorizontal::Left).unwrap().wield(Box::new(Wand)).is_ok());
+        human
+    }
+
+    fn stowed(human: &Human) -> Vec<String> {
+        human.inventory.0.values().map(|item| item.name()).collect()
+    }
+
+    #[test]
+    fn unwields_the_named_item_into_the_inventory() {
+        let mut human = armed_human();
+
+        assert!(from_command(&mut human, UnwieldCommand { item: Some(String::from("wand")), hand: None }).is_ok());
+        assert_eq!(stowed(&human), ["wand"]);
+        assert!(human.arm(DirectionHorizontal::Left).unwrap().wielded_item().is_none());
+    }
+
+    #[test]
+    fn unwields_from_the_given_hand_or_else_the_dominant_one_first() {
+        let mut human = armed_human();
+
+        assert!(from_command(&mut human, UnwieldCommand { item: None, hand: Some(HandChoice::Off) }).is_ok());
+        assert_eq!(stowed(&human), ["wand"]);
+
+        assert!(from_command(&mut human, UnwieldCommand { item: None, hand: None }).is_ok());
+        assert_eq!(stowed(&human), ["wand", "sword"]);
+
+        assert!(matches!(from_command(&mut human, UnwieldCommand { item: None, hand: None }), Err(UnwieldCommandToActionError::NothingWielded)));
+        assert!(matches!(from_command(&mut human, UnwieldCommand { item: None, hand: Some(HandChoice::Dominant) }), Err(UnwieldCommandToActionError::NothingInHand(_))));
+    }
+
+    #[test]
+    fn cannot_unwield_what_is_not_wielded() {
+        let mut human = armed_human();
+
+        let result = from_command(&mut human, UnwieldCommand { item: Some(String::from("axe")), hand: None });
+        assert!(matches!(result, Err(UnwieldCommandToActionError::NotWielding(_))));
+        assert!(stowed(&human).is_empty());
+    }
+}
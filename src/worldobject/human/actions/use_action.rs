@@ -2,13 +2,15 @@ use crate::{
     worldobject::{fns::update::Action, human::Human,
         components::controllers::commands::use_command::UseCommand
     },
-    world::{World, handle::WorldObjectHandle}
+    world::{WorldObjectGetError, handle::WorldObjectHandle, view::WorldView, range::RangeError}
 };
 
 #[derive(Debug)]
 pub enum UseCommandToActionError {
     FailedToFindWieldedItem(String),
     FailedToUseItem(Box<dyn std::error::Error>),
+    FailedToGetTargetObject(WorldObjectGetError),
+    TargetOutOfRange(RangeError),
 }
 
 impl std::fmt::Display for UseCommandToActionError {
@@ -16,17 +18,29 @@ impl std::fmt::Display for UseCommandToActionError {
         match self {
             Self::FailedToFindWieldedItem(name) => write!(f, "you are not wielding an item named \"{}\"", name),
             Self::FailedToUseItem(err) => write!(f, "failed to use item: {}", err),
+            Self::FailedToGetTargetObject(err) => write!(f, "failed to get target object: {}", err),
+            Self::TargetOutOfRange(err) => write!(f, "target out of range: {}", err),
         }
     }
 }
 
 impl std::error::Error for UseCommandToActionError {}
 
-pub fn from_command(me: &mut Human, my_handle: WorldObjectHandle, cmd: UseCommand, world: &World) -> Result<Action, UseCommandToActionError> {
+pub fn from_command(me: &mut Human, my_handle: WorldObjectHandle, cmd: UseCommand, world: &WorldView) -> Result<Action, UseCommandToActionError> {
     let wielded_item = me.wielded_items_mut().find(
         |item| item.name() == cmd.item_name
     ).ok_or(UseCommandToActionError::FailedToFindWieldedItem(cmd.item_name))?;
-    
-    wielded_item.use_item(world, my_handle, cmd.target_handle).map_err(|err| UseCommandToActionError::FailedToUseItem(err))
+
+    let target_handle = cmd.target.as_deref()
+        .map(|target| world.find_object(target))
+        .transpose()
+        .map_err(UseCommandToActionError::FailedToGetTargetObject)?;
+
+    if let Some(target_handle) = &target_handle {
+        world.check_range(&my_handle, target_handle, &wielded_item.range())
+            .map_err(UseCommandToActionError::TargetOutOfRange)?;
+    }
+
+    wielded_item.use_item(world, my_handle, target_handle).map_err(UseCommandToActionError::FailedToUseItem)
 }
 
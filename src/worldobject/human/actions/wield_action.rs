@@ -3,16 +3,17 @@ use futures::future::BoxFuture;
 use crate::{
     lang::{TransitiveVerb, TransitiveVerbPhrase, VerbPhrase, verbs::ToWield}, world::World, worldobject::{
         Error as WorldObjectError, components::{
-            controllers::commands::wield_command::WieldCommand,
+            controllers::commands::wield_command::{WieldCommand, HandChoice},
             inventory::item::InventoryItemHandle
         }, fns::update::Action, human::Human
     },
-    quantities::direction::DirectionHorizontal,
+    quantities::{direction::DirectionHorizontal, duration::seconds},
 };
 
 #[derive(Debug)]
 pub enum WieldCommandToActionError {
-    NoSuchItem(InventoryItemHandle),
+    NoSuchItem(String),
+    NoArm(DirectionHorizontal),
     FailedToWieldItem(Box<dyn std::error::Error>),
 }
 
@@ -20,6 +21,7 @@ impl std::fmt::Display for WieldCommandToActionError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::NoSuchItem(handle) => write!(f, "inventory does not contain item: {}", handle),
+            Self::NoArm(side) => write!(f, "you have no {} arm", side),
             Self::FailedToWieldItem(err) => write!(f, "failed to wield item: {}", err),
         }
     }
@@ -28,30 +30,42 @@ impl std::fmt::Display for WieldCommandToActionError {
 impl std::error::Error for WieldCommandToActionError {}
 
 pub fn from_command(me: &mut Human, cmd: WieldCommand) -> Result<Action, WieldCommandToActionError> {
-    let inventory_item = me.inventory.take(&cmd.item_handle)
-        .ok_or(WieldCommandToActionError::NoSuchItem(cmd.item_handle))?;
+    let item_handle = InventoryItemHandle::try_from(cmd.item.as_str())
+        .map_err(|_| WieldCommandToActionError::NoSuchItem(cmd.item.clone()))?;
+    let inventory_item = me.inventory.take(&item_handle)
+        .ok_or(WieldCommandToActionError::NoSuchItem(cmd.item))?;
     let inventory_item_description = inventory_item.indefinite_description();
 
-    let wielding_arm = match me.dominant_arm {
-        DirectionHorizontal::Left => {
-            &mut me.body.torso.left_arm
-        }
-        DirectionHorizontal::Right => {
-            &mut me.body.torso.right_arm
-        }
+    let side = cmd.hand.unwrap_or(HandChoice::Dominant).side(me.dominant_arm);
+
+    // whatever the hand held is stowed rather than dropped on the floor;
+    // if the item can't be wielded at all, it goes back where it came from
+    let wielded = match me.arm_mut(side) {
+        Some(arm) => arm.wield(inventory_item)
+            .map_err(|(err, inventory_item)| (WieldCommandToActionError::FailedToWieldItem(Box::new(err)), inventory_item)),
+        None => Err((WieldCommandToActionError::NoArm(side), inventory_item)),
     };
+    let displaced_item = wielded
+        .map_err(|(err, inventory_item)| {
+            me.inventory.0.insert(item_handle, inventory_item);
+            err
+        })?;
 
-    wielding_arm.wield(inventory_item)
-        .map_err(|err| WieldCommandToActionError::FailedToWieldItem(Box::new(err)))?;
+    let message = displaced_item.map(|displaced_item| {
+        let message = format!("you put {} into your inventory", displaced_item.definite_description());
+        me.inventory.stow(displaced_item);
+        message
+    });
 
     Ok(Action{
         exec: Box::new(
             move |_: &mut World| -> BoxFuture<Result<Option<String>, WorldObjectError>> {
                 Box::pin(async move {
-                    Ok(None)
+                    Ok(message)
                 })
             }
         ),
+        cost: seconds(2.0),
         verb_phrase: VerbPhrase::Transitive(
             TransitiveVerbPhrase {
                 verb: TransitiveVerb::new(ToWield),
@@ -59,4 +73,63 @@ pub fn from_command(me: &mut Human, cmd: WieldCommand) -> Result<Action, WieldCo
             }
         )
     })
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        quantities::direction::DirectionHorizontal,
+        tests::fixtures::{alice, sword},
+        worldobject::{
+            components::{
+                controllers::commands::wield_command::{HandChoice, WieldCommand},
+                inventory::item::wand::Wand
+            },
+            human::Human
+        }
+    };
+
+    use super::{WieldCommandToActionError, from_command};
+
+    fn wielded(human: &Human, side: DirectionHorizontal) -> Option<String> {
+        human.arm(side).and_then(|arm| arm.wielded_item()).map(|item| item.name())
+    }
+
+    #[test]
+    fn wields_in_the_dominant_hand_unless_told_otherwise() {
+        let mut human = alice();
+        let sword = human.inventory.give(sword("steel"));
+        let wand = human.inventory.give(Wand);
+
+        assert!(from_command(&mut human, WieldCommand { item: sword.to_string(), hand: None }).is_ok());
+        assert!(from_command(&mut human, WieldCommand { item: wand.to_string(), hand: Some(HandChoice::Off) }).is_ok());
+
+        assert_eq!(wielded(&human, DirectionHorizontal::Right).as_deref(), Some("sword"));
+        assert_eq!(wielded(&human, DirectionHorizontal::Left).as_deref(), Some("wand"));
+        assert!(human.inventory.0.is_empty());
+    }
+
+    #[test]
+    fn wielding_into_an_occupied_hand_stows_what_it_held() {
+        let mut human = alice();
+        let sword = human.inventory.give(sword("steel"));
+        let wand = human.inventory.give(Wand);
+
+        assert!(from_command(&mut human, WieldCommand { item: sword.to_string(), hand: None }).is_ok());
+        assert!(from_command(&mut human, WieldCommand { item: wand.to_string(), hand: Some(HandChoice::Dominant) }).is_ok());
+
+        assert_eq!(wielded(&human, DirectionHorizontal::Right).as_deref(), Some("wand"));
+        assert_eq!(human.inventory.0.values().map(|item| item.name()).collect::<Vec<_>>(), ["sword"]);
+    }
+
+    #[test]
+    fn an_item_that_cannot_be_wielded_goes_back_where_it_came_from() {
+        let mut human = alice();
+        let sword = human.inventory.give(sword("steel"));
+        human.arm_mut(DirectionHorizontal::Left).unwrap().hand = None;
+
+        let result = from_command(&mut human, WieldCommand { item: sword.to_string(), hand: Some(HandChoice::Off) });
+        assert!(matches!(result, Err(WieldCommandToActionError::FailedToWieldItem(_))));
+        assert!(human.inventory.get(&sword).is_some());
+    }
+}
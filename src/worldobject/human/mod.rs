@@ -5,16 +5,22 @@ use async_trait::async_trait;
 use serde::{Serialize, Serializer, ser::SerializeStruct};
 
 use crate::{
+    combat::{self, Blow, BlowOutcome, DamageType},
+    lang::{VerbPhrase, IntransitiveVerb, TransitiveVerb, verbs::{ToDie, ToDrop, ToLose, ToSuffer}},
     quantities::{
-        Quantity, direction::DirectionHorizontal, force::Force, mass::Mass
+        Quantity, direction::DirectionHorizontal, force::{Force, newtons}, mass::Mass, distance::{Distance, meters}, speed::Speed
     }, world::{
-        World,
-        handle::WorldObjectHandle
+        handle::WorldObjectHandle,
+        rng::WorldRng,
+        view::WorldView,
+        perception::Perception,
+        motion
     }, worldobject::{
         Error as WorldObjectError, TypedWorldObject, components::{
-            controllers::Controller,
+            controllers::{Controller, commands::resolve::{Referent, Referents}, observation::Observation},
             gender::Gender,
-            body::Body,
+            body::{Body, BodyPart, torso::arm::Arm},
+            health::Vitality,
             inventory::{
                 Inventory,
                 item::{InventoryItem, none::NoInventoryItem}
@@ -23,6 +29,10 @@ use crate::{
     }
 };
 
+// how far, in meters, a human can see and hear
+const HUMAN_SIGHT_METERS: f64 = 20.0;
+const HUMAN_HEARING_METERS: f64 = 30.0;
+
 pub struct Human {
     // identity
     pub name: String,
@@ -87,9 +97,9 @@ impl Human {
 
 #[async_trait]
 impl TypedWorldObject for Human {
-    type Dummy = Human;
     // humans can't be collected
     type CollectInventoryItem = NoInventoryItem;
+    const TYPE_TAG: &'static str = "human";
 
     fn name(&self) -> String {
         self.name.clone()
@@ -100,7 +110,11 @@ impl TypedWorldObject for Human {
     }
 
     fn indefinite_description(&self) -> String {
-        format!("a {}", self.gender.noun())
+        if self.body.is_alive() {
+            format!("a {}", self.gender.noun())
+        } else {
+            format!("a dead {}", self.gender.noun())
+        }
     }
 
     fn pronoun(&self) -> String {
@@ -111,19 +125,12 @@ impl TypedWorldObject for Human {
         Err((Box::new(HumanCollectError()), self))
     }
 
-    fn dummy(&self) -> Self::Dummy {
-        Human {
-            name: self.name.clone(),
-            gender: self.gender.clone(),
-            body: self.body.dummy(),
-            dominant_arm: self.dominant_arm.clone(),
-            inventory: self.inventory.dummy(),
-            controller: None,
-        }
-    }
-
     fn examine(&self) -> String {
-        format!("a human {}; {} name is {}", self.gender.noun(), self.gender.possessive_pronoun(), self.name)
+        if self.body.is_alive() {
+            format!("a human {}; {} name is {}", self.gender.noun(), self.gender.possessive_pronoun(), self.name)
+        } else {
+            format!("the body of a human {}; {} name was {}", self.gender.noun(), self.gender.possessive_pronoun(), self.name)
+        }
     }
 
     fn inventory(&self) -> Result<&Inventory, WorldObjectError> {
@@ -138,12 +145,23 @@ impl TypedWorldObject for Human {
         Ok(format!("{} says \"Hello\".", self.name))
     }
 
-    async fn update(&mut self, my_handle: WorldObjectHandle, world: &World) -> Result<Action, WorldObjectError> {
+    async fn update(&mut self, my_handle: WorldObjectHandle, world: &WorldView) -> Result<Action, WorldObjectError> {
+        let observation = self.observe(world);
+        let referents = self.referents(world);
+
         match &mut self.controller {
             Some(controller) => {
+                controller.observe(&observation).await;
                 let command = controller.prompt_turn().await?;
-                self.from_command(command, world, my_handle)
-                    .map_err(|err| Box::new(err).into())
+                let command = command.resolve(&referents, controller.as_mut()).await?;
+
+                // the command is only journaled once it has become an action
+                let journaled = serde_json::to_value(&command)?;
+                let action = self.action_from_command(command, world, my_handle)
+                    .map_err(Box::new)?;
+                world.accept(journaled);
+
+                Ok(action)
             }
             None => Ok(Action::no_op()),
         }
@@ -156,14 +174,43 @@ impl TypedWorldObject for Human {
         }
     }
 
-    async fn apply_force(&mut self, _: &Quantity<Force>) -> Result<String, WorldObjectError> {
-        Ok(format!("{}'s hefty constitution absorbs the force.", self.definite_description()))
+    // a force that isn't a blow lands like a bash, wherever it happens to;
+    // anything it knocks loose is left for the world to drop
+    async fn apply_force(&mut self, force: &Quantity<Force>, rng: &mut WorldRng) -> Result<BlowOutcome, WorldObjectError> {
+        if !self.body.is_alive() {
+            return Ok(BlowOutcome::from(format!("{}'s body is pushed about by the force", self.name)));
+        }
+
+        let part = self.body.random_part(rng);
+        Ok(self.injure(part, DamageType::Bash, combat::hit_points(force)))
+    }
+
+    async fn receive_blow(&mut self, blow: &Blow, rng: &mut WorldRng) -> Result<BlowOutcome, WorldObjectError> {
+        if !self.body.is_alive() {
+            return Ok(BlowOutcome::from(format!("{} is already dead", self.name)));
+        }
+
+        let part = blow.aim.unwrap_or_else(|| self.body.random_part(rng));
+        Ok(self.injure(part, blow.damage_type, blow.hit_points()))
     }
 
     fn mass(&self) -> Quantity<Mass> {
         self.body.mass()
     }
 
+    fn perception(&self) -> Perception {
+        Perception::new(meters(HUMAN_SIGHT_METERS), meters(HUMAN_HEARING_METERS))
+    }
+
+    // humans only take turns while they live and someone is controlling them
+    fn initiative(&self) -> Option<Quantity<Speed>> {
+        (self.controller.is_some() && self.body.is_alive()).then(|| self.body.legs.current_speed())
+    }
+
+    fn vitality(&self) -> Vitality {
+        if self.body.is_alive() { Vitality::Alive } else { Vitality::Dead }
+    }
+
     fn controller(&self) -> Result<&dyn Controller, Box<dyn StdError>> {
         match &self.controller {
             Some(controller) => Ok(&**controller),
@@ -191,15 +238,152 @@ impl TypedWorldObject for Human {
 }
 
 impl Human {
+    pub fn arm(&self, side: DirectionHorizontal) -> Option<&Arm> {
+        self.body.arm(side)
+    }
+
+    // wielding_side returns which hand holds the item with the given name,
+    // looking in the dominant hand first
+    pub fn wielding_side(&self, item_name: &str) -> Option<DirectionHorizontal> {
+        [self.dominant_arm, self.dominant_arm.opposite()].into_iter()
+            .find(|side| self.arm(*side).and_then(Arm::wielded_item).is_some_and(|item| item.name() == item_name))
+    }
+
+    pub fn arm_mut(&mut self, side: DirectionHorizontal) -> Option<&mut Arm> {
+        self.body.arm_mut(side)
+    }
+
+    // how far away an object can be for this human to grab it;
+    // a human without arms can't grab anything
+    pub fn grasp_reach(&self) -> Quantity<Distance> {
+        [DirectionHorizontal::Left, DirectionHorizontal::Right].into_iter()
+            .filter_map(|side| self.arm(side).map(|arm| arm.reach))
+            .fold(meters(0.0), |longest, reach| if reach > longest { reach } else { longest })
+    }
+
+    // how hard this human can push, with every arm that isn't crippled;
+    // a human without a working arm can't push at all
+    pub fn push_force(&self) -> Quantity<Force> {
+        let total = [DirectionHorizontal::Left, DirectionHorizontal::Right].into_iter()
+            .filter_map(|side| self.arm(side))
+            .filter(|arm| !arm.health.is_disabled())
+            .map(|arm| (&arm.punch_force / &newtons(1.0)).cancel().0.0)
+            .sum();
+
+        newtons(total)
+    }
+
+    // the side of the arm this human strikes with: the dominant arm, unless
+    // only the other arm is wielding something to strike with, or the dominant
+    // arm or its hand is crippled or severed; None if neither arm can strike
+    pub fn attacking_side(&self) -> Option<DirectionHorizontal> {
+        let dominant_side = self.dominant_arm;
+        let off_side = dominant_side.opposite();
+        let wields = |side| self.arm(side).and_then(Arm::wielded_item).is_some();
+        let sides = if !wields(dominant_side) && wields(off_side) {
+            [off_side, dominant_side]
+        } else {
+            [dominant_side, off_side]
+        };
+
+        sides.into_iter().find(|side| self.arm(*side).is_some_and(|arm| arm.check_grip().is_ok()))
+    }
+
+    // injure deals damage of the given type to a body part, returning what
+    // the human suffers as a result; a crippled arm or hand lets go of
+    // whatever it held, a crippled head or torso is fatal, and a blow that
+    // can take a limb's hit points many times over severs it outright
+    fn injure(&mut self, part: BodyPart, damage_type: DamageType, hit_points: f64) -> BlowOutcome {
+        // a blow to a missing hand lands on the arm instead,
+        // and one to a missing arm lands on the torso
+        let part = match part {
+            BodyPart::Hand(side) if !self.body.has_part(part) => BodyPart::Arm(side),
+            part => part,
+        };
+        let part = if self.body.has_part(part) { part } else { BodyPart::Torso };
+
+        let mut outcome = BlowOutcome { description: None, effects: vec![], shed: vec![] };
+        let Some(health) = self.body.health_mut(part) else {
+            return outcome;
+        };
+
+        let was_disabled = health.is_disabled();
+        let severed = damage_type.severing_factor()
+            .is_some_and(|factor| hit_points >= health.max_hit_points * factor);
+        health.damage(hit_points);
+        let (injured, disabled) = (health.is_injured(), health.is_disabled());
+
+        let suffer = |object: String| VerbPhrase::Transitive(TransitiveVerb::new(ToSuffer).with_direct_object(object));
+        let severity = if injured { "serious " } else { "" };
+        outcome.effects.push(suffer(format!("a {}{} to the {}", severity, damage_type.wound(), part)));
+
+        // a severed limb takes whatever it held along with it
+        if let Some(limb) = severed.then(|| self.body.sever(part)).flatten() {
+            outcome.effects.push(VerbPhrase::Transitive(TransitiveVerb::new(ToLose).with_direct_object(format!("the {}", part))));
+            outcome.shed.push(limb);
+        } else if disabled && !was_disabled {
+            match part {
+                BodyPart::Arm(side) | BodyPart::Hand(side) => {
+                    outcome.effects.push(suffer(format!("a crippled {}", part)));
+                    if let Some(item) = self.arm_mut(side).and_then(|arm| arm.unwield()) {
+                        outcome.effects.push(VerbPhrase::Transitive(TransitiveVerb::new(ToDrop).with_direct_object(item.definite_description())));
+                        outcome.shed.push(item);
+                    }
+                },
+                BodyPart::Legs => outcome.effects.push(suffer(String::from("crippled legs"))),
+                BodyPart::Head | BodyPart::Torso => (),
+            }
+        }
+
+        if !self.body.is_alive() {
+            outcome.effects.push(VerbPhrase::Intransitive(IntransitiveVerb::new(ToDie)));
+        }
+
+        outcome
+    }
+
+    // observe records what this human perceives, for its controller;
+    // it can strike as far as the arm it attacks with reaches
+    fn observe(&self, world: &WorldView) -> Observation {
+        let reach = self.attacking_side()
+            .and_then(|side| self.arm(side))
+            .map(Arm::armed_reach)
+            .unwrap_or(meters(0.0));
+
+        Observation::new(Self::TYPE_TAG, self.body.health(), reach, self.grasp_reach(), motion::stride(&self.body.legs.current_speed()), &self.perception(), world)
+    }
+
+    // referents lists everything this human's commands can refer to:
+    // the objects it can see, and the items it carries and wields
+    fn referents(&self, world: &WorldView) -> Referents {
+        let inventory = self.inventory.0.iter()
+            .map(|(handle, item)| Referent {
+                key: handle.to_string(),
+                names: vec![item.name(), item.definite_description(), item.indefinite_description()],
+                description: item.indefinite_description(),
+            })
+            .collect();
+
+        let wielded = self.wielded_items()
+            .map(|item| Referent {
+                key: item.name(),
+                names: vec![item.name(), item.definite_description(), item.indefinite_description()],
+                description: item.indefinite_description(),
+            })
+            .collect();
+
+        Referents { inventory, wielded, ..Referents::visible_objects(world, &self.perception()) }
+    }
+
     pub fn wielded_items<'a>(&'a self) -> impl Iterator<Item = &'a dyn InventoryItem> {
-        let left_items = self.body.torso.left_arm.wielded_item().into_iter();
-        let right_items = self.body.torso.right_arm.wielded_item().into_iter();
+        let left_items = self.body.torso.left_arm.as_ref().and_then(Arm::wielded_item).into_iter();
+        let right_items = self.body.torso.right_arm.as_ref().and_then(Arm::wielded_item).into_iter();
         left_items.chain(right_items)
     }
 
     pub fn wielded_items_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut dyn InventoryItem> {
-        let left_items = self.body.torso.left_arm.wielded_item_mut().into_iter();
-        let right_items = self.body.torso.right_arm.wielded_item_mut().into_iter();
+        let left_items = self.body.torso.left_arm.as_mut().and_then(Arm::wielded_item_mut).into_iter();
+        let right_items = self.body.torso.right_arm.as_mut().and_then(Arm::wielded_item_mut).into_iter();
         left_items.chain(right_items)
     }
 }
@@ -229,6 +413,105 @@ impl TryFrom<&serde_json::Value> for Human {
 
         let body = Body::try_from(value.get("body").ok_or("body not found")?).map_err(|err| format!("failed to parse body: {}", err))?;
 
-        Ok(Human::new(String::from(name), gender, body, dominant_arm, Inventory::new(), None::<Box<dyn Controller>>))
+        let inventory = Inventory::try_from(value.get("inventory").ok_or("inventory not found")?).map_err(|err| format!("failed to parse inventory: {}", err))?;
+
+        Ok(Human::new(String::from(name), gender, body, dominant_arm, inventory, None::<Box<dyn Controller>>))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+
+    use crate::{
+        combat::{Blow, DamageType},
+        quantities::{direction::DirectionHorizontal, force::newtons},
+        tests::fixtures::{alice, sword},
+        world::rng::WorldRng,
+        worldobject::{
+            TypedWorldObject,
+            components::body::{BodyPart, torso::arm::ArmWieldError}
+        }
+    };
+
+    use super::Human;
+
+    fn armed_alice() -> Human {
+        let mut human = alice();
+        for side in [DirectionHorizontal::Left, DirectionHorizontal::Right] {
+            let sword = Box::new(sword("steel"));
+            assert!(human.arm_mut(side).unwrap().wield(sword).is_ok());
+        }
+        human
+    }
+
+    fn cripple_hand(human: &mut Human, side: DirectionHorizontal) {
+        let hand = human.arm_mut(side).and_then(|arm| arm.hand.as_mut()).unwrap();
+        hand.health.damage(hand.health.max_hit_points);
+    }
+
+    #[test]
+    fn strikes_with_the_dominant_arm() {
+        assert_eq!(alice().attacking_side(), Some(DirectionHorizontal::Right));
+    }
+
+    #[test]
+    fn a_crippled_hand_cannot_strike() {
+        let mut human = alice();
+
+        cripple_hand(&mut human, DirectionHorizontal::Right);
+        assert_eq!(human.attacking_side(), Some(DirectionHorizontal::Left));
+
+        cripple_hand(&mut human, DirectionHorizontal::Left);
+        assert_eq!(human.attacking_side(), None);
+    }
+
+    #[test]
+    fn a_crippled_hand_cannot_wield() {
+        let mut human = alice();
+        cripple_hand(&mut human, DirectionHorizontal::Right);
+
+        let sword = Box::new(sword("steel"));
+        let result = human.arm_mut(DirectionHorizontal::Right).unwrap().wield(sword);
+        assert!(matches!(result, Err((ArmWieldError::HandCrippled, _))));
+    }
+
+    #[tokio::test]
+    async fn a_strong_enough_slash_severs_the_limb_along_with_what_it_held() {
+        let mut human = armed_alice();
+        let blow = Blow { force: newtons(1e6), damage_type: DamageType::Slash, aim: Some(BodyPart::Arm(DirectionHorizontal::Right)) };
+
+        let outcome = human.receive_blow(&blow, &mut WorldRng::seed_from_u64(1)).await.unwrap();
+
+        assert!(human.arm(DirectionHorizontal::Right).is_none());
+        assert_eq!(outcome.shed.len(), 1);
+        assert!(outcome.shed[0].examine().contains("sword"));
+    }
+
+    #[tokio::test]
+    async fn a_stab_cripples_but_never_severs() {
+        let mut human = armed_alice();
+        let blow = Blow { force: newtons(1e6), damage_type: DamageType::Stab, aim: Some(BodyPart::Arm(DirectionHorizontal::Right)) };
+
+        let outcome = human.receive_blow(&blow, &mut WorldRng::seed_from_u64(1)).await.unwrap();
+
+        let arm = human.arm(DirectionHorizontal::Right).unwrap();
+        assert!(arm.health.is_disabled());
+        assert!(arm.wielded_item().is_none());
+        assert_eq!(outcome.shed.len(), 1);
+        assert_eq!(outcome.shed[0].name(), "sword");
+    }
+
+    #[tokio::test]
+    async fn a_force_leaves_whatever_it_knocks_loose_to_the_world() {
+        let mut shed_anything = false;
+        for seed in 0..20 {
+            let mut human = armed_alice();
+            let outcome = human.apply_force(&newtons(1e6), &mut WorldRng::seed_from_u64(seed)).await.unwrap();
+
+            assert!(human.inventory.0.is_empty());
+            shed_anything |= !outcome.shed.is_empty();
+        }
+        assert!(shed_anything);
     }
 }
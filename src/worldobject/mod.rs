@@ -3,16 +3,23 @@ pub mod rat;
 pub mod none;
 pub mod components;
 pub mod fns;
+pub mod registry;
 
 use async_trait::async_trait;
+use serde::Serialize;
 
 use crate::{
+    combat::{Blow, BlowOutcome},
+    materials::Material,
     world::{
-        World,
-        handle::WorldObjectHandle
+        handle::WorldObjectHandle,
+        view::WorldView,
+        perception::Perception,
+        rng::WorldRng
     },
     worldobject::components::{
         controllers::Controller,
+        health::Vitality,
         inventory::{
             Inventory,
             item::InventoryItem
@@ -21,7 +28,8 @@ use crate::{
     quantities::{
         Quantity,
         mass::Mass,
-        force::Force
+        force::Force,
+        speed::Speed
     }
 };
 
@@ -35,10 +43,14 @@ type Error = Box<dyn std::error::Error>;
 // implements the WorldObject trait, but may allow certain
 // users of the type to rely on more specific type constraints.
 #[async_trait]
-pub trait TypedWorldObject: Send {
-    type Dummy: WorldObject + Sized + 'static;
+pub trait TypedWorldObject: Send + Serialize {
     type CollectInventoryItem: InventoryItem + Sized + 'static;
 
+    // the tag under which this type is known to the registry;
+    // it is written alongside the object's data when the object
+    // is persisted so that it can be reconstructed later.
+    const TYPE_TAG: &'static str;
+
     // linguistic accessors
     fn name(&self) -> String;
     fn examine(&self) -> String;
@@ -46,11 +58,6 @@ pub trait TypedWorldObject: Send {
     fn indefinite_description(&self) -> String;
     fn pronoun(&self) -> String;
 
-    // creates a new object with the same properties as this one,
-    // minus any fields that are not cloneable (e.g. controllers,
-    // loggers)
-    fn dummy(&self) -> Self::Dummy;
-
     // inventory accessors
     fn inventory(&self) -> Result<&Inventory, Error>;
     fn inventory_mut(&mut self) -> Result<&mut Inventory, Error>;
@@ -58,6 +65,26 @@ pub trait TypedWorldObject: Send {
     // mass accessor
     fn mass(&self) -> Quantity<Mass>;
 
+    // perception accessor
+    fn perception(&self) -> Perception;
+
+    // how quickly this object acts, which determines its place in the
+    // turn order; objects that never act (e.g. items, or characters
+    // without a controller) return None and are skipped entirely
+    fn initiative(&self) -> Option<Quantity<Speed>>;
+
+    // whether this object is alive; unless it says
+    // otherwise, an object was never alive at all
+    fn vitality(&self) -> Vitality {
+        Vitality::Inanimate
+    }
+
+    // what the object is made of, for objects whose material
+    // matters when they strike or are struck
+    fn material(&self) -> Option<&'static Material> {
+        None
+    }
+
     // controller accessors
     fn controller(&self) -> Result<&dyn Controller, Error>;
     fn controller_mut(&mut self) -> Result<&mut dyn Controller, Error>;
@@ -65,10 +92,16 @@ pub trait TypedWorldObject: Send {
     fn set_controller<C: Controller + 'static>(&mut self, controller: C) -> Result<(), (C, Error)>;
 
     // game mechanics; all async to allow interaction with the controller.
-    async fn apply_force(&mut self, force: &Quantity<Force>) -> Result<String, Error>;
+    async fn apply_force(&mut self, force: &Quantity<Force>, rng: &mut WorldRng) -> Result<BlowOutcome, Error>;
+    // receive_blow is called when the object is struck; unless
+    // the object says otherwise, a blow merely pushes it.  Whatever
+    // either leaves to chance is drawn from the given generator
+    async fn receive_blow(&mut self, blow: &Blow, rng: &mut WorldRng) -> Result<BlowOutcome, Error> {
+        self.apply_force(&blow.force, rng).await
+    }
     async fn send_message(&mut self, message: String) -> Result<(), Error>;
     async fn interact(&mut self) -> Result<String, Error>;
-    async fn update(&mut self, my_handle: WorldObjectHandle, world: &World) -> Result<Action, Error>;
+    async fn update(&mut self, my_handle: WorldObjectHandle, world: &WorldView) -> Result<Action, Error>;
     async fn collect(self: Box<Self>) -> Result<Self::CollectInventoryItem, (Error, Box<Self>)>;
 }
 
@@ -94,11 +127,15 @@ impl<T: TypedWorldObject + Send + Sync + 'static> WorldObject for T {
         <T as TypedWorldObject>::pronoun(&self)
     }
 
-    fn dummy(&self) -> Box<dyn WorldObject> {
-        Box::new(<T as TypedWorldObject>::dummy(self))
+    fn type_tag(&self) -> &'static str {
+        T::TYPE_TAG
+    }
+
+    fn to_json(&self) -> Result<serde_json::Value, Error> {
+        serde_json::to_value(self).map_err(|err| Box::new(err).into())
     }
 
-    async fn update(&mut self, my_handle: WorldObjectHandle, world: &World) -> Result<Action, Error> {
+    async fn update(&mut self, my_handle: WorldObjectHandle, world: &WorldView) -> Result<Action, Error> {
         <T as TypedWorldObject>::update(self, my_handle, world).await
     }
 
@@ -120,8 +157,28 @@ impl<T: TypedWorldObject + Send + Sync + 'static> WorldObject for T {
         <T as TypedWorldObject>::mass(self)
     }
 
-    async fn apply_force(&mut self, force: &Quantity<Force>) -> Result<String, Error> {
-        <T as TypedWorldObject>::apply_force(self, force).await
+    fn perception(&self) -> Perception {
+        <T as TypedWorldObject>::perception(self)
+    }
+
+    fn initiative(&self) -> Option<Quantity<Speed>> {
+        <T as TypedWorldObject>::initiative(self)
+    }
+
+    fn vitality(&self) -> Vitality {
+        <T as TypedWorldObject>::vitality(self)
+    }
+
+    fn material(&self) -> Option<&'static Material> {
+        <T as TypedWorldObject>::material(self)
+    }
+
+    async fn apply_force(&mut self, force: &Quantity<Force>, rng: &mut WorldRng) -> Result<BlowOutcome, Error> {
+        <T as TypedWorldObject>::apply_force(self, force, rng).await
+    }
+
+    async fn receive_blow(&mut self, blow: &Blow, rng: &mut WorldRng) -> Result<BlowOutcome, Error> {
+        <T as TypedWorldObject>::receive_blow(self, blow, rng).await
     }
 
     async fn send_message(&mut self, message: String) -> Result<(), Error> {
@@ -147,6 +204,10 @@ impl<T: TypedWorldObject + Send + Sync + 'static> WorldObject for T {
     fn set_controller(&mut self, controller: Box<dyn Controller>) -> Result<(), (Box<dyn Controller>, Error)> {
         <T as TypedWorldObject>::set_controller(self, controller)
     }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
 }
 
 #[async_trait]
@@ -161,18 +222,32 @@ pub trait WorldObject: Send + Sync {
     // physics
     fn mass(&self) -> Quantity<Mass>;
 
+    // perception
+    fn perception(&self) -> Perception;
+
+    // turn order; objects that never act return None
+    fn initiative(&self) -> Option<Quantity<Speed>>;
+
+    // whether the object is alive, dead, or was never alive
+    fn vitality(&self) -> Vitality;
+
+    // what the object is made of, if anything in particular
+    fn material(&self) -> Option<&'static Material>;
+
     // inventory accessors
     fn inventory(&self) -> Result<&Inventory, Error>;
     fn inventory_mut(&mut self) -> Result<&mut Inventory, Error>;
 
-    // creates a new object with the same properties as this one,
-    // minus any fields that are not cloneable (typically controllers)
-    fn dummy(&self) -> Box<dyn WorldObject>;
+    // persistence; the type tag identifies the concrete type
+    // in the registry, and to_json serializes the object's data
+    fn type_tag(&self) -> &'static str;
+    fn to_json(&self) -> Result<serde_json::Value, Error>;
 
     // game mechanics; all async to allow interaction with the controller.
-    async fn update(&mut self, my_handle: WorldObjectHandle, world: &World) -> Result<Action, Error>;
+    async fn update(&mut self, my_handle: WorldObjectHandle, world: &WorldView) -> Result<Action, Error>;
     async fn collect(self: Box<Self>) -> Result<Box<dyn InventoryItem>, (Error, Box<dyn WorldObject>)>;
-    async fn apply_force(&mut self, force: &Quantity<Force>) -> Result<String, Error>;
+    async fn apply_force(&mut self, force: &Quantity<Force>, rng: &mut WorldRng) -> Result<BlowOutcome, Error>;
+    async fn receive_blow(&mut self, blow: &Blow, rng: &mut WorldRng) -> Result<BlowOutcome, Error>;
     async fn send_message(&mut self, message: String) -> Result<(), Error>;
     async fn interact(&mut self) -> Result<String, Error>;
 
@@ -181,4 +256,8 @@ pub trait WorldObject: Send + Sync {
     fn controller_mut(&mut self) -> Result<&mut dyn Controller, Error>;
     fn take_controller(&mut self) -> Result<Box<dyn Controller>, Error>;
     fn set_controller(&mut self, controller: Box<dyn Controller>) -> Result<(), (Box<dyn Controller>, Error)>;
+
+    // downcasting, for code that only works with one kind of
+    // object, e.g. a command only humans can carry out
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any;
 }
\ No newline at end of file
@@ -9,11 +9,15 @@
 // will always be an error.
 
 use async_trait::async_trait;
+use serde::{Serialize, Serializer};
 
 use crate::{
+    combat::BlowOutcome,
     world::{
-        World,
-        handle::WorldObjectHandle
+        handle::WorldObjectHandle,
+        rng::WorldRng,
+        view::WorldView,
+        perception::Perception
     },
     quantities::{
         Quantity,
@@ -21,7 +25,8 @@ use crate::{
             Mass,
             kilograms
         },
-        force::Force
+        force::Force,
+        speed::Speed
     }
 };
 
@@ -44,6 +49,12 @@ use super::{
 /// occur.
 pub struct NoWorldObject(!);
 
+impl Serialize for NoWorldObject {
+    fn serialize<S: Serializer>(&self, _: S) -> Result<S::Ok, S::Error> {
+        self.0
+    }
+}
+
 #[derive(Debug)]
 pub struct NoWorldObjectInventoryError;
 
@@ -104,8 +115,8 @@ impl std::error::Error for NoWorldObjectControllerError {}
 // but rust requires us to provide an implementation
 #[async_trait]
 impl TypedWorldObject for NoWorldObject {
-    type Dummy = Self;
     type CollectInventoryItem = NoInventoryItem;
+    const TYPE_TAG: &'static str = "none";
 
     fn name(&self) -> String {
         String::from("nothing")
@@ -127,11 +138,7 @@ impl TypedWorldObject for NoWorldObject {
         String::from("it")
     }
 
-    fn dummy(&self) -> Self {
-        NoWorldObject(self.0)
-    }
-
-    async fn update(&mut self, my_handle: WorldObjectHandle, world: &World) -> Result<Action, Error> {
+    async fn update(&mut self, _my_handle: WorldObjectHandle, _world: &WorldView) -> Result<Action, Error> {
         Ok(Action::no_op())
     }
 
@@ -151,7 +158,15 @@ impl TypedWorldObject for NoWorldObject {
         kilograms(0.0)
     }
 
-    async fn apply_force(&mut self, force: &Quantity<Force>) -> Result<String, Error> {
+    fn perception(&self) -> Perception {
+        Perception::none()
+    }
+
+    fn initiative(&self) -> Option<Quantity<Speed>> {
+        None
+    }
+
+    async fn apply_force(&mut self, _force: &Quantity<Force>, _rng: &mut WorldRng) -> Result<BlowOutcome, Error> {
         Err(Box::new(NoWorldObjectForceApplicationError))
     }
 
@@ -0,0 +1,161 @@
+// This module turns commands into the actions a rat takes.  Rats can only
+// get about and bite, so attacking anything means biting it, and a rat
+// that looks around just sits there.  The commands rats carry out in their
+// own way are registered with the command registry; anything else is left
+// to the command, and is beyond a rat unless the command was written for rats.
+
+use futures::future::BoxFuture;
+
+use crate::{
+    combat,
+    lang::{VerbPhrase, IntransitiveVerb, TransitiveVerb, verbs::{ToBite, ToMove}},
+    quantities::{distance::meters, duration::seconds},
+    world::{
+        World,
+        WorldObjectGetError,
+        handle::{WorldObjectHandle, WorldObjectLabel},
+        motion::{self, MoveError},
+        range::RangeError,
+        view::WorldView
+    },
+    worldobject::{
+        Error as WorldObjectError,
+        fns::update::Action,
+        components::{
+            body::BodyPart,
+            controllers::commands::{
+                Command,
+                CarriesOut,
+                attack_command::AttackCommand,
+                bite_command::BiteCommand,
+                WrongActorError,
+                circumspect_command::CircumspectCommand,
+                move_command::MoveCommand
+            }
+        }
+    }
+};
+
+use super::{Rat, RAT_BITE_REACH_METERS};
+
+#[derive(Debug)]
+pub enum RatCommandToActionError {
+    CannotDo(&'static str),
+    CannotMove(MoveError),
+    CommandFailed(Box<dyn std::error::Error>),
+    FailedToGetTargetObject(WorldObjectGetError),
+    TargetOutOfRange(RangeError),
+}
+
+impl std::fmt::Display for RatCommandToActionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::CannotDo(keyword) => write!(f, "rats can't {}", keyword),
+            Self::CannotMove(err) => write!(f, "can't move: {}", err),
+            Self::CommandFailed(err) => write!(f, "{}", err),
+            Self::FailedToGetTargetObject(err) => write!(f, "failed to get target object: {}", err),
+            Self::TargetOutOfRange(err) => write!(f, "target out of reach: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for RatCommandToActionError {}
+
+impl Rat {
+    // action_from_command builds the action this rat takes when given the command
+    pub fn action_from_command(&mut self, cmd: Command, world: &WorldView, my_handle: WorldObjectHandle) -> Result<Action, RatCommandToActionError> {
+        let keyword = cmd.keyword();
+        cmd.into_action(self, world, my_handle)
+            .map_err(|err| {
+                if err.is::<WrongActorError>() {
+                    return RatCommandToActionError::CannotDo(keyword);
+                }
+
+                match err.downcast::<RatCommandToActionError>() {
+                    Ok(err) => *err,
+                    Err(err) => RatCommandToActionError::CommandFailed(err),
+                }
+            })
+    }
+
+    // move_action scurries the given distance, which takes as long as it takes
+    // at the rat's speed; rats climb as readily as they run
+    fn move_action(&self, cmd: MoveCommand, my_handle: WorldObjectHandle) -> Result<Action, RatCommandToActionError> {
+        let cost = motion::move_cost(&cmd.distance, &self.speed)
+            .map_err(RatCommandToActionError::CannotMove)?;
+
+        Ok(Action {
+            exec: Box::new(
+                move |world: &mut World| -> BoxFuture<Result<Option<String>, WorldObjectError>> {
+                    Box::pin(async move {
+                        world.move_object(&my_handle, &cmd.direction, &cmd.distance)
+                            .map_err(Box::new)?;
+
+                        let dist_f64 = (cmd.distance / meters(1.0)).cancel().0.0;
+
+                        Ok(Some(format!("you move {} meters {}", dist_f64, cmd.direction)))
+                    })
+                }
+            ),
+            cost,
+            verb_phrase: VerbPhrase::Intransitive(IntransitiveVerb::new(ToMove)),
+        })
+    }
+
+    // bite_action bites the target, if it is close enough to sink teeth into
+    fn bite_action(&self, target: WorldObjectLabel, aim: Option<BodyPart>, world: &WorldView, my_handle: WorldObjectHandle) -> Result<Action, RatCommandToActionError> {
+        let target_handle = world.find_object(&target)
+            .map_err(RatCommandToActionError::FailedToGetTargetObject)?;
+
+        let target_description = world.get_object(&target_handle)
+            .map(|object| object.definite_description())
+            .map_err(RatCommandToActionError::FailedToGetTargetObject)?;
+
+        world.check_range(&my_handle, &target_handle, &meters(RAT_BITE_REACH_METERS))
+            .map_err(RatCommandToActionError::TargetOutOfRange)?;
+
+        let blow = combat::bite(&self.mouth.teeth, aim);
+
+        Ok(Action {
+            exec: Box::new(
+                move |world: &mut World| {
+                    Box::pin(combat::strike(world, my_handle, target_handle, blow))
+                }
+            ),
+            cost: seconds(1.0),
+            verb_phrase: VerbPhrase::Transitive(TransitiveVerb::new(ToBite).with_direct_object(target_description)),
+        })
+    }
+}
+
+impl CarriesOut<MoveCommand> for Rat {
+    type ActionError = RatCommandToActionError;
+
+    fn carry_out(&mut self, command: MoveCommand, _world: &WorldView, my_handle: WorldObjectHandle) -> Result<Action, Self::ActionError> {
+        self.move_action(command, my_handle)
+    }
+}
+
+impl CarriesOut<AttackCommand> for Rat {
+    type ActionError = RatCommandToActionError;
+
+    fn carry_out(&mut self, command: AttackCommand, world: &WorldView, my_handle: WorldObjectHandle) -> Result<Action, Self::ActionError> {
+        self.bite_action(command.target, command.aim, world, my_handle)
+    }
+}
+
+impl CarriesOut<BiteCommand> for Rat {
+    type ActionError = RatCommandToActionError;
+
+    fn carry_out(&mut self, command: BiteCommand, world: &WorldView, my_handle: WorldObjectHandle) -> Result<Action, Self::ActionError> {
+        self.bite_action(command.target, command.aim, world, my_handle)
+    }
+}
+
+impl CarriesOut<CircumspectCommand> for Rat {
+    type ActionError = RatCommandToActionError;
+
+    fn carry_out(&mut self, _command: CircumspectCommand, _world: &WorldView, _my_handle: WorldObjectHandle) -> Result<Action, Self::ActionError> {
+        Ok(Action::no_op())
+    }
+}
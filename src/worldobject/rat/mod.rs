@@ -0,0 +1,308 @@
+pub mod actions;
+
+use async_trait::async_trait;
+use serde::Serialize;
+
+use crate::{
+    combat::{self, Blow, BlowOutcome},
+    lang::{VerbPhrase, IntransitiveVerb, TransitiveVerb, verbs::{ToDie, ToSuffer}},
+    world::{
+        handle::WorldObjectHandle,
+        rng::WorldRng,
+        view::WorldView,
+        perception::Perception,
+        motion
+    },
+    worldobject::{
+        TypedWorldObject,
+        Error as WorldObjectError,
+        fns::update::Action,
+        components::{
+            body::head::{Mouth, Tooth},
+            controllers::{
+                Controller,
+                ai::AiController,
+                commands::resolve::Referents,
+                observation::Observation
+            },
+            health::{Health, Vitality},
+            inventory::{
+                Inventory,
+                item::InventoryItem
+            }
+        }
+    },
+    quantities::{
+        Quantity,
+        mass::{Mass, grams},
+        speed::Speed,
+        distance::{Distance, meters, centimeters},
+        force::Force
+    }
+};
+
+// how far, in meters, a rat can see and hear;
+// rats are short-sighted, but have keen ears
+const RAT_SIGHT_METERS: f64 = 5.0;
+const RAT_HEARING_METERS: f64 = 40.0;
+
+// rats are fragile; one punch leaves a rat badly hurt, and a second kills it
+const RAT_HIT_POINTS: f64 = 15.0;
+
+// how far, in meters, a rat can reach with its teeth
+const RAT_BITE_REACH_METERS: f64 = 0.2;
+
+#[derive(Serialize)]
+pub struct Rat {
+    mass: Quantity<Mass>,
+    speed: Quantity<Speed>,
+    health: Health,
+    mouth: Mouth,
+
+    // rats are played by the AI unless someone takes one over;
+    // controllers aren't saved, so loaded rats get a fresh AI
+    #[serde(skip)]
+    controller: Option<Box<dyn Controller>>,
+}
+
+#[derive(Debug)]
+pub struct RatInventoryError ();
+
+impl std::fmt::Display for RatInventoryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "rats don't have inventories")
+    }
+}
+
+impl Rat {
+    pub fn new(mass: Quantity<Mass>, speed: Quantity<Speed>) -> Rat {
+        Rat { mass, speed, health: Health::new(RAT_HIT_POINTS), mouth: rat_mouth(), controller: Some(Box::new(AiController::rat())) }
+    }
+
+    fn is_alive(&self) -> bool {
+        !self.health.is_disabled()
+    }
+
+    // observe records what this rat perceives, for its controller;
+    // rats have no hands, and so can't pick anything up
+    fn observe(&self, world: &WorldView) -> Observation {
+        Observation::new(Self::TYPE_TAG, self.health.fraction(), meters(RAT_BITE_REACH_METERS), meters(0.0), motion::stride(&self.speed), &self.perception(), world)
+    }
+}
+
+impl std::error::Error for RatInventoryError {}
+
+// rat_mouth returns the mouth every rat is born with:
+// four long, sharp incisors, and little else worth biting with
+fn rat_mouth() -> Mouth {
+    Mouth {
+        base_mass: grams(2.0),
+        teeth: std::iter::repeat_with(|| Tooth { mass: grams(0.1), length: centimeters(1.0), sharp: true }).take(4).collect(),
+    }
+}
+
+impl TryFrom<&serde_json::Value> for Rat {
+    type Error = String;
+
+    fn try_from(value: &serde_json::Value) -> Result<Self, Self::Error> {
+        let mass = Quantity::<Mass>::try_from(value.get("mass").ok_or("mass not found")?.clone()).map_err(|err| format!("failed to parse mass: {}", err))?;
+        let speed = Quantity::<Speed>::try_from(value.get("speed").ok_or("speed not found")?.clone()).map_err(|err| format!("failed to parse speed: {}", err))?;
+        let health = Health::try_from(value.get("health").ok_or("health not found")?).map_err(|err| format!("failed to parse health: {}", err))?;
+        let mouth = Mouth::try_from(value.get("mouth").ok_or("mouth not found")?).map_err(|err| format!("failed to parse mouth: {}", err))?;
+        Ok(Rat { mass, speed, health, mouth, controller: Some(Box::new(AiController::rat())) })
+    }
+}
+
+#[derive(Debug)]
+pub struct RatUseError;
+
+impl std::error::Error for RatUseError {}
+
+impl std::fmt::Display for RatUseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "you can't think of anything particularly interesting to do with this rat")
+    }
+}
+
+#[derive(Debug)]
+pub struct RatNoControllerError;
+
+impl std::fmt::Display for RatNoControllerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "rat has no controller")
+    }
+}
+
+impl std::error::Error for RatNoControllerError {}
+
+impl InventoryItem for Rat {
+    fn reach(&self) -> Quantity<Distance> {
+        meters(0.0)
+    }
+
+    fn range(&self) -> Quantity<Distance> {
+        meters(0.0)
+    }
+
+    fn use_item(&mut self, _: &WorldView, _: WorldObjectHandle, _: Option<WorldObjectHandle>) -> Result<Action, Box<dyn std::error::Error>> {
+        Err(Box::new(RatUseError))
+    }
+}
+
+#[async_trait]
+impl TypedWorldObject for Rat {
+    type CollectInventoryItem = Self;
+    const TYPE_TAG: &'static str = "rat";
+
+    fn name(&self) -> String {
+        String::from("rat")
+    }
+
+    async fn update(&mut self, my_handle: WorldObjectHandle, world: &WorldView) -> Result<Action, WorldObjectError> {
+        let observation = self.observe(world);
+        let referents = Referents::visible_objects(world, &self.perception());
+
+        match &mut self.controller {
+            Some(controller) => {
+                controller.observe(&observation).await;
+
+                let command = controller.prompt_turn().await?;
+                let command = command.resolve(&referents, controller.as_mut()).await?;
+
+                // the command is only journaled once it has become an action
+                let journaled = serde_json::to_value(&command)?;
+                let action = self.action_from_command(command, world, my_handle)
+                    .map_err(Box::new)?;
+                world.accept(journaled);
+
+                Ok(action)
+            }
+            None => Ok(Action::no_op()),
+        }
+    }
+
+    fn examine(&self) -> String {
+        if self.is_alive() {
+            String::from("a rat")
+        } else {
+            String::from("a dead rat")
+        }
+    }
+
+    fn definite_description(&self) -> String {
+        if self.is_alive() {
+            String::from("the rat")
+        } else {
+            String::from("the dead rat")
+        }
+    }
+
+    fn indefinite_description(&self) -> String {
+        if self.is_alive() {
+            String::from("a rat")
+        } else {
+            String::from("a dead rat")
+        }
+    }
+
+    fn pronoun(&self) -> String {
+        String::from("it")
+    }
+
+    async fn collect(self: Box<Self>) -> Result<Self, (WorldObjectError, Box<Self>)> {
+        Ok(*self)
+    }
+
+    async fn interact(&mut self) -> Result<String, WorldObjectError> {
+        if self.is_alive() {
+            Ok(String::from("the rat squeaks happily."))
+        } else {
+            Ok(String::from("the rat does nothing."))
+        }
+    }
+
+    fn inventory(&self) -> Result<&Inventory, WorldObjectError> {
+        Err(Box::new(RatInventoryError()))
+    }
+
+    fn inventory_mut(&mut self) -> Result<&mut Inventory, WorldObjectError> {
+        Err(Box::new(RatInventoryError()))
+    }
+
+    fn mass(&self) -> Quantity<Mass> {
+        self.mass.clone()
+    }
+
+    fn perception(&self) -> Perception {
+        Perception::new(meters(RAT_SIGHT_METERS), meters(RAT_HEARING_METERS))
+    }
+
+    // dead rats no longer take turns
+    fn initiative(&self) -> Option<Quantity<Speed>> {
+        self.is_alive().then(|| self.speed.clone())
+    }
+
+    fn vitality(&self) -> Vitality {
+        if self.is_alive() { Vitality::Alive } else { Vitality::Dead }
+    }
+
+    async fn apply_force(&mut self, force: &Quantity<Force>, _rng: &mut WorldRng) -> Result<BlowOutcome, WorldObjectError> {
+        if !self.is_alive() {
+            return Ok(BlowOutcome::from(String::from("the dead rat is pushed about by the force")));
+        }
+
+        self.health.damage(combat::hit_points(force));
+        if self.is_alive() {
+            Ok(BlowOutcome::from(String::from("the rat survives the force")))
+        } else {
+            Ok(BlowOutcome::from(String::from("the rat is crushed by the force")))
+        }
+    }
+
+    // a rat is too small for blows to land anywhere in particular,
+    // so it ignores where they were aimed
+    async fn receive_blow(&mut self, blow: &Blow, _rng: &mut WorldRng) -> Result<BlowOutcome, WorldObjectError> {
+        if !self.is_alive() {
+            return Ok(BlowOutcome::from(String::from("the rat is already dead")));
+        }
+
+        self.health.damage(blow.hit_points());
+        let effect = if self.is_alive() {
+            VerbPhrase::Transitive(TransitiveVerb::new(ToSuffer).with_direct_object(format!("a {}", blow.damage_type.wound())))
+        } else {
+            VerbPhrase::Intransitive(IntransitiveVerb::new(ToDie))
+        };
+
+        Ok(BlowOutcome { description: None, effects: vec![effect], shed: vec![] })
+    }
+
+    async fn send_message(&mut self, message: String) -> Result<(), WorldObjectError> {
+        match &mut self.controller {
+            Some(controller) => controller.display_message(message).await,
+            None => Ok(()),
+        }
+    }
+
+    fn controller(&self) -> Result<&dyn Controller, WorldObjectError> {
+        match &self.controller {
+            Some(controller) => Ok(&**controller),
+            None => Err(Box::new(RatNoControllerError)),
+        }
+    }
+
+    fn controller_mut(&mut self) -> Result<&mut dyn Controller, WorldObjectError> {
+        match &mut self.controller {
+            Some(controller) => Ok(&mut **controller),
+            None => Err(Box::new(RatNoControllerError)),
+        }
+    }
+
+    fn take_controller(&mut self) -> Result<Box<dyn Controller>, WorldObjectError> {
+        self.controller.take().ok_or(Box::new(RatNoControllerError))
+    }
+
+    fn set_controller<C: Controller + 'static>(&mut self, controller: C) -> Result<(), (C, WorldObjectError)> {
+        self.controller = Some(Box::new(controller));
+        Ok(())
+    }
+}
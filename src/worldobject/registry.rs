@@ -0,0 +1,191 @@
+// This module houses the type registry.
+//
+// Trait objects such as Box<dyn WorldObject> and Box<dyn InventoryItem>
+// cannot be deserialized directly, since the concrete type behind them
+// is lost.  To work around this, persisted objects are written alongside
+// their type tag (see TypedWorldObject::TYPE_TAG), and the registry maps
+// each tag back to a constructor for the concrete type.
+//
+// Every type that ships with the game is registered in Registry::builtin.
+// Other types are added to a registry of their own, usually the builtin
+// one, which is then installed in its place; this has to happen before
+// anything is first saved or loaded.
+
+use std::{
+    collections::HashMap,
+    sync::OnceLock
+};
+
+use serde_json::json;
+
+use crate::worldobject::{
+    WorldObject,
+    TypedWorldObject,
+    Error,
+    human::Human,
+    rat::Rat,
+    components::{
+        body::torso::arm::{Arm, hand::Hand},
+        inventory::item::{
+            InventoryItem,
+            sword::Sword,
+            wand::Wand
+        }
+    }
+};
+
+type WorldObjectConstructor = fn(&serde_json::Value) -> Result<Box<dyn WorldObject>, String>;
+type InventoryItemConstructor = fn(&serde_json::Value) -> Result<Box<dyn InventoryItem>, String>;
+
+pub struct Registry {
+    world_objects: HashMap<&'static str, WorldObjectConstructor>,
+    inventory_items: HashMap<&'static str, InventoryItemConstructor>,
+}
+
+static REGISTRY: OnceLock<Registry> = OnceLock::new();
+
+// registry gives the registry in use, which is the builtin
+// registry unless another was installed before it was needed
+fn registry() -> &'static Registry {
+    REGISTRY.get_or_init(Registry::builtin)
+}
+
+#[derive(Debug)]
+pub enum RegistryError {
+    AlreadyInUse,
+    MissingTypeTag,
+    MissingData(String),
+    UnknownType(String),
+    FailedToSerialize(String, Error),
+    FailedToDeserialize(String, String),
+}
+
+impl std::fmt::Display for RegistryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AlreadyInUse => write!(f, "a type registry is already in use"),
+            Self::MissingTypeTag => write!(f, "type tag not found"),
+            Self::MissingData(tag) => write!(f, "data not found for object of type \"{}\"", tag),
+            Self::UnknownType(tag) => write!(f, "no type registered under tag \"{}\"", tag),
+            Self::FailedToSerialize(tag, err) => write!(f, "failed to serialize object of type \"{}\": {}", tag, err),
+            Self::FailedToDeserialize(tag, err) => write!(f, "failed to deserialize object of type \"{}\": {}", tag, err),
+        }
+    }
+}
+
+impl std::error::Error for RegistryError {}
+
+fn construct_world_object<T>(value: &serde_json::Value) -> Result<Box<dyn WorldObject>, String>
+where T: TypedWorldObject + Sync + 'static + for<'a> TryFrom<&'a serde_json::Value, Error = String> {
+    T::try_from(value).map(|object| Box::new(object) as Box<dyn WorldObject>)
+}
+
+fn construct_inventory_item<T>(value: &serde_json::Value) -> Result<Box<dyn InventoryItem>, String>
+where T: TypedWorldObject + InventoryItem + 'static + for<'a> TryFrom<&'a serde_json::Value, Error = String> {
+    T::try_from(value).map(|item| Box::new(item) as Box<dyn InventoryItem>)
+}
+
+impl Registry {
+    fn new() -> Self {
+        Self {
+            world_objects: HashMap::new(),
+            inventory_items: HashMap::new(),
+        }
+    }
+
+    // builtin creates a registry containing every type that ships with the game
+    pub fn builtin() -> Self {
+        let mut registry = Self::new();
+
+        registry.add_world_object::<Human>();
+
+        registry.add_world_object::<Rat>();
+        registry.add_inventory_item::<Rat>();
+
+        registry.add_world_object::<Sword>();
+        registry.add_inventory_item::<Sword>();
+
+        registry.add_world_object::<Wand>();
+        registry.add_inventory_item::<Wand>();
+
+        registry.add_world_object::<Arm>();
+        registry.add_inventory_item::<Arm>();
+
+        registry.add_world_object::<Hand>();
+        registry.add_inventory_item::<Hand>();
+
+        registry
+    }
+
+    // install makes this the registry in use; it fails once anything
+    // has been saved or loaded, since the registry is then fixed
+    pub fn install(self) -> Result<(), RegistryError> {
+        REGISTRY.set(self).map_err(|_| RegistryError::AlreadyInUse)
+    }
+
+    pub fn add_world_object<T>(&mut self)
+    where T: TypedWorldObject + Sync + 'static + for<'a> TryFrom<&'a serde_json::Value, Error = String> {
+        self.world_objects.insert(T::TYPE_TAG, construct_world_object::<T>);
+    }
+
+    pub fn add_inventory_item<T>(&mut self)
+    where T: TypedWorldObject + InventoryItem + 'static + for<'a> TryFrom<&'a serde_json::Value, Error = String> {
+        self.inventory_items.insert(T::TYPE_TAG, construct_inventory_item::<T>);
+    }
+}
+
+fn tagged(tag: &'static str, data: Result<serde_json::Value, Error>) -> Result<serde_json::Value, RegistryError> {
+    let data = data.map_err(|err| RegistryError::FailedToSerialize(String::from(tag), err))?;
+    Ok(json!({ "type": tag, "data": data }))
+}
+
+fn untagged(value: &serde_json::Value) -> Result<(&str, &serde_json::Value), RegistryError> {
+    let tag = value.get("type").and_then(|tag| tag.as_str()).ok_or(RegistryError::MissingTypeTag)?;
+    let data = value.get("data").ok_or(RegistryError::MissingData(String::from(tag)))?;
+    Ok((tag, data))
+}
+
+// world_object_to_json serializes an object along with its type tag
+pub fn world_object_to_json(object: &dyn WorldObject) -> Result<serde_json::Value, RegistryError> {
+    tagged(object.type_tag(), object.to_json())
+}
+
+// world_object_from_json reconstructs an object written by world_object_to_json
+pub fn world_object_from_json(value: &serde_json::Value) -> Result<Box<dyn WorldObject>, RegistryError> {
+    let (tag, data) = untagged(value)?;
+
+    let constructor = registry().world_objects.get(tag)
+        .ok_or(RegistryError::UnknownType(String::from(tag)))?;
+
+    constructor(data).map_err(|err| RegistryError::FailedToDeserialize(String::from(tag), err))
+}
+
+// inventory_item_to_json serializes an item along with its type tag
+pub fn inventory_item_to_json(item: &dyn InventoryItem) -> Result<serde_json::Value, RegistryError> {
+    tagged(item.type_tag(), item.to_json())
+}
+
+// inventory_item_from_json reconstructs an item written by inventory_item_to_json
+pub fn inventory_item_from_json(value: &serde_json::Value) -> Result<Box<dyn InventoryItem>, RegistryError> {
+    let (tag, data) = untagged(value)?;
+
+    let constructor = registry().inventory_items.get(tag)
+        .ok_or(RegistryError::UnknownType(String::from(tag)))?;
+
+    constructor(data).map_err(|err| RegistryError::FailedToDeserialize(String::from(tag), err))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::fixtures::sword;
+
+    use super::{Registry, RegistryError, inventory_item_from_json, inventory_item_to_json};
+
+    #[test]
+    fn cannot_install_a_registry_once_anything_has_been_loaded() {
+        let value = inventory_item_to_json(&sword("steel")).unwrap();
+        assert!(inventory_item_from_json(&value).is_ok());
+
+        assert!(matches!(Registry::builtin().install(), Err(RegistryError::AlreadyInUse)));
+    }
+}